@@ -20,39 +20,57 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
 
     $completions = @(switch ($command) {
         'hurl'
-         {[CompletionResult]::new('--aws-sigv4', 'aws-sigv4', [CompletionResultType]::ParameterName, 'Use AWS V4 signature authentication in the transfer')
+         {[CompletionResult]::new('--assert', 'assert', [CompletionResultType]::ParameterName, 'Add an assert to every entry')
+            [CompletionResult]::new('--aws-sigv4', 'aws-sigv4', [CompletionResultType]::ParameterName, 'Use AWS V4 signature authentication in the transfer')
+            [CompletionResult]::new('--benchmark', 'benchmark', [CompletionResultType]::ParameterName, 'Display latency percentiles of each request')
             [CompletionResult]::new('--cacert', 'cacert', [CompletionResultType]::ParameterName, 'CA certificate to verify peer against (PEM format)')
             [CompletionResult]::new('--cert', 'cert', [CompletionResultType]::ParameterName, 'Client certificate file and password')
             [CompletionResult]::new('--key', 'key', [CompletionResultType]::ParameterName, 'Private key file name')
             [CompletionResult]::new('--color', 'color', [CompletionResultType]::ParameterName, 'Colorize output')
             [CompletionResult]::new('--compressed', 'compressed', [CompletionResultType]::ParameterName, 'Request compressed response (using deflate or gzip)')
+            [CompletionResult]::new('--concurrency-per-file', 'concurrency-per-file', [CompletionResultType]::ParameterName, 'Maximum number of entries of a file run concurrently')
             [CompletionResult]::new('--connect-timeout', 'connect-timeout', [CompletionResultType]::ParameterName, 'Maximum time allowed for connection')
             [CompletionResult]::new('--connect-to', 'connect-to', [CompletionResultType]::ParameterName, 'For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead')
             [CompletionResult]::new('--continue-on-error', 'continue-on-error', [CompletionResultType]::ParameterName, 'Continue executing requests even if an error occurs')
+            [CompletionResult]::new('--cookie-jar-format', 'cookie-jar-format', [CompletionResultType]::ParameterName, 'Set the format of the cookie jar file (netscape or json)')
             [CompletionResult]::new('--cookie', 'cookie', [CompletionResultType]::ParameterName, 'Read cookies from FILE')
-            [CompletionResult]::new('--cookie-jar', 'cookie-jar', [CompletionResultType]::ParameterName, 'Write cookies to FILE after running the session (only for one session)')
+            [CompletionResult]::new('--cookie-jar', 'cookie-jar', [CompletionResultType]::ParameterName, 'Write cookies to FILE after running the session')
             [CompletionResult]::new('--curl', 'curl', [CompletionResultType]::ParameterName, 'Export each request to a list of curl commands')
             [CompletionResult]::new('--delay', 'delay', [CompletionResultType]::ParameterName, 'Sets delay before each request (aka sleep)')
+            [CompletionResult]::new('--delay-jitter', 'delay-jitter', [CompletionResultType]::ParameterName, 'Adds a random delay up to MILLISECONDS to each delay')
+            [CompletionResult]::new('--delay-seed', 'delay-seed', [CompletionResultType]::ParameterName, 'Sets the seed used to randomize delays')
+            [CompletionResult]::new('--dns-servers', 'dns-servers', [CompletionResultType]::ParameterName, 'Use this list of DNS servers instead of the system default (ex: 1.1.1.1,8.8.8.8)')
+            [CompletionResult]::new('--dump-request', 'dump-request', [CompletionResultType]::ParameterName, 'Write the last HTTP request sent to FILE')
             [CompletionResult]::new('--error-format', 'error-format', [CompletionResultType]::ParameterName, 'Control the format of error messages')
+            [CompletionResult]::new('--fail-fast', 'fail-fast', [CompletionResultType]::ParameterName, 'Stop running Hurl files after the first failing file')
+            [CompletionResult]::new('--fail-if-empty', 'fail-if-empty', [CompletionResultType]::ParameterName, 'Fail if a Hurl file has no entry')
+            [CompletionResult]::new('--features', 'features', [CompletionResultType]::ParameterName, 'List the features supported by this Hurl build and exit')
             [CompletionResult]::new('--file-root', 'file-root', [CompletionResultType]::ParameterName, 'Set root directory to import files [default: input file directory]')
             [CompletionResult]::new('--location', 'location', [CompletionResultType]::ParameterName, 'Follow redirects')
             [CompletionResult]::new('--location-trusted', 'location-trusted', [CompletionResultType]::ParameterName, 'Follow redirects but allows sending the name + password to all hosts that the site may redirect to')
+            [CompletionResult]::new('--from-curl', 'from-curl', [CompletionResultType]::ParameterName, 'Read curl commands from input files and run them')
             [CompletionResult]::new('--from-entry', 'from-entry', [CompletionResultType]::ParameterName, 'Execute Hurl file from ENTRY_NUMBER (starting at 1)')
             [CompletionResult]::new('--glob', 'glob', [CompletionResultType]::ParameterName, 'Specify input files that match the given GLOB. Multiple glob flags may be used')
             [CompletionResult]::new('--header', 'header', [CompletionResultType]::ParameterName, 'Pass custom header(s) to server')
+            [CompletionResult]::new('--header-for-host', 'header-for-host', [CompletionResultType]::ParameterName, 'Pass custom header(s) to HOST only')
             [CompletionResult]::new('--http1.0', 'http1.0', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 1.0')
             [CompletionResult]::new('--http1.1', 'http1.1', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 1.1')
             [CompletionResult]::new('--http2', 'http2', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 2')
             [CompletionResult]::new('--http3', 'http3', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 3')
             [CompletionResult]::new('--ignore-asserts', 'ignore-asserts', [CompletionResultType]::ParameterName, 'Ignore asserts defined in the Hurl file')
+            [CompletionResult]::new('--ignore-trailing-newline', 'ignore-trailing-newline', [CompletionResultType]::ParameterName, 'Ignore a single trailing newline when comparing response bodies')
             [CompletionResult]::new('--include', 'include', [CompletionResultType]::ParameterName, 'Include the HTTP headers in the output')
             [CompletionResult]::new('--insecure', 'insecure', [CompletionResultType]::ParameterName, 'Allow insecure SSL connections')
+            [CompletionResult]::new('--insecure-host', 'insecure-host', [CompletionResultType]::ParameterName, 'Allow insecure SSL connections to HOST only')
             [CompletionResult]::new('--interactive', 'interactive', [CompletionResultType]::ParameterName, 'Turn on interactive mode')
             [CompletionResult]::new('--ipv4', 'ipv4', [CompletionResultType]::ParameterName, 'Tell Hurl to use IPv4 addresses only when resolving host names, and not for example try IPv6')
             [CompletionResult]::new('--ipv6', 'ipv6', [CompletionResultType]::ParameterName, 'Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4')
             [CompletionResult]::new('--jobs', 'jobs', [CompletionResultType]::ParameterName, 'Maximum number of parallel jobs')
             [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Output each Hurl file result to JSON')
+            [CompletionResult]::new('--json-report-ndjson', 'json-report-ndjson', [CompletionResultType]::ParameterName, 'Append a line-delimited JSON report to FILE')
+            [CompletionResult]::new('--junit-classname', 'junit-classname', [CompletionResultType]::ParameterName, 'Set the classname attribute of JUnit testcases from TEMPLATE')
             [CompletionResult]::new('--limit-rate', 'limit-rate', [CompletionResultType]::ParameterName, 'Specify the maximum transfer rate in bytes/second, for both downloads and uploads')
+            [CompletionResult]::new('--max-entries', 'max-entries', [CompletionResultType]::ParameterName, 'Fail if a Hurl file has more than NUM entries')
             [CompletionResult]::new('--max-filesize', 'max-filesize', [CompletionResultType]::ParameterName, 'Specify the maximum size in bytes of a file to download')
             [CompletionResult]::new('--max-redirs', 'max-redirs', [CompletionResultType]::ParameterName, 'Maximum number of redirects allowed, -1 for unlimited redirects')
             [CompletionResult]::new('--max-time', 'max-time', [CompletionResultType]::ParameterName, 'Maximum time allowed for the transfer')
@@ -60,31 +78,45 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--netrc-file', 'netrc-file', [CompletionResultType]::ParameterName, 'Specify FILE for .netrc')
             [CompletionResult]::new('--netrc-optional', 'netrc-optional', [CompletionResultType]::ParameterName, 'Use either .netrc or the URL')
             [CompletionResult]::new('--no-color', 'no-color', [CompletionResultType]::ParameterName, 'Do not colorize output')
+            [CompletionResult]::new('--no-fail-fast', 'no-fail-fast', [CompletionResultType]::ParameterName, 'Run all Hurl files even if a file fails (default)')
             [CompletionResult]::new('--no-output', 'no-output', [CompletionResultType]::ParameterName, 'Suppress output. By default, Hurl outputs the body of the last response')
             [CompletionResult]::new('--noproxy', 'noproxy', [CompletionResultType]::ParameterName, 'List of hosts which do not use proxy')
             [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Write to FILE instead of stdout')
+            [CompletionResult]::new('--output-append', 'output-append', [CompletionResultType]::ParameterName, 'Append to the output FILE instead of truncating it')
+            [CompletionResult]::new('--output-dir', 'output-dir', [CompletionResultType]::ParameterName, 'Write each entry response body to a file in DIR')
             [CompletionResult]::new('--parallel', 'parallel', [CompletionResultType]::ParameterName, 'Run files in parallel (default in test mode)')
             [CompletionResult]::new('--path-as-is', 'path-as-is', [CompletionResultType]::ParameterName, 'Tell Hurl to not handle sequences of /../ or /./ in the given URL path')
+            [CompletionResult]::new('--progress-format', 'progress-format', [CompletionResultType]::ParameterName, 'Format of the parallel progress reporting')
             [CompletionResult]::new('--proxy', 'proxy', [CompletionResultType]::ParameterName, 'Use proxy on given PROTOCOL/HOST/PORT')
             [CompletionResult]::new('--repeat', 'repeat', [CompletionResultType]::ParameterName, 'Repeat the input files sequence NUM times, -1 for infinite loop')
             [CompletionResult]::new('--report-html', 'report-html', [CompletionResultType]::ParameterName, 'Generate HTML report to DIR')
             [CompletionResult]::new('--report-json', 'report-json', [CompletionResultType]::ParameterName, 'Generate JSON report to DIR')
             [CompletionResult]::new('--report-junit', 'report-junit', [CompletionResultType]::ParameterName, 'Write a JUnit XML report to FILE')
             [CompletionResult]::new('--report-tap', 'report-tap', [CompletionResultType]::ParameterName, 'Write a TAP report to FILE')
+            [CompletionResult]::new('--request-charset', 'request-charset', [CompletionResultType]::ParameterName, 'Encode text request bodies with CHARSET before sending')
+            [CompletionResult]::new('--require-http-version', 'require-http-version', [CompletionResultType]::ParameterName, 'Fail if the requested HTTP version is not used')
             [CompletionResult]::new('--resolve', 'resolve', [CompletionResultType]::ParameterName, 'Provide a custom address for a specific HOST and PORT pair')
+            [CompletionResult]::new('--response-charset', 'response-charset', [CompletionResultType]::ParameterName, 'Decode response bodies with CHARSET, overriding the response Content-Type charset')
             [CompletionResult]::new('--retry', 'retry', [CompletionResultType]::ParameterName, 'Maximum number of retries, 0 for no retries, -1 for unlimited retries')
+            [CompletionResult]::new('--retry-backoff', 'retry-backoff', [CompletionResultType]::ParameterName, 'Strategy used to compute the interval between retries')
             [CompletionResult]::new('--retry-interval', 'retry-interval', [CompletionResultType]::ParameterName, 'Interval in milliseconds before a retry')
+            [CompletionResult]::new('--retry-max-interval', 'retry-max-interval', [CompletionResultType]::ParameterName, 'Maximum interval before a retry')
+            [CompletionResult]::new('--retry-on-status', 'retry-on-status', [CompletionResultType]::ParameterName, 'Retry only on these response status codes (ex: 429,503)')
             [CompletionResult]::new('--secret', 'secret', [CompletionResultType]::ParameterName, 'Define a variable which value is secret')
             [CompletionResult]::new('--ssl-no-revoke', 'ssl-no-revoke', [CompletionResultType]::ParameterName, '(Windows) Tell Hurl to disable certificate revocation checks')
+            [CompletionResult]::new('--tap-granularity', 'tap-granularity', [CompletionResultType]::ParameterName, 'Set the TAP report testpoints granularity (file, entry or assert)')
+            [CompletionResult]::new('--tap-verbose', 'tap-verbose', [CompletionResultType]::ParameterName, 'Add a YAML diagnostic block to each TAP report testpoint')
             [CompletionResult]::new('--test', 'test', [CompletionResultType]::ParameterName, 'Activate test mode (use parallel execution)')
             [CompletionResult]::new('--to-entry', 'to-entry', [CompletionResultType]::ParameterName, 'Execute Hurl file to ENTRY_NUMBER (starting at 1)')
             [CompletionResult]::new('--unix-socket', 'unix-socket', [CompletionResultType]::ParameterName, '(HTTP) Connect through this Unix domain socket, instead of using the network')
             [CompletionResult]::new('--user', 'user', [CompletionResultType]::ParameterName, 'Add basic Authentication header to each request')
             [CompletionResult]::new('--user-agent', 'user-agent', [CompletionResultType]::ParameterName, 'Specify the User-Agent string to send to the HTTP server')
             [CompletionResult]::new('--variable', 'variable', [CompletionResultType]::ParameterName, 'Define a variable')
+            [CompletionResult]::new('--variable-from-file', 'variable-from-file', [CompletionResultType]::ParameterName, 'Define a variable from the content of a file')
             [CompletionResult]::new('--variables-file', 'variables-file', [CompletionResultType]::ParameterName, 'Define a properties file in which you define your variables')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Turn on verbose')
             [CompletionResult]::new('--very-verbose', 'very-verbose', [CompletionResultType]::ParameterName, 'Turn on verbose output, including HTTP response and libcurl logs')
+            [CompletionResult]::new('--warmup', 'warmup', [CompletionResultType]::ParameterName, 'Send each request NUM times before its measured run')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help')
             [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version')
             break
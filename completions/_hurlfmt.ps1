@@ -22,6 +22,7 @@ Register-ArgumentCompleter -Native -CommandName 'hurlfmt' -ScriptBlock {
         'hurlfmt'
          {[CompletionResult]::new('--check', 'check', [CompletionResultType]::ParameterName, 'Run in check mode')
             [CompletionResult]::new('--color', 'color', [CompletionResultType]::ParameterName, 'Colorize Output')
+            [CompletionResult]::new('--diff', 'diff', [CompletionResultType]::ParameterName, 'Print a diff of the changes in check mode')
             [CompletionResult]::new('--in-place', 'in-place', [CompletionResultType]::ParameterName, 'Modify files in place')
             [CompletionResult]::new('--in', 'in', [CompletionResultType]::ParameterName, 'Specify input format: hurl or curl')
             [CompletionResult]::new('--no-color', 'no-color', [CompletionResultType]::ParameterName, 'Do not colorize output')
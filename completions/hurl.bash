@@ -5,7 +5,7 @@ _hurl()
     _init_completion || return
 
     if [[ $cur == -* ]]; then
-        COMPREPLY=($(compgen -W '--aws-sigv4 --cacert --cert --key --color --compressed --connect-timeout --connect-to --continue-on-error --cookie --cookie-jar --curl --delay --error-format --file-root --location --location-trusted --from-entry --glob --header --http1.0 --http1.1 --http2 --http3 --ignore-asserts --include --insecure --interactive --ipv4 --ipv6 --jobs --json --limit-rate --max-filesize --max-redirs --max-time --netrc --netrc-file --netrc-optional --no-color --no-output --noproxy --output --parallel --path-as-is --proxy --repeat --report-html --report-json --report-junit --report-tap --resolve --retry --retry-interval --secret --ssl-no-revoke --test --to-entry --unix-socket --user --user-agent --variable --variables-file --verbose --very-verbose --help --version' -- "$cur"))
+        COMPREPLY=($(compgen -W '--assert --aws-sigv4 --benchmark --cacert --cert --key --color --compressed --concurrency-per-file --connect-timeout --connect-to --continue-on-error --cookie-jar-format --cookie --cookie-jar --curl --delay --delay-jitter --delay-seed --dns-servers --dump-request --error-format --fail-fast --fail-if-empty --features --file-root --location --location-trusted --from-curl --from-entry --glob --header --header-for-host --http1.0 --http1.1 --http2 --http3 --ignore-asserts --ignore-trailing-newline --include --insecure --insecure-host --interactive --ipv4 --ipv6 --jobs --json --json-report-ndjson --junit-classname --limit-rate --max-entries --max-filesize --max-redirs --max-time --netrc --netrc-file --netrc-optional --no-color --no-fail-fast --no-output --noproxy --output --output-append --output-dir --parallel --path-as-is --progress-format --proxy --repeat --report-html --report-json --report-junit --report-tap --request-charset --require-http-version --resolve --response-charset --retry --retry-backoff --retry-interval --retry-max-interval --retry-on-status --secret --ssl-no-revoke --tap-granularity --tap-verbose --test --to-entry --unix-socket --user --user-agent --variable --variable-from-file --variables-file --verbose --very-verbose --warmup --help --version' -- "$cur"))
         return
     fi
  
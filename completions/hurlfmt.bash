@@ -5,7 +5,7 @@ _hurlfmt()
     _init_completion || return
 
     if [[ $cur == -* ]]; then
-        COMPREPLY=($(compgen -W '--check --color --in-place --in --no-color --output --out --standalone --help --version' -- "$cur"))
+        COMPREPLY=($(compgen -W '--check --color --diff --in-place --in --no-color --output --out --standalone --help --version' -- "$cur"))
         return
     fi
  
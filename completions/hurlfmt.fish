@@ -1,5 +1,6 @@
 complete -c hurlfmt -l check -d 'Run in check mode'
 complete -c hurlfmt -l color -d 'Colorize Output'
+complete -c hurlfmt -l diff -d 'Print a diff of the changes in check mode'
 complete -c hurlfmt -l in-place -d 'Modify files in place'
 complete -c hurlfmt -l in -d 'Specify input format: hurl or curl'
 complete -c hurlfmt -l no-color -d 'Do not colorize output'
@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+use hurl::http::{Call, Header, Request, Response};
 use hurl::runner;
 use hurl::runner::{
     AssertResult, CaptureResult, EntryResult, HurlResult, RunnerError, RunnerOptionsBuilder,
@@ -140,13 +141,12 @@ fn print_entry(entry: &EntryResult) {
         print(level, "errors", "");
         entry.errors.iter().for_each(print_error);
     }
-    // FIXME: Call is not public
-    // if entry.calls.is_empty() {
-    //     print(level, "calls", "-");
-    // } else {
-    //     print(level, "calls", "");
-    //     entry.calls.iter().for_each(print_call);
-    // }
+    if entry.calls.is_empty() {
+        print(level, "calls", "-");
+    } else {
+        print(level, "calls", "");
+        entry.calls.iter().for_each(print_call);
+    }
 }
 
 /// Prints a capture.
@@ -175,55 +175,55 @@ fn print_error(error: &RunnerError) {
     print_dbg(level, "type", &error.kind);
 }
 
-// /// Prints a call.
-// fn print_call(call: &Call) {
-//     let level = 2;
-//     print(level, "request", "");
-//     print_request(&call.request);
-//     print(level, "response", "");
-//     print_response(&call.response);
-// }
-//
-// /// Prints an HTTP request
-// fn print_request(request: &Request) {
-//     let level = 3;
-//     print(level, "method", &request.method);
-//     print(level, "url", &request.url);
-//     if request.headers.is_empty() {
-//         print(level, "headers", "-");
-//     } else {
-//         print(level, "headers", "");
-//         request.headers.iter().for_each(print_header);
-//     }
-//     print(level, "body (bytes)", &request.body.len().to_string());
-// }
-//
-// /// Prints an HTTP response
-// fn print_response(response: &Response) {
-//     let level = 3;
-//     print(level, "url", &response.url);
-//     print(level, "version", &response.version.to_string());
-//     print(level, "status", &response.status.to_string());
-//     if response.headers.is_empty() {
-//         print(level, "headers", "-");
-//     } else {
-//         print(level, "headers", "");
-//         response.headers.iter().for_each(print_header);
-//     }
-//     print(level, "body (bytes)", &response.body.len().to_string());
-//     print(
-//         level,
-//         "duration (ms)",
-//         &response.duration.as_millis().to_string(),
-//     );
-// }
-//
-// /// Print an HTTP header
-// fn print_header(header: &Header) {
-//     let level = 4;
-//     print(level, "name", &header.name);
-//     print(level, "value", &header.value);
-// }
+/// Prints a call.
+fn print_call(call: &Call) {
+    let level = 2;
+    print(level, "request", "");
+    print_request(&call.request);
+    print(level, "response", "");
+    print_response(&call.response);
+}
+
+/// Prints an HTTP request
+fn print_request(request: &Request) {
+    let level = 3;
+    print(level, "method", &request.method);
+    print(level, "url", &request.url.to_string());
+    if request.headers.is_empty() {
+        print(level, "headers", "-");
+    } else {
+        print(level, "headers", "");
+        request.headers.iter().for_each(print_header);
+    }
+    print(level, "body (bytes)", &request.body.len().to_string());
+}
+
+/// Prints an HTTP response
+fn print_response(response: &Response) {
+    let level = 3;
+    print(level, "url", &response.url.to_string());
+    print(level, "version", &response.version.to_string());
+    print(level, "status", &response.status.to_string());
+    if response.headers.is_empty() {
+        print(level, "headers", "-");
+    } else {
+        print(level, "headers", "");
+        response.headers.iter().for_each(print_header);
+    }
+    print(level, "body (bytes)", &response.body.len().to_string());
+    print(
+        level,
+        "duration (ms)",
+        &response.duration.as_millis().to_string(),
+    );
+}
+
+/// Print an HTTP header
+fn print_header(header: &Header) {
+    let level = 4;
+    print(level, "name", &header.name);
+    print(level, "value", &header.value);
+}
 
 fn print(level: usize, key: &str, value: &str) {
     let prefix = " ".repeat(level * 2);
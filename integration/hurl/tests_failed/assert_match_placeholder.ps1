@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --variable 'pattern=\d{4}-(' tests_failed/assert_match_placeholder.hurl
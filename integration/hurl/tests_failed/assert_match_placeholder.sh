@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable 'pattern=\d{4}-(' tests_failed/assert_match_placeholder.hurl
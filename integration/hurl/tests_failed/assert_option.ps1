@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --continue-on-error --assert 'status < 400' --assert 'body contains "e"' tests_failed/assert_option.hurl
@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-option/ok")
+def assert_option_ok():
+    return "Hello"
+
+
+@app.route("/assert-option/error")
+def assert_option_error():
+    return Response("Internal Server Error", status=500)
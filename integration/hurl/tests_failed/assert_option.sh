@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --continue-on-error --assert 'status < 400' --assert 'body contains "e"' tests_failed/assert_option.hurl
@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-server-timing-not-found")
+def error_assert_server_timing_not_found():
+    return Response("Hello", headers={"Server-Timing": "db;dur=53"})
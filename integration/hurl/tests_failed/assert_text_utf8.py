@@ -0,0 +1,9 @@
+from app import app
+from flask import make_response
+
+
+@app.route("/error-assert/text-utf8")
+def error_assert_text_utf8():
+    resp = make_response(b"caf\xe9 latte")
+    resp.headers["Content-Type"] = "text/plain; charset=utf-8"
+    return resp
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --connect-to example.com:80:localhost tests_ok/hello.hurl
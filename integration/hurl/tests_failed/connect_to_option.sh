@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --connect-to example.com:80:localhost tests_ok/hello.hurl
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --test --cookie-jar build/cookies_parallel.txt tests_ok/hello.hurl tests_ok/hello.hurl
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --test --cookie-jar build/cookies_parallel.txt tests_ok/hello.hurl tests_ok/hello.hurl
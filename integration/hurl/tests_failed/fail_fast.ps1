@@ -0,0 +1,6 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --test --jobs 1 --fail-fast `
+  tests_failed/fail_fast.hurl `
+  tests_ok/parallel_a.hurl `
+  tests_ok/parallel_b.hurl
@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --test --jobs 1 --fail-fast \
+  tests_failed/fail_fast.hurl \
+  tests_ok/parallel_a.hurl \
+  tests_ok/parallel_b.hurl
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+echo "curl 'http://localhost:8000/hello" | hurl --from-curl
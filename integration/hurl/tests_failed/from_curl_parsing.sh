@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+echo "curl 'http://localhost:8000/hello" | hurl --from-curl
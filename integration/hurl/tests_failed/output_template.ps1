@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --output 'build/output_{{slug}}.json' tests_failed/output_template.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --output 'build/output_{{slug}}.json' tests_failed/output_template.hurl
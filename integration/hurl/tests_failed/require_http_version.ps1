@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --http1.0 --require-http-version tests_failed/require_http_version.hurl
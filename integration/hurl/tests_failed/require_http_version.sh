@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --http1.0 --require-http-version tests_failed/require_http_version.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_failed/retry_on_status.hurl --retry 5 --retry-interval 100 --retry-on-status 429,503
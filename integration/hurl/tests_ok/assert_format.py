@@ -0,0 +1,29 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-format")
+def assert_format():
+    return Response(
+        """{
+  "id": "123e4567-e89b-12d3-a456-426614174000",
+  "email": "bob@example.org",
+  "homepage": "https://example.org/bob",
+  "ipv4": "192.168.0.1",
+  "ipv6": "2001:db8::1",
+  "name": "Bob"
+}""",
+        mimetype="application/json",
+    )
+
+
+@app.route("/assert-format/date")
+def assert_format_date():
+    return Response(
+        """{
+  "birthday": "1983-04-13",
+  "leap_day": "2023-02-29",
+  "updated": "13/04/1983 12:09"
+}""",
+        mimetype="application/json",
+    )
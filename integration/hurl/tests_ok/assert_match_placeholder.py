@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-match/config")
+def assert_match_config():
+    return Response(
+        """{
+  "pattern": "^\\\\d{4}-\\\\d{2}-\\\\d{2}$"
+}""",
+        mimetype="application/json",
+    )
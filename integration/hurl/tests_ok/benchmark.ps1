@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --benchmark --repeat 10 tests_ok/benchmark.hurl
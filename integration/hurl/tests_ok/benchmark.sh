@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --benchmark --repeat 10 tests_ok/benchmark.hurl
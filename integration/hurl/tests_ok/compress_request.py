@@ -0,0 +1,17 @@
+import gzip
+import zlib
+
+from app import app
+from flask import request
+
+
+@app.route("/compress-request", methods=["POST"])
+def compress_request():
+    encoding = request.headers.get("Content-Encoding")
+    data = request.get_data()
+    assert int(request.headers["Content-Length"]) == len(data)
+    if encoding == "gzip":
+        data = gzip.decompress(data)
+    elif encoding == "deflate":
+        data = zlib.decompress(data)
+    return f"{encoding}: {data.decode()}"
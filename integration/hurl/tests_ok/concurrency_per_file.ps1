@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --concurrency-per-file 3 --test tests_ok/concurrency_per_file.hurl
@@ -0,0 +1,17 @@
+from threading import Barrier, BrokenBarrierError
+
+from app import app
+from flask import Response
+
+# The barrier is only crossed if 3 requests are received at the same time.
+barrier = Barrier(3, timeout=2)
+
+
+@app.route("/concurrency-per-file")
+def concurrency_per_file():
+    try:
+        barrier.wait()
+    except BrokenBarrierError:
+        barrier.reset()
+        return Response("Requests are not concurrent", status=500)
+    return "Hello World!"
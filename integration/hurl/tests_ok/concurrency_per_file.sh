@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --concurrency-per-file 3 --test tests_ok/concurrency_per_file.hurl
@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/concurrency_per_file_cookies.txt) {
+    Remove-Item build/concurrency_per_file_cookies.txt
+}
+hurl --concurrency-per-file 3 --cookie-jar build/concurrency_per_file_cookies.txt --no-output tests_ok/concurrency_per_file_cookies.hurl
+Write-Host (Get-Content build/concurrency_per_file_cookies.txt -Raw) -NoNewLine
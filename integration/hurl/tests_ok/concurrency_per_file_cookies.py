@@ -0,0 +1,13 @@
+from app import app
+from flask import make_response
+
+
+@app.route("/concurrency-per-file/cookies")
+def concurrency_per_file_cookies():
+    resp = make_response()
+    resp.set_cookie(
+        "session",
+        "A1B2C3",
+        path="/",
+    )
+    return resp
@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/concurrency_per_file_cookies.txt
+hurl --concurrency-per-file 3 --cookie-jar build/concurrency_per_file_cookies.txt --no-output tests_ok/concurrency_per_file_cookies.hurl
+cat build/concurrency_per_file_cookies.txt
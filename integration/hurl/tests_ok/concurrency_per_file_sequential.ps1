@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --concurrency-per-file 2 --no-output tests_ok/concurrency_per_file_sequential.hurl
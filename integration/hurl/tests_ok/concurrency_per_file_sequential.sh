@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --concurrency-per-file 2 --no-output tests_ok/concurrency_per_file_sequential.hurl
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/connect_to_wildcard.hurl --connect-to '*:80:localhost:8000' --verbose
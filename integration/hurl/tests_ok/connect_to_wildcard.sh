@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/connect_to_wildcard.hurl --connect-to '*:80:localhost:8000' --verbose
@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/cookies.json) {
+    Remove-Item build/cookies.json
+}
+hurl --cookie-jar build/cookies.json --cookie-jar-format json --secret lsid=DQAAAKEaem_vYg --no-output tests_ok/cookie_jar_json.hurl
+Write-Host (Get-Content build/cookies.json -Raw) -NoNewLine
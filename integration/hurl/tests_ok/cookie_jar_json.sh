@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/cookies.json
+hurl --cookie-jar build/cookies.json --cookie-jar-format json --secret lsid=DQAAAKEaem_vYg --no-output tests_ok/cookie_jar_json.hurl
+cat build/cookies.json
@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/cookies_multiple_files.txt) {
+    Remove-Item build/cookies_multiple_files.txt
+}
+hurl --cookie-jar build/cookies_multiple_files.txt --no-output tests_ok/cookie_jar_multiple_files_login.hurl tests_ok/cookie_jar_multiple_files.hurl
+Write-Host (Get-Content build/cookies_multiple_files.txt -Raw) -NoNewLine
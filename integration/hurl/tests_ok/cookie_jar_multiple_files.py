@@ -0,0 +1,27 @@
+from app import app
+from flask import make_response, request
+
+
+@app.route("/cookie-jar-multiple-files/login", methods=["POST"])
+def cookie_jar_multiple_files_login():
+    resp = make_response()
+    resp.set_cookie(
+        "session",
+        "A1B2C3",
+        path="/",
+    )
+    return resp
+
+
+@app.route("/cookie-jar-multiple-files/profile")
+def cookie_jar_multiple_files_profile():
+    session = request.cookies.get("session")
+    if session is None:
+        return "Unauthorized", 401
+    resp = make_response(f"Hello {session}")
+    resp.set_cookie(
+        "theme",
+        "dark",
+        path="/",
+    )
+    return resp
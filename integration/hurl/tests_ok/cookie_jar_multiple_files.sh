@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/cookies_multiple_files.txt
+hurl --cookie-jar build/cookies_multiple_files.txt --no-output tests_ok/cookie_jar_multiple_files_login.hurl tests_ok/cookie_jar_multiple_files.hurl
+cat build/cookies_multiple_files.txt
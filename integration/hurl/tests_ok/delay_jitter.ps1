@@ -0,0 +1,6 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# With a seed, the randomized delays are the same on each run. No jitter is added to a zero delay.
+hurl --verbose --delay 10 --delay-jitter 100 --delay-seed 42 tests_ok/delay_jitter.hurl 2>build/delay_jitter.err
+Get-Content build/delay_jitter.err | Select-String -CaseSensitive 'Delay entry' | ForEach-Object { $_.Line }
@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# With a seed, the randomized delays are the same on each run. No jitter is added to a zero delay.
+hurl --verbose --delay 10 --delay-jitter 100 --delay-seed 42 tests_ok/delay_jitter.hurl 2>build/delay_jitter.err
+grep "Delay entry" build/delay_jitter.err
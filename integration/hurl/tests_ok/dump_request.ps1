@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/dump_request.txt) {
+    Remove-Item build/dump_request.txt
+}
+
+hurl --dump-request build/dump_request.txt tests_ok/dump_request.hurl
+Write-Host (Get-Content build/dump_request.txt -Raw) -NoNewLine
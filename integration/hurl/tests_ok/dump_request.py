@@ -0,0 +1,10 @@
+from app import app
+from flask import request
+
+
+@app.route("/dump-request", methods=["POST"])
+def dump_request():
+    assert request.headers["Content-Type"] == "application/json"
+    assert request.headers["X-Custom"] == "foo"
+    assert request.data == b'{"name": "Bob"}'
+    return ""
@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/dump_request.txt
+
+hurl --dump-request build/dump_request.txt tests_ok/dump_request.hurl
+cat build/dump_request.txt
@@ -0,0 +1,14 @@
+from app import app
+from flask import Response
+
+
+@app.route("/filter-decode-auto/latin1")
+def filter_decode_auto_latin1():
+    headers = {"Content-Type": "text/plain; charset=ISO-8859-1"}
+    return Response("café".encode("iso-8859-1"), headers=headers)
+
+
+@app.route("/filter-decode-auto/no-charset")
+def filter_decode_auto_no_charset():
+    headers = {"Content-Type": "text/plain"}
+    return Response("café".encode("utf-8"), headers=headers)
@@ -0,0 +1,8 @@
+from app import app
+from flask import Response
+
+
+@app.route("/filter-jsonl")
+def filter_jsonl():
+    body = '{"id": "abc", "value": 1}\n{"id": "def", "value": 2}\n{"id": "ghi", "value": 3}\n'
+    return Response(body, mimetype="application/x-ndjson")
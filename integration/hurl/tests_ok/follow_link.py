@@ -0,0 +1,30 @@
+from app import app
+from flask import jsonify, request
+
+PAGES = [["a", "b", "c"], ["d", "e", "f"], ["g"]]
+served = {"pages": 0, "items": 0}
+
+
+@app.route("/follow-link/reset")
+def follow_link_reset():
+    served["pages"] = 0
+    served["items"] = 0
+    return ""
+
+
+@app.route("/follow-link/items")
+def follow_link_items():
+    page = int(request.args.get("page", "1"))
+    items = PAGES[page - 1]
+    served["pages"] += 1
+    served["items"] += len(items)
+    response = jsonify({"page": page, "items": items})
+    if page < len(PAGES):
+        # Links can be relative to the request URL.
+        response.headers["Link"] = f'<items?page={page + 1}>; rel="next"'
+    return response
+
+
+@app.route("/follow-link/served")
+def follow_link_served():
+    return jsonify(served)
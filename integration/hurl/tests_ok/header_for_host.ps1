@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --header 'X-Global: 1' --header-for-host 'localhost:Authorization: Bearer x' tests_ok/header_for_host.hurl
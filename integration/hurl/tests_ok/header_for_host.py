@@ -0,0 +1,13 @@
+from app import app
+from flask import Response, request
+
+
+@app.route("/header-for-host")
+def header_for_host():
+    # Echoes received headers as response headers
+    headers = {}
+    authorization = request.headers.get("Authorization")
+    if authorization is not None:
+        headers["X-Authorization"] = authorization
+    headers["X-Global"] = request.headers.get("X-Global", "")
+    return Response(headers=headers)
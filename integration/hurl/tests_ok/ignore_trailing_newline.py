@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/ignore_trailing_newline")
+def ignore_trailing_newline():
+    return "Hello World!"
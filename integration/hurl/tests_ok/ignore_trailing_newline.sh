@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/ignore_trailing_newline.hurl --ignore-trailing-newline
@@ -0,0 +1,20 @@
+from app import app
+from flask import Response
+
+
+@app.route("/jsonpointer")
+def jsonpointer():
+    return Response(
+        """{
+  "books": [
+    { "title": "Dune", "tags": ["sf", "classic"] },
+    { "title": "Neuromancer", "tags": ["cyberpunk"] }
+  ],
+  "links": {
+    "application/json": "http://localhost:8000/jsonpointer",
+    "m~n": 8
+  },
+  "": "empty key"
+}""",
+        mimetype="application/json",
+    )
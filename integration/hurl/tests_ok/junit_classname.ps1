@@ -0,0 +1,9 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/junit_classname/result.xml) {
+    Remove-Item build/junit_classname/result.xml
+}
+
+hurl --no-output --report-junit build/junit_classname/result.xml --junit-classname 'hurl.{dir}.{stem}' tests_ok/test.1.hurl tests_ok/test.3.hurl
+
+Write-Host (Get-Content build/junit_classname/result.xml -Raw) -NoNewLine
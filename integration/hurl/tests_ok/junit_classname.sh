@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/junit_classname/result.xml
+
+hurl --no-output --report-junit build/junit_classname/result.xml --junit-classname 'hurl.{dir}.{stem}' tests_ok/test.1.hurl tests_ok/test.3.hurl
+
+cat build/junit_classname/result.xml
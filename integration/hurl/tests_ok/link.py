@@ -0,0 +1,15 @@
+from app import app
+from flask import Response, request
+
+
+@app.route("/link/items")
+def link_items():
+    page = int(request.args.get("page", "1"))
+    url = "http://localhost:8000/link/items"
+    links = [f'<{url}?page=1>; rel="first", <{url}?page=3>; rel="last"']
+    if page > 1:
+        links.append(f'<{url}?page={page - 1}>; rel="prev"')
+    if page < 3:
+        links.append(f'<{url}?page={page + 1}>; rel="next"')
+    headers = [("Link", link) for link in links]
+    return Response(f"page {page}", headers=headers)
@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/name_option/report.tap) {
+    Remove-Item build/name_option/report.tap
+}
+
+hurl --test --report-tap build/name_option/report.tap --tap-granularity entry tests_ok/name_option.hurl
+Write-Host (Get-Content build/name_option/report.tap -Raw) -NoNewLine
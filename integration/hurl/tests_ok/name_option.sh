@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/name_option/report.tap
+
+hurl --test --report-tap build/name_option/report.tap --tap-granularity entry tests_ok/name_option.hurl
+cat build/name_option/report.tap
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --proxy 127.0.0.1:1 tests_ok/noproxy_option.hurl
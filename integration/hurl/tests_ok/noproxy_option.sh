@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --proxy 127.0.0.1:1 tests_ok/noproxy_option.hurl
@@ -0,0 +1,10 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# We test that --output-append appends an existing file instead of truncating it, run after run.
+
+echo "Not a response" > build/output_append.bin
+
+hurl --output build/output_append.bin --output-append tests_ok/output_existing.hurl
+hurl --output build/output_append.bin --output-append tests_ok/output_existing.hurl
+Write-Host (Get-Content build/output_append.bin -Raw) -NoNewLine
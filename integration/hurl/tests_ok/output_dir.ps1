@@ -0,0 +1,9 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/output_dir) {
+    Remove-Item -Recurse build/output_dir
+}
+hurl --no-output --file-root build --output-dir build/output_dir tests_ok/output_dir.hurl
+Write-Host (Get-Content build/output_dir/1.bin -Raw) -NoNewLine
+Write-Host (Get-Content build/output_dir/endpoint3.txt -Raw) -NoNewLine
+Write-Host (Get-Content build/output_dir/3.bin -Raw) -NoNewLine
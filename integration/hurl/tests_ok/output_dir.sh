@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/output_dir
+hurl --no-output --file-root build --output-dir build/output_dir tests_ok/output_dir.hurl
+cat build/output_dir/1.bin build/output_dir/endpoint3.txt build/output_dir/3.bin
@@ -0,0 +1,12 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/output_dir_files) {
+    Remove-Item -Recurse build/output_dir_files
+}
+hurl --no-output --file-root build --output-dir build/output_dir_files tests_ok/output_dir.hurl tests_ok/output_dir_files.hurl
+Write-Host (Get-Content build/output_dir_files/1-output_dir/1.bin -Raw) -NoNewLine
+Write-Host (Get-Content build/output_dir_files/2-output_dir_files/1.bin -Raw) -NoNewLine
+Remove-Item -Recurse build/output_dir_files
+hurl --parallel --no-output --file-root build --output-dir build/output_dir_files tests_ok/output_dir.hurl tests_ok/output_dir_files.hurl
+Write-Host (Get-Content build/output_dir_files/1-output_dir/1.bin -Raw) -NoNewLine
+Write-Host (Get-Content build/output_dir_files/2-output_dir_files/1.bin -Raw) -NoNewLine
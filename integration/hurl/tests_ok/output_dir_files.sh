@@ -0,0 +1,8 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/output_dir_files
+hurl --no-output --file-root build --output-dir build/output_dir_files tests_ok/output_dir.hurl tests_ok/output_dir_files.hurl
+cat build/output_dir_files/1-output_dir/1.bin build/output_dir_files/2-output_dir_files/1.bin
+rm -rf build/output_dir_files
+hurl --parallel --no-output --file-root build --output-dir build/output_dir_files tests_ok/output_dir.hurl tests_ok/output_dir_files.hurl
+cat build/output_dir_files/1-output_dir/1.bin build/output_dir_files/2-output_dir_files/1.bin
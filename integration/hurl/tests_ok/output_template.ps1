@@ -0,0 +1,9 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/output_hello-world.json) {
+    Remove-Item build/output_hello-world.json
+}
+
+hurl --output 'build/output_{{slug}}.json' tests_ok/output_template.hurl
+Write-Host (Get-Content build/output_hello-world.json -Raw) -NoNewLine
@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/output/article")
+def output_article():
+    return Response(
+        '{"slug":"hello-world","title":"Hello World"}\n',
+        mimetype="application/json",
+    )
@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/output_hello-world.json
+
+hurl --output 'build/output_{{slug}}.json' tests_ok/output_template.hurl
+cat build/output_hello-world.json
@@ -0,0 +1,28 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# In parallel mode, each log line of a file run is prefixed with the worker id and the file.
+hurl --parallel --jobs 2 --very-verbose `
+    tests_ok/parallel_correlation_id.hurl `
+    tests_ok/hello.hurl `
+    2>build/parallel_correlation_id.err
+
+$file = "build/parallel_correlation_id.err"
+
+if (-not (Get-Content $file | Select-String -CaseSensitive '^\[worker=\d+ file=tests_ok/parallel_correlation_id.hurl\] > GET /parallel/correlation-id HTTP/1.1$')) {
+    echo "Missing correlation id for tests_ok/parallel_correlation_id.hurl in $file"
+    Get-Content $file
+    exit 1
+}
+
+if (-not (Get-Content $file | Select-String -CaseSensitive '^\[worker=\d+ file=tests_ok/hello.hurl\] > GET /hello HTTP/1.1$')) {
+    echo "Missing correlation id for tests_ok/hello.hurl in $file"
+    Get-Content $file
+    exit 1
+}
+
+if (Get-Content $file | Select-String -CaseSensitive '^\[worker=\d+ file=tests_ok/hello.hurl\].*correlation-id') {
+    echo "Wrong correlation id for tests_ok/hello.hurl in $file"
+    Get-Content $file
+    exit 1
+}
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/parallel/correlation-id")
+def parallel_correlation_id():
+    return "Hello"
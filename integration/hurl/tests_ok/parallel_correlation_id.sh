@@ -0,0 +1,28 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# In parallel mode, each log line of a file run is prefixed with the worker id and the file.
+hurl --parallel --jobs 2 --very-verbose \
+    tests_ok/parallel_correlation_id.hurl \
+    tests_ok/hello.hurl \
+    2>build/parallel_correlation_id.err
+
+file="build/parallel_correlation_id.err"
+
+if ! grep -q '^\[worker=[0-9]* file=tests_ok/parallel_correlation_id.hurl\] > GET /parallel/correlation-id HTTP/1.1$' "$file"; then
+    echo "Missing correlation id for tests_ok/parallel_correlation_id.hurl in $file"
+    cat "$file"
+    exit 1
+fi
+
+if ! grep -q '^\[worker=[0-9]* file=tests_ok/hello.hurl\] > GET /hello HTTP/1.1$' "$file"; then
+    echo "Missing correlation id for tests_ok/hello.hurl in $file"
+    cat "$file"
+    exit 1
+fi
+
+if grep '^\[worker=[0-9]* file=tests_ok/hello.hurl\]' "$file" | grep -q 'correlation-id'; then
+    echo "Wrong correlation id for tests_ok/hello.hurl in $file"
+    cat "$file"
+    exit 1
+fi
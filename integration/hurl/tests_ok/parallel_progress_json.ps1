@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# Each file completion is reported as a JSON line on standard error.
+hurl --parallel --jobs 1 --no-output --progress-format json `
+  tests_ok/parallel_a.hurl `
+  tests_ok/parallel_b.hurl `
+  tests_ok/parallel_c.hurl
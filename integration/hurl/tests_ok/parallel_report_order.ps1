@@ -0,0 +1,34 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/parallel_report_order) {
+    Remove-Item -Recurse build/parallel_report_order
+}
+New-Item -ItemType Directory -Force -Path build/parallel_report_order | Out-Null
+
+# The first file is the slowest one and completes last, but reports keep the command line order.
+# Reports must also be the same whatever the number of workers is.
+foreach ($jobs in 1, 4) {
+    hurl --parallel --jobs $jobs --no-output `
+      --report-tap build/parallel_report_order/report_$jobs.tap `
+      --report-junit build/parallel_report_order/report_$jobs.xml `
+      tests_ok/parallel_report_order.hurl `
+      tests_ok/parallel_a.hurl `
+      tests_ok/parallel_b.hurl `
+      tests_ok/parallel_c.hurl
+}
+
+$tap_1 = Get-Content build/parallel_report_order/report_1.tap -Raw
+$tap_4 = Get-Content build/parallel_report_order/report_4.tap -Raw
+if ($tap_1 -ne $tap_4) {
+    echo "TAP reports differ between 1 and 4 jobs"
+    exit 1
+}
+$testcases_1 = Select-String -Path build/parallel_report_order/report_1.xml -Pattern '<testcase id="[^"]*"' -AllMatches | ForEach-Object { $_.Matches.Value }
+$testcases_4 = Select-String -Path build/parallel_report_order/report_4.xml -Pattern '<testcase id="[^"]*"' -AllMatches | ForEach-Object { $_.Matches.Value }
+if (Compare-Object $testcases_1 $testcases_4 -SyncWindow 0) {
+    echo "JUnit reports differ between 1 and 4 jobs"
+    exit 1
+}
+
+Write-Host $tap_4 -NoNewLine
+$testcases_4 | ForEach-Object { Write-Host $_ }
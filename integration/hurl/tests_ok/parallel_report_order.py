@@ -0,0 +1,9 @@
+import time
+
+from app import app
+
+
+@app.route("/parallel/slow")
+def parallel_slow():
+    time.sleep(0.5)
+    return "Hello from a slow parallel world!\n"
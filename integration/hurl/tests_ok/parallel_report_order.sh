@@ -0,0 +1,23 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/parallel_report_order
+mkdir -p build/parallel_report_order
+
+# The first file is the slowest one and completes last, but reports keep the command line order.
+# Reports must also be the same whatever the number of workers is.
+for jobs in 1 4; do
+  hurl --parallel --jobs "$jobs" --no-output \
+    --report-tap "build/parallel_report_order/report_$jobs.tap" \
+    --report-junit "build/parallel_report_order/report_$jobs.xml" \
+    tests_ok/parallel_report_order.hurl \
+    tests_ok/parallel_a.hurl \
+    tests_ok/parallel_b.hurl \
+    tests_ok/parallel_c.hurl
+done
+
+diff build/parallel_report_order/report_1.tap build/parallel_report_order/report_4.tap
+diff <(grep -o '<testcase id="[^"]*"' build/parallel_report_order/report_1.xml) \
+     <(grep -o '<testcase id="[^"]*"' build/parallel_report_order/report_4.xml)
+
+cat build/parallel_report_order/report_4.tap
+grep -o '<testcase id="[^"]*"' build/parallel_report_order/report_4.xml
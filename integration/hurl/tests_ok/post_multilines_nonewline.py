@@ -0,0 +1,16 @@
+from app import app
+from flask import request
+
+
+@app.route("/post-multilines-nonewline", methods=["POST"])
+def post_multilines_nonewline():
+    s = request.data.decode("utf-8")
+    assert s == "name,age\nbob,10\nbill,22"
+    return "Hello World!"
+
+
+@app.route("/post-multilines-nonewline-json", methods=["POST"])
+def post_multilines_nonewline_json():
+    s = request.data.decode("utf-8")
+    assert s == '{"g_clef": "\U0001d11e"}'
+    return "Hello World!"
@@ -12,3 +12,4 @@ hurl --test --report-json build/report/json tests_ok/test.3.hurl
 $ErrorActionPreference = 'Stop'
 
 Write-Host (Get-Content build/report/json/report.json -Raw) -NoNewLine
+Write-Host (Get-Content build/report/json/summary.json -Raw) -NoNewLine
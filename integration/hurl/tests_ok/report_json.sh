@@ -10,3 +10,4 @@ hurl --test --report-json build/report/json tests_ok/test.3.hurl
 set -Eeuo pipefail
 
 cat build/report/json/report.json
+cat build/report/json/summary.json
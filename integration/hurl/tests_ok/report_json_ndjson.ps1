@@ -0,0 +1,12 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/report/ndjson) {
+    Remove-Item -Recurse build/report/ndjson
+}
+
+# Each run is appended to the report, in sequential and parallel mode.
+hurl --json-report-ndjson build/report/ndjson/report.ndjson --no-output tests_ok/test.3.hurl
+hurl --test --json-report-ndjson build/report/ndjson/report.ndjson tests_ok/test.3.hurl
+
+Write-Host (Get-Content build/report/ndjson/report.ndjson -Raw) -NoNewLine
@@ -0,0 +1,10 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/report/ndjson
+
+# Each run is appended to the report, in sequential and parallel mode.
+hurl --json-report-ndjson build/report/ndjson/report.ndjson --no-output tests_ok/test.3.hurl
+hurl --test --json-report-ndjson build/report/ndjson/report.ndjson tests_ok/test.3.hurl
+
+cat build/report/ndjson/report.ndjson
@@ -0,0 +1,11 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/report/json_request_body) {
+    Remove-Item -Recurse build/report/json_request_body
+}
+
+# The rendered request body is exported in the report, with secrets redacted.
+hurl --test --variable user=bob --secret password=s3cr3t --report-json build/report/json_request_body tests_ok/report_json_request_body.hurl
+
+Write-Host (Get-Content build/report/json_request_body/report.json -Raw) -NoNewLine
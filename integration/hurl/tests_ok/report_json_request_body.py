@@ -0,0 +1,8 @@
+from app import app
+from flask import request
+
+
+@app.route("/report-json-request-body", methods=["POST"])
+def report_json_request_body():
+    assert request.json == {"user": "bob", "password": "s3cr3t"}
+    return ""
@@ -0,0 +1,9 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/report/json_request_body
+
+# The rendered request body is exported in the report, with secrets redacted.
+hurl --test --variable user=bob --secret password=s3cr3t --report-json build/report/json_request_body tests_ok/report_json_request_body.hurl
+
+cat build/report/json_request_body/report.json
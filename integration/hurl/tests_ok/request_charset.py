@@ -0,0 +1,14 @@
+from app import app
+from flask import request
+
+
+@app.route("/request-charset/shift-jis", methods=["POST"])
+def request_charset_shift_jis():
+    assert request.data == "こんにちは世界".encode("shift_jis")
+    return ""
+
+
+@app.route("/request-charset/latin1", methods=["POST"])
+def request_charset_latin1():
+    assert request.data == '{"name":"café"}'.encode("latin-1")
+    return ""
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --http1.1 --require-http-version tests_ok/require_http_version.hurl
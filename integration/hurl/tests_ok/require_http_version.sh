@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --http1.1 --require-http-version tests_ok/require_http_version.hurl
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --response-charset ISO-8859-1 --test tests_ok/response_charset.hurl
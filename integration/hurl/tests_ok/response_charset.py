@@ -0,0 +1,19 @@
+from app import app
+from flask import Response
+
+
+@app.route("/response-charset/latin1")
+def response_charset_latin1():
+    # A latin-1 body, mislabelled as UTF-8
+    return Response(
+        "<p>café</p>".encode("latin-1"),
+        headers={"Content-Type": "text/html; charset=utf-8"},
+    )
+
+
+@app.route("/response-charset/utf8")
+def response_charset_utf8():
+    return Response(
+        "<p>café</p>".encode("utf-8"),
+        headers={"Content-Type": "text/html; charset=utf-8"},
+    )
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --response-charset ISO-8859-1 --test tests_ok/response_charset.hurl
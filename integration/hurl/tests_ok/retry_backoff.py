@@ -0,0 +1,27 @@
+# return 500 for the first three requests, then 200
+from app import app
+from flask import Response
+
+backoff_count = 0
+retry_after_count = 0
+
+
+@app.route("/retry/backoff")
+def retry_backoff():
+    global backoff_count
+    backoff_count += 1
+    if backoff_count < 4:
+        return "", 500
+    backoff_count = 0
+    return "OK", 200
+
+
+# return 503 with a `Retry-After` header for the first request, then 200
+@app.route("/retry/retry-after")
+def retry_after():
+    global retry_after_count
+    retry_after_count += 1
+    if retry_after_count < 2:
+        return Response("", status=503, headers={"Retry-After": "0"})
+    retry_after_count = 0
+    return "OK", 200
@@ -0,0 +1,22 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# With an exponential backoff, the retry interval doubles after each retry, up to the maximum
+# retry interval. A `Retry-After` response header overrides the computed interval.
+hurl --verbose --retry 5 --retry-interval 100 --retry-backoff exponential --retry-max-interval 300 \
+    tests_ok/retry_backoff.hurl \
+    2>build/retry_backoff.err
+
+file="build/retry_backoff.err"
+
+expected="* Retry entry 1 (x1 pause 100 ms)
+* Retry entry 1 (x2 pause 200 ms)
+* Retry entry 1 (x3 pause 300 ms)
+* Retry entry 2 (x1 pause 0 ms)"
+actual=$(grep '^\* Retry entry' "$file")
+
+if [ "$actual" != "$expected" ]; then
+    echo "Unexpected retry intervals in $file"
+    cat "$file"
+    exit 1
+fi
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/retry_on_status.hurl --retry 5 --retry-interval 100 --retry-on-status 429,503
@@ -0,0 +1,14 @@
+# return 429 for the first two requests, then 200
+from app import app
+
+count = 0
+
+
+@app.route("/retry/on-status")
+def retry_on_status():
+    global count
+    count += 1
+    if count < 3:
+        return "", 429
+    count = 0
+    return "OK", 200
@@ -0,0 +1,11 @@
+from app import app
+from flask import Response
+
+
+@app.route("/server-timing")
+def server_timing():
+    headers = [
+        ("Server-Timing", "db;dur=53, app;dur=47.2"),
+        ("Server-Timing", 'cache;desc="Cache Read", total;dur=120.5'),
+    ]
+    return Response("Hello", headers=headers)
@@ -0,0 +1,20 @@
+from app import app
+from flask import request
+
+
+@app.route("/strip-newlines/preserved", methods=["POST"])
+def strip_newlines_preserved():
+    assert request.data == b"line1\nline2\nline3\n"
+    return ""
+
+
+@app.route("/strip-newlines/preserved-file", methods=["POST"])
+def strip_newlines_preserved_file():
+    assert request.data == b"line1\nline2\r\nline3\n"
+    return ""
+
+
+@app.route("/strip-newlines/stripped", methods=["POST"])
+def strip_newlines_stripped():
+    assert request.data == b"line1line2line3"
+    return ""
@@ -0,0 +1,17 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/tap_granularity/entry.tap) {
+    Remove-Item build/tap_granularity/entry.tap
+}
+if (Test-Path build/tap_granularity/assert.tap) {
+    Remove-Item build/tap_granularity/assert.tap
+}
+
+# test.2.hurl is KO but we want the script to continue until the end
+$ErrorActionPreference = 'Continue'
+hurl --test --report-tap build/tap_granularity/entry.tap --tap-granularity entry tests_ok/test.1.hurl tests_ok/test.2.hurl
+hurl --test --report-tap build/tap_granularity/assert.tap --tap-granularity assert tests_ok/test.1.hurl tests_ok/test.2.hurl
+$ErrorActionPreference = 'Stop'
+
+Write-Host (Get-Content build/tap_granularity/entry.tap -Raw) -NoNewLine
+Write-Host (Get-Content build/tap_granularity/assert.tap -Raw) -NoNewLine
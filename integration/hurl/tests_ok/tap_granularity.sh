@@ -0,0 +1,11 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/tap_granularity/entry.tap build/tap_granularity/assert.tap
+
+# test.2.hurl is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --report-tap build/tap_granularity/entry.tap --tap-granularity entry tests_ok/test.1.hurl tests_ok/test.2.hurl
+hurl --test --report-tap build/tap_granularity/assert.tap --tap-granularity assert tests_ok/test.1.hurl tests_ok/test.2.hurl
+set -Eeuo pipefail
+
+cat build/tap_granularity/entry.tap build/tap_granularity/assert.tap
@@ -0,0 +1,12 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/tap_verbose/report.tap) {
+    Remove-Item build/tap_verbose/report.tap
+}
+
+# test.2.hurl is KO but we want the script to continue until the end
+$ErrorActionPreference = 'Continue'
+hurl --test --report-tap build/tap_verbose/report.tap --tap-granularity entry --tap-verbose tests_ok/test.1.hurl tests_ok/test.2.hurl
+$ErrorActionPreference = 'Stop'
+
+Write-Host (Get-Content build/tap_verbose/report.tap -Raw) -NoNewLine
@@ -0,0 +1,10 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/tap_verbose/report.tap
+
+# test.2.hurl is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --report-tap build/tap_verbose/report.tap --tap-granularity entry --tap-verbose tests_ok/test.1.hurl tests_ok/test.2.hurl
+set -Eeuo pipefail
+
+cat build/tap_verbose/report.tap
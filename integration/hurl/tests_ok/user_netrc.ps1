@@ -0,0 +1,19 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# With `--user :`, credentials are read from the default .netrc file of the user's home directory.
+if (Test-Path build/user_netrc) {
+    Remove-Item -Recurse build/user_netrc
+}
+New-Item -ItemType Directory -Force -Path build/user_netrc | Out-Null
+Copy-Item tests_ok/netrc_file.netrc build/user_netrc/.netrc
+$home_save = $env:HOME
+$env:HOME = 'build/user_netrc'
+hurl --user : tests_ok/user_netrc.hurl
+$env:HOME = $home_save
+
+# With `--user :` and `--netrc-file`, credentials are read from the given .netrc file.
+hurl --user : --netrc-file tests_ok/netrc_file.netrc tests_ok/user_netrc.hurl
+
+# Explicit `--user` credentials take precedence over .netrc entries.
+hurl --user bob@email.com:secret --netrc-file tests_ok/user_netrc_wrong.netrc tests_ok/user_netrc.hurl
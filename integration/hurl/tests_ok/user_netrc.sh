@@ -0,0 +1,14 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# With `--user :`, credentials are read from the default .netrc file of the user's home directory.
+rm -rf build/user_netrc
+mkdir -p build/user_netrc
+cp tests_ok/netrc_file.netrc build/user_netrc/.netrc
+HOME=build/user_netrc hurl --user : tests_ok/user_netrc.hurl
+
+# With `--user :` and `--netrc-file`, credentials are read from the given .netrc file.
+hurl --user : --netrc-file tests_ok/netrc_file.netrc tests_ok/user_netrc.hurl
+
+# Explicit `--user` credentials take precedence over .netrc entries.
+hurl --user bob@email.com:secret --netrc-file tests_ok/user_netrc_wrong.netrc tests_ok/user_netrc.hurl
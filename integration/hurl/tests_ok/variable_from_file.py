@@ -0,0 +1,7 @@
+from app import app
+from flask import request
+
+
+@app.route("/variable-from-file", methods=["POST"])
+def variable_from_file():
+    return request.data
@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable-from-file token=tests_ok/variable_from_file.txt \
+    --variable-from-file token_raw:raw=tests_ok/variable_from_file.txt \
+    tests_ok/variable_from_file.hurl
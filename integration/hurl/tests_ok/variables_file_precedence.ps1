@@ -0,0 +1,18 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --test `
+    --very-verbose `
+    --variables-file tests_ok/variables_file_precedence_base.properties `
+    --variables-file tests_ok/variables_file_precedence_local.properties `
+    --variable user=bob `
+    --secret token=s3cr3t `
+    tests_ok/variables_file_precedence.hurl 2>build/variables_file_precedence.err
+
+$file = "build/variables_file_precedence.err"
+
+if (Get-Content $file | Select-String -CaseSensitive "s3cr3t") {
+    echo "Secret <s3cr3t> have leaked in $file"
+    Get-Content $file
+    exit 1
+}
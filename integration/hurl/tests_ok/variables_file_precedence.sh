@@ -0,0 +1,18 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --test \
+    --very-verbose \
+    --variables-file tests_ok/variables_file_precedence_base.properties \
+    --variables-file tests_ok/variables_file_precedence_local.properties \
+    --variable user=bob \
+    --secret token=s3cr3t \
+    tests_ok/variables_file_precedence.hurl 2>build/variables_file_precedence.err
+
+file="build/variables_file_precedence.err"
+
+if grep -q "s3cr3t" "$file"; then
+    echo "Secret <s3cr3t> have leaked in $file"
+    cat "$file"
+    exit 1
+fi
@@ -0,0 +1,22 @@
+import time
+
+from app import app
+
+warmup_count = 0
+
+
+@app.route("/warmup/reset")
+def warmup_reset():
+    global warmup_count
+    warmup_count = 0
+    return ""
+
+
+@app.route("/warmup")
+def warmup():
+    global warmup_count
+    warmup_count += 1
+    # Only the first, "cold" request is slow.
+    if warmup_count == 1:
+        time.sleep(1)
+    return str(warmup_count)
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --warmup 2 --test tests_ok/warmup.hurl
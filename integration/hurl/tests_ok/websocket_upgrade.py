@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/websocket-upgrade", websocket=True)
+def websocket_upgrade():
+    headers = {
+        "Upgrade": "websocket",
+        "Connection": "Upgrade",
+        "Sec-WebSocket-Accept": "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=",
+    }
+    return Response(status=101, headers=headers)
@@ -0,0 +1,21 @@
+from app import app
+from flask import Response
+
+
+@app.route("/xpath-namespace-option")
+def xpath_namespace_option():
+    body = """<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope/">
+    <soap:Header>
+        <Auth xmlns="urn:example:auth">
+            <Token>abc</Token>
+        </Auth>
+    </soap:Header>
+    <soap:Body>
+        <GetPriceResponse xmlns="https://www.example.org/prices">
+            <Price>1.90</Price>
+        </GetPriceResponse>
+    </soap:Body>
+</soap:Envelope>
+"""
+    return Response(body, mimetype="application/soap+xml")
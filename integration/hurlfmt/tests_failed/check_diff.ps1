@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurlfmt --check --diff tests_ok/hello.hurl tests_failed/check_diff.hurl
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurlfmt --check --diff tests_ok/hello.hurl tests_failed/check_diff.hurl
@@ -50,6 +50,15 @@ impl BaseLogger {
         eprintln!("{}", s.to_string(self.format));
     }
 
+    /// Prints a warning `message` on standard error.
+    pub fn warning(&self, message: &str) {
+        let mut s = StyledString::new();
+        s.push_with("warning", Style::new().yellow().bold());
+        s.push(": ");
+        s.push_with(message, Style::new().bold());
+        eprintln!("{}", s.to_string(self.format));
+    }
+
     /// Prints an error `message` on standard error.
     pub fn error(&self, message: &str) {
         let mut s = StyledString::new();
@@ -24,4 +24,4 @@ mod summary;
 pub(crate) use self::error::CliError;
 pub(crate) use self::logger::BaseLogger;
 pub(crate) use self::options::OutputType;
-pub(crate) use self::summary::summary;
+pub(crate) use self::summary::{benchmark_summary, insecure_requests_count, summary};
@@ -26,6 +26,16 @@ pub fn input_files() -> clap::Arg {
         .num_args(1..)
 }
 
+pub fn assert() -> clap::Arg {
+    clap::Arg::new("assert")
+        .long("assert")
+        .value_name("ASSERT")
+        .help("Add an assert to every entry")
+        .help_heading("Run options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
 pub fn aws_sigv4() -> clap::Arg {
     clap::Arg::new("aws_sigv4")
         .long("aws-sigv4")
@@ -35,6 +45,14 @@ pub fn aws_sigv4() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn benchmark() -> clap::Arg {
+    clap::Arg::new("benchmark")
+        .long("benchmark")
+        .help("Display latency percentiles of each request")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn cacert_file() -> clap::Arg {
     clap::Arg::new("cacert_file")
         .long("cacert")
@@ -80,6 +98,16 @@ pub fn compressed() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn concurrency_per_file() -> clap::Arg {
+    clap::Arg::new("concurrency_per_file")
+        .long("concurrency-per-file")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u32).range(1..))
+        .help("Maximum number of entries of a file run concurrently")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn connect_timeout() -> clap::Arg {
     clap::Arg::new("connect_timeout")
         .long("connect-timeout")
@@ -108,6 +136,17 @@ pub fn continue_on_error() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn cookie_jar_format() -> clap::Arg {
+    clap::Arg::new("cookie_jar_format")
+        .long("cookie-jar-format")
+        .value_name("FORMAT")
+        .default_value("netscape")
+        .value_parser(["netscape", "json"])
+        .help("Set the format of the cookie jar file (netscape or json)")
+        .help_heading("Other options")
+        .num_args(1)
+}
+
 pub fn cookies_input_file() -> clap::Arg {
     clap::Arg::new("cookies_input_file")
         .long("cookie")
@@ -123,7 +162,7 @@ pub fn cookies_output_file() -> clap::Arg {
         .long("cookie-jar")
         .short('c')
         .value_name("FILE")
-        .help("Write cookies to FILE after running the session (only for one session)")
+        .help("Write cookies to FILE after running the session")
         .help_heading("Other options")
         .num_args(1)
 }
@@ -147,6 +186,43 @@ pub fn delay() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn delay_jitter() -> clap::Arg {
+    clap::Arg::new("delay_jitter")
+        .long("delay-jitter")
+        .value_name("MILLISECONDS")
+        .help("Adds a random delay up to MILLISECONDS to each delay")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn delay_seed() -> clap::Arg {
+    clap::Arg::new("delay_seed")
+        .long("delay-seed")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u64))
+        .help("Sets the seed used to randomize delays")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn dns_servers() -> clap::Arg {
+    clap::Arg::new("dns_servers")
+        .long("dns-servers")
+        .value_name("ADDRESSES")
+        .help("Use this list of DNS servers instead of the system default (ex: 1.1.1.1,8.8.8.8)")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
+pub fn dump_request() -> clap::Arg {
+    clap::Arg::new("dump_request")
+        .long("dump-request")
+        .value_name("FILE")
+        .help("Write the last HTTP request sent to FILE")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
@@ -158,6 +234,31 @@ pub fn error_format() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn fail_fast() -> clap::Arg {
+    clap::Arg::new("fail_fast")
+        .long("fail-fast")
+        .help("Stop running Hurl files after the first failing file")
+        .help_heading("Run options")
+        .conflicts_with("no_fail_fast")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn fail_if_empty() -> clap::Arg {
+    clap::Arg::new("fail_if_empty")
+        .long("fail-if-empty")
+        .help("Fail if a Hurl file has no entry")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn features() -> clap::Arg {
+    clap::Arg::new("features")
+        .long("features")
+        .help("List the features supported by this Hurl build and exit")
+        .help_heading("Other options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn file_root() -> clap::Arg {
     clap::Arg::new("file_root")
         .long("file-root")
@@ -184,6 +285,14 @@ pub fn follow_location_trusted() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn from_curl() -> clap::Arg {
+    clap::Arg::new("from_curl")
+        .long("from-curl")
+        .help("Read curl commands from input files and run them")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn from_entry() -> clap::Arg {
     clap::Arg::new("from_entry")
         .long("from-entry")
@@ -216,6 +325,16 @@ pub fn header() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn header_for_host() -> clap::Arg {
+    clap::Arg::new("header_for_host")
+        .long("header-for-host")
+        .value_name("HOST:HEADER")
+        .help("Pass custom header(s) to HOST only")
+        .help_heading("HTTP options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
 pub fn http10() -> clap::Arg {
     clap::Arg::new("http10")
         .long("http1.0")
@@ -257,6 +376,14 @@ pub fn ignore_asserts() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn ignore_trailing_newline() -> clap::Arg {
+    clap::Arg::new("ignore_trailing_newline")
+        .long("ignore-trailing-newline")
+        .help("Ignore a single trailing newline when comparing response bodies")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn include() -> clap::Arg {
     clap::Arg::new("include")
         .long("include")
@@ -275,6 +402,16 @@ pub fn insecure() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn insecure_host() -> clap::Arg {
+    clap::Arg::new("insecure_host")
+        .long("insecure-host")
+        .value_name("HOST")
+        .help("Allow insecure SSL connections to HOST only")
+        .help_heading("HTTP options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
 pub fn interactive() -> clap::Arg {
     clap::Arg::new("interactive")
         .long("interactive")
@@ -321,6 +458,24 @@ pub fn json() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn json_report_ndjson() -> clap::Arg {
+    clap::Arg::new("json_report_ndjson")
+        .long("json-report-ndjson")
+        .value_name("FILE")
+        .help("Append a line-delimited JSON report to FILE")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn junit_classname() -> clap::Arg {
+    clap::Arg::new("junit_classname")
+        .long("junit-classname")
+        .value_name("TEMPLATE")
+        .help("Set the classname attribute of JUnit testcases from TEMPLATE")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
 pub fn limit_rate() -> clap::Arg {
     clap::Arg::new("limit_rate")
         .long("limit-rate")
@@ -331,6 +486,16 @@ pub fn limit_rate() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn max_entries() -> clap::Arg {
+    clap::Arg::new("max_entries")
+        .long("max-entries")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u32))
+        .help("Fail if a Hurl file has more than NUM entries")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn max_filesize() -> clap::Arg {
     clap::Arg::new("max_filesize")
         .long("max-filesize")
@@ -403,6 +568,15 @@ pub fn no_color() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn no_fail_fast() -> clap::Arg {
+    clap::Arg::new("no_fail_fast")
+        .long("no-fail-fast")
+        .help("Run all Hurl files even if a file fails (default)")
+        .help_heading("Run options")
+        .conflicts_with("fail_fast")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn no_output() -> clap::Arg {
     clap::Arg::new("no_output")
         .long("no-output")
@@ -431,6 +605,23 @@ pub fn output() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn output_append() -> clap::Arg {
+    clap::Arg::new("output_append")
+        .long("output-append")
+        .help("Append to the output FILE instead of truncating it")
+        .help_heading("Output options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn output_dir() -> clap::Arg {
+    clap::Arg::new("output_dir")
+        .long("output-dir")
+        .value_name("DIR")
+        .help("Write each entry response body to a file in DIR")
+        .help_heading("Output options")
+        .num_args(1)
+}
+
 pub fn parallel() -> clap::Arg {
     clap::Arg::new("parallel")
         .long("parallel")
@@ -447,6 +638,17 @@ pub fn path_as_is() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn progress_format() -> clap::Arg {
+    clap::Arg::new("progress_format")
+        .long("progress-format")
+        .value_name("FORMAT")
+        .default_value("human")
+        .value_parser(["human", "json"])
+        .help("Format of the parallel progress reporting")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn proxy() -> clap::Arg {
     clap::Arg::new("proxy")
         .long("proxy")
@@ -504,6 +706,23 @@ pub fn report_tap() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn request_charset() -> clap::Arg {
+    clap::Arg::new("request_charset")
+        .long("request-charset")
+        .value_name("CHARSET")
+        .help("Encode text request bodies with CHARSET before sending")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
+pub fn require_http_version() -> clap::Arg {
+    clap::Arg::new("require_http_version")
+        .long("require-http-version")
+        .help("Fail if the requested HTTP version is not used")
+        .help_heading("HTTP options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn resolve() -> clap::Arg {
     clap::Arg::new("resolve")
         .long("resolve")
@@ -514,6 +733,15 @@ pub fn resolve() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn response_charset() -> clap::Arg {
+    clap::Arg::new("response_charset")
+        .long("response-charset")
+        .value_name("CHARSET")
+        .help("Decode response bodies with CHARSET, overriding the response Content-Type charset")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn retry() -> clap::Arg {
     clap::Arg::new("retry")
         .long("retry")
@@ -525,6 +753,17 @@ pub fn retry() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn retry_backoff() -> clap::Arg {
+    clap::Arg::new("retry_backoff")
+        .long("retry-backoff")
+        .value_name("STRATEGY")
+        .default_value("constant")
+        .value_parser(["constant", "exponential"])
+        .help("Strategy used to compute the interval between retries")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn retry_interval() -> clap::Arg {
     clap::Arg::new("retry_interval")
         .long("retry-interval")
@@ -535,6 +774,24 @@ pub fn retry_interval() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn retry_max_interval() -> clap::Arg {
+    clap::Arg::new("retry_max_interval")
+        .long("retry-max-interval")
+        .value_name("DURATION")
+        .help("Maximum interval before a retry")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn retry_on_status() -> clap::Arg {
+    clap::Arg::new("retry_on_status")
+        .long("retry-on-status")
+        .value_name("STATUS")
+        .help("Retry only on these response status codes (ex: 429,503)")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn secret() -> clap::Arg {
     clap::Arg::new("secret")
         .long("secret")
@@ -553,6 +810,25 @@ pub fn ssl_no_revoke() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn tap_granularity() -> clap::Arg {
+    clap::Arg::new("tap_granularity")
+        .long("tap-granularity")
+        .value_name("GRANULARITY")
+        .default_value("file")
+        .value_parser(["file", "entry", "assert"])
+        .help("Set the TAP report testpoints granularity (file, entry or assert)")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn tap_verbose() -> clap::Arg {
+    clap::Arg::new("tap_verbose")
+        .long("tap-verbose")
+        .help("Add a YAML diagnostic block to each TAP report testpoint")
+        .help_heading("Report options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn test() -> clap::Arg {
     clap::Arg::new("test")
         .long("test")
@@ -611,6 +887,16 @@ pub fn variable() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn variable_from_file() -> clap::Arg {
+    clap::Arg::new("variable_from_file")
+        .long("variable-from-file")
+        .value_name("NAME=FILE")
+        .help("Define a variable from the content of a file")
+        .help_heading("Run options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
 pub fn variables_file() -> clap::Arg {
     clap::Arg::new("variables_file")
         .long("variables-file")
@@ -637,3 +923,13 @@ pub fn very_verbose() -> clap::Arg {
         .help_heading("Output options")
         .action(clap::ArgAction::SetTrue)
 }
+
+pub fn warmup() -> clap::Arg {
+    clap::Arg::new("warmup")
+        .long("warmup")
+        .value_name("NUM")
+        .value_parser(clap::value_parser!(u32))
+        .help("Send each request NUM times before its measured run")
+        .help_heading("Run options")
+        .num_args(1)
+}
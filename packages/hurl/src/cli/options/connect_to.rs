@@ -0,0 +1,152 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use super::CliOptionsError;
+
+/// Parses a `--connect-to` value `HOST1:PORT1:HOST2:PORT2` and returns the value to pass to
+/// libcurl.
+///
+/// `HOST1` can be `*` to match any host: it's converted to an empty host, which is how libcurl
+/// expects "any host". IPv6 addresses must be enclosed in brackets, for instance `[::1]`.
+pub fn parse(s: &str) -> Result<String, CliOptionsError> {
+    let fields = split_fields(s).ok_or_else(|| shape_error(s))?;
+    let [host1, port1, host2, port2] = fields.as_slice() else {
+        return Err(shape_error(s));
+    };
+    for port in [port1, port2] {
+        if !is_valid_port(port) {
+            return Err(CliOptionsError::Error(format!(
+                "invalid port '{port}' in --connect-to '{s}'"
+            )));
+        }
+    }
+    if *host2 == "*" {
+        return Err(CliOptionsError::Error(format!(
+            "wildcard '*' is only allowed for HOST1 in --connect-to '{s}'"
+        )));
+    }
+    let host1 = if *host1 == "*" { "" } else { host1 };
+    Ok(format!("{host1}:{port1}:{host2}:{port2}"))
+}
+
+/// Splits `s` on `:`, taking care of not splitting bracketed IPv6 addresses.
+fn split_fields(s: &str) -> Option<Vec<&str>> {
+    let mut fields = vec![];
+    let mut start = 0;
+    let mut in_brackets = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            '[' if !in_brackets && i == start => in_brackets = true,
+            ']' if in_brackets => in_brackets = false,
+            ':' if !in_brackets => {
+                fields.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if in_brackets {
+        return None;
+    }
+    fields.push(&s[start..]);
+    Some(fields)
+}
+
+/// Returns `true` if `port` is empty (any port) or a valid port number.
+fn is_valid_port(port: &str) -> bool {
+    port.is_empty() || (port.chars().all(|c| c.is_ascii_digit()) && port.parse::<u16>().is_ok())
+}
+
+fn shape_error(s: &str) -> CliOptionsError {
+    CliOptionsError::Error(format!(
+        "invalid value '{s}' for --connect-to, expected HOST1:PORT1:HOST2:PORT2"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_explicit_host() {
+        assert_eq!(
+            parse("example.com:443:host-47.example.com:443").unwrap(),
+            "example.com:443:host-47.example.com:443"
+        );
+        assert_eq!(
+            parse("[::1]:8080:127.0.0.1:8000").unwrap(),
+            "[::1]:8080:127.0.0.1:8000"
+        );
+        assert_eq!(
+            parse("example.com::localhost:").unwrap(),
+            "example.com::localhost:"
+        );
+    }
+
+    #[test]
+    fn test_parse_wildcard_host() {
+        assert_eq!(
+            parse("*:443:127.0.0.1:8443").unwrap(),
+            ":443:127.0.0.1:8443"
+        );
+        assert_eq!(parse("*::[::1]:8443").unwrap(), "::[::1]:8443");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("example.com:443:localhost").unwrap_err(),
+            CliOptionsError::Error(
+                "invalid value 'example.com:443:localhost' for --connect-to, expected HOST1:PORT1:HOST2:PORT2"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            parse("a:1:b:2:c").unwrap_err(),
+            CliOptionsError::Error(
+                "invalid value 'a:1:b:2:c' for --connect-to, expected HOST1:PORT1:HOST2:PORT2"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            parse("[::1:443:localhost:8443").unwrap_err(),
+            CliOptionsError::Error(
+                "invalid value '[::1:443:localhost:8443' for --connect-to, expected HOST1:PORT1:HOST2:PORT2"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            parse("*:https:127.0.0.1:8443").unwrap_err(),
+            CliOptionsError::Error(
+                "invalid port 'https' in --connect-to '*:https:127.0.0.1:8443'".to_string()
+            )
+        );
+        assert_eq!(
+            parse("*:443:127.0.0.1:70000").unwrap_err(),
+            CliOptionsError::Error(
+                "invalid port '70000' in --connect-to '*:443:127.0.0.1:70000'".to_string()
+            )
+        );
+        assert_eq!(
+            parse("example.com:443:*:8443").unwrap_err(),
+            CliOptionsError::Error(
+                "wildcard '*' is only allowed for HOST1 in --connect-to 'example.com:443:*:8443'"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -24,12 +24,18 @@ use std::{env, fs, io};
 
 use clap::ArgMatches;
 use hurl::runner::Value;
+use hurl_core::ast::Assert;
+use hurl_core::error::DisplaySourceError;
 use hurl_core::input::Input;
+use hurl_core::parser;
 use hurl_core::typing::{BytesPerSec, Count, DurationUnit};
 
 use crate::cli::options::variables;
-use crate::cli::options::{duration, CliOptionsError};
-use crate::cli::options::{ErrorFormat, HttpVersion, IpResolve, Output};
+use crate::cli::options::{connect_to, duration, CliOptionsError};
+use crate::cli::options::{
+    CookieOutputFormat, ErrorFormat, HttpVersion, IpResolve, Output, ProgressFormat, RetryBackoff,
+    TapGranularity,
+};
 use crate::cli::OutputType;
 
 pub fn cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
@@ -49,10 +55,30 @@ pub fn cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOption
     }
 }
 
+pub fn asserts(arg_matches: &ArgMatches) -> Result<Vec<Assert>, CliOptionsError> {
+    get_strings(arg_matches, "assert")
+        .unwrap_or_default()
+        .iter()
+        .map(|s| {
+            parser::parse_assert(s).map_err(|e| {
+                CliOptionsError::Error(format!(
+                    "{s} is not a valid assert for --assert ({} at column {})",
+                    e.description().to_lowercase(),
+                    e.pos.column
+                ))
+            })
+        })
+        .collect()
+}
+
 pub fn aws_sigv4(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "aws_sigv4")
 }
 
+pub fn benchmark(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "benchmark")
+}
+
 pub fn client_cert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
     match get::<String>(arg_matches, "client_cert_file") {
         None => Ok(None),
@@ -101,13 +127,21 @@ pub fn compressed(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "compressed")
 }
 
+pub fn concurrency_per_file(arg_matches: &ArgMatches) -> usize {
+    get::<u32>(arg_matches, "concurrency_per_file").map_or(1, |m| m as usize)
+}
+
 pub fn connect_timeout(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsError> {
     let s = get::<String>(arg_matches, "connect_timeout").unwrap_or_default();
     get_duration(&s, DurationUnit::Second)
 }
 
-pub fn connects_to(arg_matches: &ArgMatches) -> Vec<String> {
-    get_strings(arg_matches, "connect_to").unwrap_or_default()
+pub fn connects_to(arg_matches: &ArgMatches) -> Result<Vec<String>, CliOptionsError> {
+    get_strings(arg_matches, "connect_to")
+        .unwrap_or_default()
+        .iter()
+        .map(|s| connect_to::parse(s))
+        .collect()
 }
 
 pub fn continue_on_error(arg_matches: &ArgMatches) -> bool {
@@ -122,15 +156,41 @@ pub fn cookie_output_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get::<String>(arg_matches, "cookies_output_file").map(PathBuf::from)
 }
 
+pub fn cookie_output_format(arg_matches: &ArgMatches) -> CookieOutputFormat {
+    match get::<String>(arg_matches, "cookie_jar_format").as_deref() {
+        Some("json") => CookieOutputFormat::Json,
+        _ => CookieOutputFormat::Netscape,
+    }
+}
+
 pub fn curl_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get::<String>(arg_matches, "curl").map(PathBuf::from)
 }
 
+pub fn dump_request(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "dump_request").map(PathBuf::from)
+}
+
 pub fn delay(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsError> {
     let s = get::<String>(arg_matches, "delay").unwrap_or_default();
     get_duration(&s, DurationUnit::MilliSecond)
 }
 
+pub fn delay_jitter(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsError> {
+    match get::<String>(arg_matches, "delay_jitter") {
+        Some(s) => get_duration(&s, DurationUnit::MilliSecond),
+        None => Ok(Duration::ZERO),
+    }
+}
+
+pub fn delay_seed(arg_matches: &ArgMatches) -> Option<u64> {
+    get::<u64>(arg_matches, "delay_seed")
+}
+
+pub fn dns_servers(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "dns_servers")
+}
+
 pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
     let error_format = get::<String>(arg_matches, "error_format");
     match error_format.as_deref() {
@@ -140,6 +200,23 @@ pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
     }
 }
 
+/// Returns true if the supported features should be listed.
+pub fn features(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "features")
+}
+
+/// Returns true if the run of Hurl files must stop after the first failing file.
+pub fn fail_fast(arg_matches: &ArgMatches) -> bool {
+    if has_flag(arg_matches, "no_fail_fast") {
+        return false;
+    }
+    has_flag(arg_matches, "fail_fast")
+}
+
+pub fn fail_if_empty(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "fail_if_empty")
+}
+
 pub fn file_root(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "file_root")
 }
@@ -151,6 +228,10 @@ pub fn follow_location(arg_matches: &ArgMatches) -> (bool, bool) {
     (follow_location, follow_location_trusted)
 }
 
+pub fn from_curl(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "from_curl")
+}
+
 pub fn from_entry(arg_matches: &ArgMatches) -> Option<usize> {
     get::<u32>(arg_matches, "from_entry").map(|x| x as usize)
 }
@@ -159,6 +240,22 @@ pub fn headers(arg_matches: &ArgMatches) -> Vec<String> {
     get_strings(arg_matches, "header").unwrap_or_default()
 }
 
+pub fn host_headers(arg_matches: &ArgMatches) -> Result<Vec<String>, CliOptionsError> {
+    let host_headers = get_strings(arg_matches, "header_for_host").unwrap_or_default();
+    for value in &host_headers {
+        let valid = match value.split_once(':') {
+            Some((host, header)) => !host.is_empty() && header.contains([':', ';']),
+            None => false,
+        };
+        if !valid {
+            return Err(CliOptionsError::Error(format!(
+                "{value} is not a valid HOST:HEADER for --header-for-host"
+            )));
+        }
+    }
+    Ok(host_headers)
+}
+
 pub fn html_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsError> {
     if let Some(dir) = get::<String>(arg_matches, "report_html") {
         let path = Path::new(&dir);
@@ -201,6 +298,10 @@ pub fn ignore_asserts(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "ignore_asserts")
 }
 
+pub fn ignore_trailing_newline(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "ignore_trailing_newline")
+}
+
 pub fn include(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "include")
 }
@@ -266,6 +367,10 @@ pub fn insecure(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "insecure")
 }
 
+pub fn insecure_hosts(arg_matches: &ArgMatches) -> Vec<String> {
+    get_strings(arg_matches, "insecure_host").unwrap_or_default()
+}
+
 pub fn interactive(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "interactive")
 }
@@ -280,6 +385,14 @@ pub fn ip_resolve(arg_matches: &ArgMatches) -> Option<IpResolve> {
     }
 }
 
+pub fn junit_classname(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "junit_classname")
+}
+
+pub fn json_report_ndjson(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "json_report_ndjson").map(PathBuf::from)
+}
+
 pub fn junit_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get::<String>(arg_matches, "report_junit").map(PathBuf::from)
 }
@@ -288,6 +401,10 @@ pub fn limit_rate(arg_matches: &ArgMatches) -> Option<BytesPerSec> {
     get::<u64>(arg_matches, "limit_rate").map(BytesPerSec)
 }
 
+pub fn max_entries(arg_matches: &ArgMatches) -> Option<usize> {
+    get::<u32>(arg_matches, "max_entries").map(|n| n as usize)
+}
+
 pub fn max_filesize(arg_matches: &ArgMatches) -> Option<u64> {
     get::<u64>(arg_matches, "max_filesize")
 }
@@ -346,7 +463,18 @@ pub fn netrc_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptions
 }
 
 pub fn netrc_optional(arg_matches: &ArgMatches) -> bool {
+    // Without any explicit netrc option, `--user :` looks up the credentials in the default
+    // .netrc file, if any.
     has_flag(arg_matches, "netrc_optional")
+        || (has_netrc_user(arg_matches)
+            && !netrc(arg_matches)
+            && get::<String>(arg_matches, "netrc_file").is_none())
+}
+
+/// Returns `true` if empty credentials are set with `--user :`: the username and password of each
+/// host are then read from the netrc file.
+fn has_netrc_user(arg_matches: &ArgMatches) -> bool {
+    get::<String>(arg_matches, "user").as_deref() == Some(":")
 }
 
 pub fn no_proxy(arg_matches: &ArgMatches) -> Option<String> {
@@ -357,10 +485,38 @@ pub fn output(arg_matches: &ArgMatches) -> Option<Output> {
     get::<String>(arg_matches, "output").map(|filename| Output::new(&filename))
 }
 
+pub fn output_append(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "output_append")
+}
+
+pub fn output_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsError> {
+    if let Some(dir) = get::<String>(arg_matches, "output_dir") {
+        let path = Path::new(&dir);
+        if !path.exists() {
+            match fs::create_dir_all(path) {
+                Err(_) => Err(CliOptionsError::Error(format!(
+                    "Output dir {} can not be created",
+                    path.display()
+                ))),
+                Ok(_) => Ok(Some(path.to_path_buf())),
+            }
+        } else if path.is_dir() {
+            Ok(Some(path.to_path_buf()))
+        } else {
+            Err(CliOptionsError::Error(format!(
+                "{} is not a valid directory",
+                path.display()
+            )))
+        }
+    } else {
+        Ok(None)
+    }
+}
+
 pub fn output_type(arg_matches: &ArgMatches) -> OutputType {
     if has_flag(arg_matches, "json") {
         OutputType::Json
-    } else if has_flag(arg_matches, "no_output") || test(arg_matches) {
+    } else if has_flag(arg_matches, "no_output") || test(arg_matches) || benchmark(arg_matches) {
         OutputType::NoOutput
     } else {
         OutputType::ResponseBody
@@ -379,6 +535,13 @@ pub fn progress_bar(arg_matches: &ArgMatches) -> bool {
     test(arg_matches) && !interactive(arg_matches) && !is_ci() && io::stderr().is_terminal()
 }
 
+pub fn progress_format(arg_matches: &ArgMatches) -> ProgressFormat {
+    match get::<String>(arg_matches, "progress_format").as_deref() {
+        Some("json") => ProgressFormat::Json,
+        _ => ProgressFormat::Human,
+    }
+}
+
 pub fn proxy(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "proxy")
 }
@@ -391,10 +554,38 @@ pub fn repeat(arg_matches: &ArgMatches) -> Option<Count> {
     }
 }
 
+pub fn request_charset(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
+    match get::<String>(arg_matches, "request_charset") {
+        Some(charset) => match encoding::label::encoding_from_whatwg_label(&charset) {
+            Some(_) => Ok(Some(charset)),
+            None => Err(CliOptionsError::Error(format!(
+                "{charset} is not a valid charset for --request-charset"
+            ))),
+        },
+        None => Ok(None),
+    }
+}
+
+pub fn require_http_version(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "require_http_version")
+}
+
 pub fn resolves(arg_matches: &ArgMatches) -> Vec<String> {
     get_strings(arg_matches, "resolve").unwrap_or_default()
 }
 
+pub fn response_charset(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
+    match get::<String>(arg_matches, "response_charset") {
+        Some(charset) => match encoding::label::encoding_from_whatwg_label(&charset) {
+            Some(_) => Ok(Some(charset)),
+            None => Err(CliOptionsError::Error(format!(
+                "{charset} is not a valid charset for --response-charset"
+            ))),
+        },
+        None => Ok(None),
+    }
+}
+
 pub fn retry(arg_matches: &ArgMatches) -> Option<Count> {
     match get::<i32>(arg_matches, "retry") {
         Some(-1) => Some(Count::Infinite),
@@ -403,11 +594,39 @@ pub fn retry(arg_matches: &ArgMatches) -> Option<Count> {
     }
 }
 
+pub fn retry_backoff(arg_matches: &ArgMatches) -> RetryBackoff {
+    match get::<String>(arg_matches, "retry_backoff").as_deref() {
+        Some("exponential") => RetryBackoff::Exponential,
+        _ => RetryBackoff::Constant,
+    }
+}
+
 pub fn retry_interval(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsError> {
     let s = get::<String>(arg_matches, "retry_interval").unwrap_or_default();
     get_duration(&s, DurationUnit::MilliSecond)
 }
 
+pub fn retry_max_interval(arg_matches: &ArgMatches) -> Result<Option<Duration>, CliOptionsError> {
+    match get::<String>(arg_matches, "retry_max_interval") {
+        Some(s) => Ok(Some(get_duration(&s, DurationUnit::MilliSecond)?)),
+        None => Ok(None),
+    }
+}
+
+pub fn retry_on_status(arg_matches: &ArgMatches) -> Result<Vec<u16>, CliOptionsError> {
+    let Some(s) = get::<String>(arg_matches, "retry_on_status") else {
+        return Ok(vec![]);
+    };
+    s.split(',')
+        .map(|status| match status.trim().parse::<u16>() {
+            Ok(status) if (100..=599).contains(&status) => Ok(status),
+            _ => Err(CliOptionsError::Error(format!(
+                "{status} is not a valid HTTP status code for --retry-on-status"
+            ))),
+        })
+        .collect()
+}
+
 pub fn secret(matches: &ArgMatches) -> Result<HashMap<String, String>, CliOptionsError> {
     let mut secrets = HashMap::new();
     if let Some(secret) = get_strings(matches, "secret") {
@@ -438,6 +657,19 @@ pub fn tap_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get::<String>(arg_matches, "report_tap").map(PathBuf::from)
 }
 
+pub fn tap_granularity(arg_matches: &ArgMatches) -> TapGranularity {
+    let tap_granularity = get::<String>(arg_matches, "tap_granularity");
+    match tap_granularity.as_deref() {
+        Some("entry") => TapGranularity::Entry,
+        Some("assert") => TapGranularity::Assert,
+        _ => TapGranularity::File,
+    }
+}
+
+pub fn tap_verbose(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "tap_verbose")
+}
+
 pub fn test(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "test")
 }
@@ -456,6 +688,10 @@ pub fn unix_socket(arg_matches: &ArgMatches) -> Option<String> {
 }
 
 pub fn user(arg_matches: &ArgMatches) -> Option<String> {
+    // With `--user :`, no Authorization header is built and credentials are deferred to netrc.
+    if has_netrc_user(arg_matches) {
+        return None;
+    }
     get::<String>(arg_matches, "user")
 }
 
@@ -463,7 +699,11 @@ pub fn user_agent(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "user_agent")
 }
 
-/// Returns a map of variables from the command line options `matches`.
+/// Returns the variables defined by `HURL_xxx` environment variables, `--variables-file` files,
+/// `--variable-from-file` files and `--variable` options.
+///
+/// Later definitions override earlier ones: files override environment variables, each file
+/// overrides the previous files, and `--variable` options override all files.
 pub fn variables(matches: &ArgMatches) -> Result<HashMap<String, Value>, CliOptionsError> {
     let mut variables = HashMap::new();
 
@@ -510,6 +750,14 @@ pub fn variables(matches: &ArgMatches) -> Result<HashMap<String, Value>, CliOpti
         }
     }
 
+    if let Some(input) = get_strings(matches, "variable_from_file") {
+        let file_root = file_root(matches);
+        for s in input {
+            let (name, value) = variables::parse_from_file(&s, file_root.as_deref())?;
+            variables.insert(name, value);
+        }
+    }
+
     if let Some(input) = get_strings(matches, "variable") {
         for s in input {
             let inferred = true;
@@ -525,10 +773,19 @@ pub fn verbose(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "verbose")
 }
 
+/// Returns true if the version should be printed.
+pub fn version(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "version")
+}
+
 pub fn very_verbose(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "very_verbose")
 }
 
+pub fn warmup(arg_matches: &ArgMatches) -> usize {
+    get::<u32>(arg_matches, "warmup").unwrap_or_default() as usize
+}
+
 /// Returns a list of path names from the command line options `matches`.
 fn glob_files(matches: &ArgMatches) -> Result<Vec<Input>, CliOptionsError> {
     let mut all_files = vec![];
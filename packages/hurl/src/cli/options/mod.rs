@@ -16,6 +16,7 @@
  *
  */
 mod commands;
+mod connect_to;
 mod duration;
 mod error;
 mod matches;
@@ -29,13 +30,14 @@ use std::time::Duration;
 use clap::ArgMatches;
 pub use error::CliOptionsError;
 use hurl::http;
-use hurl::http::RequestedHttpVersion;
+use hurl::http::{Cookie, RequestedHttpVersion};
 use hurl::runner::Output;
 use hurl::util::logger::{LoggerOptions, LoggerOptionsBuilder, Verbosity};
 use hurl::util::path::ContextDir;
-use hurl_core::ast::Entry;
+use hurl_core::ast::{Assert, Entry};
 use hurl_core::input::{Input, InputKind};
 use hurl_core::typing::{BytesPerSec, Count};
+use serde_json::json;
 
 use crate::cli;
 use crate::runner::{RunnerOptions, RunnerOptionsBuilder, Value};
@@ -43,37 +45,54 @@ use crate::runner::{RunnerOptions, RunnerOptionsBuilder, Value};
 /// Represents the list of all options that can be used in Hurl command line.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CliOptions {
+    pub asserts: Vec<Assert>,
     pub aws_sigv4: Option<String>,
+    pub benchmark: bool,
     pub cacert_file: Option<String>,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
     pub color: bool,
     pub compressed: bool,
+    pub concurrency_per_file: usize,
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
     pub continue_on_error: bool,
     pub cookie_input_file: Option<String>,
     pub cookie_output_file: Option<PathBuf>,
+    pub cookie_output_format: CookieOutputFormat,
     pub curl_file: Option<PathBuf>,
     pub delay: Duration,
+    pub delay_jitter: Duration,
+    pub delay_seed: Option<u64>,
+    pub dns_servers: Option<String>,
+    pub dump_request: Option<PathBuf>,
     pub error_format: ErrorFormat,
+    pub fail_fast: bool,
+    pub fail_if_empty: bool,
     pub file_root: Option<String>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
+    pub from_curl: bool,
     pub from_entry: Option<usize>,
     pub headers: Vec<String>,
+    pub host_headers: Vec<String>,
     pub html_dir: Option<PathBuf>,
     pub http_version: Option<HttpVersion>,
     pub ignore_asserts: bool,
+    pub ignore_trailing_newline: bool,
     pub include: bool,
     pub input_files: Vec<Input>,
     pub insecure: bool,
+    pub insecure_hosts: Vec<String>,
     pub interactive: bool,
     pub ip_resolve: Option<IpResolve>,
     pub jobs: Option<usize>,
     pub json_report_dir: Option<PathBuf>,
+    pub json_report_ndjson: Option<PathBuf>,
+    pub junit_classname: Option<String>,
     pub junit_file: Option<PathBuf>,
     pub limit_rate: Option<BytesPerSec>,
+    pub max_entries: Option<usize>,
     pub max_filesize: Option<u64>,
     pub max_redirect: Count,
     pub netrc: bool,
@@ -81,18 +100,29 @@ pub struct CliOptions {
     pub netrc_optional: bool,
     pub no_proxy: Option<String>,
     pub output: Option<Output>,
+    pub output_append: bool,
+    pub output_dir: Option<PathBuf>,
     pub output_type: OutputType,
     pub parallel: bool,
     pub path_as_is: bool,
     pub progress_bar: bool,
+    pub progress_format: ProgressFormat,
     pub proxy: Option<String>,
     pub repeat: Option<Count>,
+    pub request_charset: Option<String>,
+    pub require_http_version: bool,
     pub resolves: Vec<String>,
+    pub response_charset: Option<String>,
     pub retry: Option<Count>,
+    pub retry_backoff: RetryBackoff,
     pub retry_interval: Duration,
+    pub retry_max_interval: Option<Duration>,
+    pub retry_on_status: Vec<u16>,
     pub secrets: HashMap<String, String>,
     pub ssl_no_revoke: bool,
     pub tap_file: Option<PathBuf>,
+    pub tap_granularity: TapGranularity,
+    pub tap_verbose: bool,
     pub test: bool,
     pub timeout: Duration,
     pub to_entry: Option<usize>,
@@ -102,6 +132,7 @@ pub struct CliOptions {
     pub variables: HashMap<String, Value>,
     pub verbose: bool,
     pub very_verbose: bool,
+    pub warmup: usize,
 }
 
 /// Error format: long or rich.
@@ -120,6 +151,31 @@ impl From<ErrorFormat> for hurl::util::logger::ErrorFormat {
     }
 }
 
+/// Format of the cookie jar file written with `--cookie-jar`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CookieOutputFormat {
+    Netscape,
+    Json,
+}
+
+/// TAP report granularity: one testpoint per file, entry or assert.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TapGranularity {
+    File,
+    Entry,
+    Assert,
+}
+
+impl From<TapGranularity> for hurl::report::tap::Granularity {
+    fn from(value: TapGranularity) -> Self {
+        match value {
+            TapGranularity::File => hurl::report::tap::Granularity::File,
+            TapGranularity::Entry => hurl::report::tap::Granularity::Entry,
+            TapGranularity::Assert => hurl::report::tap::Granularity::Assert,
+        }
+    }
+}
+
 /// Requested HTTP version.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum HttpVersion {
@@ -140,6 +196,38 @@ impl From<HttpVersion> for RequestedHttpVersion {
     }
 }
 
+/// Format of the parallel progress reporting.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProgressFormat {
+    Human,
+    Json,
+}
+
+impl From<ProgressFormat> for hurl::parallel::runner::ProgressFormat {
+    fn from(value: ProgressFormat) -> Self {
+        match value {
+            ProgressFormat::Human => hurl::parallel::runner::ProgressFormat::Human,
+            ProgressFormat::Json => hurl::parallel::runner::ProgressFormat::Json,
+        }
+    }
+}
+
+/// Strategy used to compute the interval between retries.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RetryBackoff {
+    Constant,
+    Exponential,
+}
+
+impl From<RetryBackoff> for hurl::runner::RetryBackoff {
+    fn from(value: RetryBackoff) -> Self {
+        match value {
+            RetryBackoff::Constant => hurl::runner::RetryBackoff::Constant,
+            RetryBackoff::Exponential => hurl::runner::RetryBackoff::Exponential,
+        }
+    }
+}
+
 /// IP protocol used.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum IpResolve {
@@ -156,23 +244,70 @@ impl From<IpResolve> for http::IpResolve {
     }
 }
 
+/// Returns the features built in Hurl, independently of libcurl.
+fn built_in_features() -> Vec<&'static str> {
+    let mut features = vec!["brotli"];
+    if cfg!(feature = "static-openssl") {
+        features.push("static-openssl");
+    }
+    features
+}
+
 fn get_version() -> String {
     let libcurl_version = http::libcurl_version_info();
     let pkg_version = env!("CARGO_PKG_VERSION");
     format!(
-        "{} ({}) {}\nFeatures (libcurl):  {}\nFeatures (built-in): brotli",
+        "{} ({}) {}\nFeatures (libcurl):  {}\nFeatures (built-in): {}",
         pkg_version,
         libcurl_version.host,
         libcurl_version.libraries.join(" "),
-        libcurl_version.features.join(" ")
+        libcurl_version.features.join(" "),
+        built_in_features().join(" ")
     )
 }
 
+/// Returns the version as a JSON object, for tools that need structured version information.
+fn get_version_json() -> String {
+    let libcurl_version = http::libcurl_version_info();
+    let version = json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "host": libcurl_version.host,
+        "libcurl": {
+            "version": libcurl_version.version,
+            "libraries": libcurl_version.libraries,
+            "features": libcurl_version.features,
+        },
+        "features": built_in_features(),
+    });
+    format!("{version}\n")
+}
+
+/// Returns the list of features supported by this build, built-in features first, then libcurl
+/// features, one feature per line.
+fn get_features() -> String {
+    let libcurl_version = http::libcurl_version_info();
+    built_in_features()
+        .iter()
+        .map(|f| f.to_string())
+        .chain(libcurl_version.features)
+        .map(|f| format!("{f}\n"))
+        .collect()
+}
+
 pub fn parse() -> Result<CliOptions, CliOptionsError> {
     let mut command = clap::Command::new("hurl")
-        .version(get_version())
         .disable_colored_help(true)
+        // We handle the version flag ourselves, so it can be combined with `--json`.
+        .disable_version_flag(true)
         .about("Hurl, run and test HTTP requests with plain text")
+        .arg(
+            clap::Arg::new("version")
+                .short('V')
+                .long("version")
+                .help("Print version")
+                .action(clap::ArgAction::SetTrue)
+                .display_order(usize::MAX),
+        )
         // HTTP options
         .arg(commands::aws_sigv4())
         .arg(commands::cacert_file())
@@ -180,13 +315,16 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::compressed())
         .arg(commands::connect_timeout())
         .arg(commands::connect_to())
+        .arg(commands::dns_servers())
         .arg(commands::header())
+        .arg(commands::header_for_host())
         .arg(commands::http10())
         .arg(commands::http11())
         .arg(commands::http2())
         .arg(commands::http3())
         .arg(commands::input_files())
         .arg(commands::insecure())
+        .arg(commands::insecure_host())
         .arg(commands::ipv4())
         .arg(commands::ipv6())
         .arg(commands::client_key_file())
@@ -199,7 +337,10 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::noproxy())
         .arg(commands::path_as_is())
         .arg(commands::proxy())
+        .arg(commands::request_charset())
+        .arg(commands::require_http_version())
         .arg(commands::resolve())
+        .arg(commands::response_charset())
         .arg(commands::ssl_no_revoke())
         .arg(commands::unix_socket())
         .arg(commands::user())
@@ -207,38 +348,64 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         // Output options
         .arg(commands::color())
         .arg(commands::curl())
+        .arg(commands::dump_request())
         .arg(commands::error_format())
         .arg(commands::include())
         .arg(commands::json())
         .arg(commands::no_color())
         .arg(commands::no_output())
         .arg(commands::output())
+        .arg(commands::output_append())
+        .arg(commands::output_dir())
         .arg(commands::verbose())
         .arg(commands::very_verbose())
         // Run options
+        .arg(commands::assert())
+        .arg(commands::benchmark())
+        .arg(commands::concurrency_per_file())
         .arg(commands::continue_on_error())
         .arg(commands::delay())
+        .arg(commands::delay_jitter())
+        .arg(commands::delay_seed())
+        .arg(commands::fail_fast())
+        .arg(commands::fail_if_empty())
+        .arg(commands::from_curl())
         .arg(commands::from_entry())
         .arg(commands::ignore_asserts())
+        .arg(commands::ignore_trailing_newline())
         .arg(commands::interactive())
         .arg(commands::jobs())
+        .arg(commands::max_entries())
+        .arg(commands::no_fail_fast())
         .arg(commands::parallel())
+        .arg(commands::progress_format())
         .arg(commands::repeat())
         .arg(commands::retry())
+        .arg(commands::retry_backoff())
         .arg(commands::retry_interval())
+        .arg(commands::retry_max_interval())
+        .arg(commands::retry_on_status())
         .arg(commands::secret())
         .arg(commands::test())
         .arg(commands::to_entry())
         .arg(commands::variable())
+        .arg(commands::variable_from_file())
         .arg(commands::variables_file())
+        .arg(commands::warmup())
         // Report options
         .arg(commands::report_html())
         .arg(commands::report_json())
+        .arg(commands::json_report_ndjson())
         .arg(commands::report_junit())
+        .arg(commands::junit_classname())
         .arg(commands::report_tap())
+        .arg(commands::tap_granularity())
+        .arg(commands::tap_verbose())
         // Other options
         .arg(commands::cookies_input_file())
         .arg(commands::cookies_output_file())
+        .arg(commands::cookie_jar_format())
+        .arg(commands::features())
         .arg(commands::file_root())
         .arg(commands::glob())
         .arg(commands::netrc())
@@ -247,6 +414,17 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
 
     let arg_matches = command.try_get_matches_from_mut(env::args_os())?;
 
+    if matches::version(&arg_matches) {
+        let version = match matches::output_type(&arg_matches) {
+            cli::OutputType::Json => get_version_json(),
+            _ => format!("hurl {}\n", get_version()),
+        };
+        return Err(CliOptionsError::Info(version));
+    }
+    if matches::features(&arg_matches) {
+        return Err(CliOptionsError::Info(get_features()));
+    }
+
     // If we've no file input (either from the standard input or from the command line arguments),
     // we just print help and exit.
     if !matches::has_input_files(&arg_matches) {
@@ -261,45 +439,70 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         ));
     }
 
-    if opts.cookie_output_file.is_some() && opts.input_files.len() > 1 {
+    // In parallel mode, files are run in an undefined order, so we can't share a cookie storage
+    // between them.
+    if opts.cookie_output_file.is_some() && opts.parallel && opts.input_files.len() > 1 {
+        return Err(CliOptionsError::Error(
+            "--cookie-jar can not be used with multiple files in parallel mode".to_string(),
+        ));
+    }
+    // Curl commands are converted to Hurl and run sequentially.
+    if opts.from_curl && opts.parallel {
         return Err(CliOptionsError::Error(
-            "Only save cookies for a unique session".to_string(),
+            "--from-curl can not be used in parallel mode".to_string(),
         ));
     }
     Ok(opts)
 }
 
 fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError> {
+    let asserts = matches::asserts(arg_matches)?;
     let aws_sigv4 = matches::aws_sigv4(arg_matches);
+    let benchmark = matches::benchmark(arg_matches);
     let cacert_file = matches::cacert_file(arg_matches)?;
     let client_cert_file = matches::client_cert_file(arg_matches)?;
     let client_key_file = matches::client_key_file(arg_matches)?;
     let color = matches::color(arg_matches);
     let compressed = matches::compressed(arg_matches);
+    let concurrency_per_file = matches::concurrency_per_file(arg_matches);
     let connect_timeout = matches::connect_timeout(arg_matches)?;
-    let connects_to = matches::connects_to(arg_matches);
+    let connects_to = matches::connects_to(arg_matches)?;
     let continue_on_error = matches::continue_on_error(arg_matches);
     let cookie_input_file = matches::cookie_input_file(arg_matches);
     let cookie_output_file = matches::cookie_output_file(arg_matches);
+    let cookie_output_format = matches::cookie_output_format(arg_matches);
     let curl_file = matches::curl_file(arg_matches);
     let delay = matches::delay(arg_matches)?;
+    let delay_jitter = matches::delay_jitter(arg_matches)?;
+    let delay_seed = matches::delay_seed(arg_matches);
+    let dns_servers = matches::dns_servers(arg_matches);
+    let dump_request = matches::dump_request(arg_matches);
     let error_format = matches::error_format(arg_matches);
+    let fail_fast = matches::fail_fast(arg_matches);
+    let fail_if_empty = matches::fail_if_empty(arg_matches);
     let file_root = matches::file_root(arg_matches);
     let (follow_location, follow_location_trusted) = matches::follow_location(arg_matches);
+    let from_curl = matches::from_curl(arg_matches);
     let from_entry = matches::from_entry(arg_matches);
     let headers = matches::headers(arg_matches);
+    let host_headers = matches::host_headers(arg_matches)?;
     let html_dir = matches::html_dir(arg_matches)?;
     let http_version = matches::http_version(arg_matches);
     let ignore_asserts = matches::ignore_asserts(arg_matches);
+    let ignore_trailing_newline = matches::ignore_trailing_newline(arg_matches);
     let include = matches::include(arg_matches);
     let input_files = matches::input_files(arg_matches)?;
     let insecure = matches::insecure(arg_matches);
+    let insecure_hosts = matches::insecure_hosts(arg_matches);
     let interactive = matches::interactive(arg_matches);
     let ip_resolve = matches::ip_resolve(arg_matches);
     let jobs = matches::jobs(arg_matches);
     let json_report_dir = matches::json_report_dir(arg_matches)?;
+    let json_report_ndjson = matches::json_report_ndjson(arg_matches);
+    let junit_classname = matches::junit_classname(arg_matches);
     let junit_file = matches::junit_file(arg_matches);
     let limit_rate = matches::limit_rate(arg_matches);
+    let max_entries = matches::max_entries(arg_matches);
     let max_filesize = matches::max_filesize(arg_matches);
     let max_redirect = matches::max_redirect(arg_matches);
     let netrc = matches::netrc(arg_matches);
@@ -309,16 +512,27 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let parallel = matches::parallel(arg_matches);
     let path_as_is = matches::path_as_is(arg_matches);
     let progress_bar = matches::progress_bar(arg_matches);
+    let progress_format = matches::progress_format(arg_matches);
     let proxy = matches::proxy(arg_matches);
     let output = matches::output(arg_matches);
+    let output_append = matches::output_append(arg_matches);
+    let output_dir = matches::output_dir(arg_matches)?;
     let output_type = matches::output_type(arg_matches);
     let repeat = matches::repeat(arg_matches);
+    let request_charset = matches::request_charset(arg_matches)?;
+    let require_http_version = matches::require_http_version(arg_matches);
     let resolves = matches::resolves(arg_matches);
+    let response_charset = matches::response_charset(arg_matches)?;
     let retry = matches::retry(arg_matches);
+    let retry_backoff = matches::retry_backoff(arg_matches);
     let retry_interval = matches::retry_interval(arg_matches)?;
+    let retry_max_interval = matches::retry_max_interval(arg_matches)?;
+    let retry_on_status = matches::retry_on_status(arg_matches)?;
     let secrets = matches::secret(arg_matches)?;
     let ssl_no_revoke = matches::ssl_no_revoke(arg_matches);
     let tap_file = matches::tap_file(arg_matches);
+    let tap_granularity = matches::tap_granularity(arg_matches);
+    let tap_verbose = matches::tap_verbose(arg_matches);
     let test = matches::test(arg_matches);
     let timeout = matches::timeout(arg_matches)?;
     let to_entry = matches::to_entry(arg_matches);
@@ -328,37 +542,55 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let variables = matches::variables(arg_matches)?;
     let verbose = matches::verbose(arg_matches);
     let very_verbose = matches::very_verbose(arg_matches);
+    let warmup = matches::warmup(arg_matches);
     Ok(CliOptions {
+        asserts,
         aws_sigv4,
+        benchmark,
         cacert_file,
         client_cert_file,
         client_key_file,
         color,
         compressed,
+        concurrency_per_file,
         connect_timeout,
         connects_to,
         continue_on_error,
         cookie_input_file,
         cookie_output_file,
+        cookie_output_format,
         curl_file,
         delay,
+        delay_jitter,
+        delay_seed,
+        dns_servers,
+        dump_request,
         error_format,
+        fail_fast,
+        fail_if_empty,
         file_root,
         follow_location,
         follow_location_trusted,
+        from_curl,
         from_entry,
         headers,
+        host_headers,
         html_dir,
         http_version,
         ignore_asserts,
+        ignore_trailing_newline,
         include,
         input_files,
         insecure,
+        insecure_hosts,
         interactive,
         ip_resolve,
         json_report_dir,
+        json_report_ndjson,
+        junit_classname,
         junit_file,
         limit_rate,
+        max_entries,
         max_filesize,
         max_redirect,
         netrc,
@@ -368,16 +600,27 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         path_as_is,
         parallel,
         progress_bar,
+        progress_format,
         proxy,
         output,
+        output_append,
+        output_dir,
         output_type,
         repeat,
+        request_charset,
+        require_http_version,
         resolves,
+        response_charset,
         retry,
+        retry_backoff,
         retry_interval,
+        retry_max_interval,
+        retry_on_status,
         secrets,
         ssl_no_revoke,
         tap_file,
+        tap_granularity,
+        tap_verbose,
         test,
         timeout,
         to_entry,
@@ -387,6 +630,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         variables,
         verbose,
         very_verbose,
+        warmup,
         jobs,
     })
 }
@@ -403,12 +647,21 @@ pub enum OutputType {
 
 impl CliOptions {
     /// Converts this instance of [`CliOptions`] to an instance of [`RunnerOptions`]
-    pub fn to_runner_options(&self, filename: &Input, current_dir: &Path) -> RunnerOptions {
+    ///
+    /// `cookies` are added to the cookie storage before running `filename`.
+    pub fn to_runner_options(
+        &self,
+        filename: &Input,
+        current_dir: &Path,
+        cookies: &[Cookie],
+    ) -> RunnerOptions {
+        let asserts = &self.asserts;
         let aws_sigv4 = self.aws_sigv4.clone();
         let cacert_file = self.cacert_file.clone();
         let client_cert_file = self.client_cert_file.clone();
         let client_key_file = self.client_key_file.clone();
         let compressed = self.compressed;
+        let concurrency_per_file = self.concurrency_per_file;
         let connect_timeout = self.connect_timeout;
         let connects_to = self.connects_to.clone();
         let file_root = match &self.file_root {
@@ -422,20 +675,28 @@ impl CliOptions {
         let continue_on_error = self.continue_on_error;
         let cookie_input_file = self.cookie_input_file.clone();
         let delay = self.delay;
+        let delay_jitter = self.delay_jitter;
+        let delay_seed = self.delay_seed;
+        let dns_servers = self.dns_servers.clone();
+        let fail_if_empty = self.fail_if_empty;
         let follow_location = self.follow_location;
         let follow_location_trusted = self.follow_location_trusted;
         let from_entry = self.from_entry;
         let headers = &self.headers;
+        let host_headers = &self.host_headers;
         let http_version = match self.http_version {
             Some(version) => version.into(),
             None => RequestedHttpVersion::default(),
         };
         let ignore_asserts = self.ignore_asserts;
+        let ignore_trailing_newline = self.ignore_trailing_newline;
         let insecure = self.insecure;
+        let insecure_hosts = self.insecure_hosts.clone();
         let ip_resolve = match self.ip_resolve {
             Some(ip) => ip.into(),
             None => http::IpResolve::default(),
         };
+        let max_entries = self.max_entries;
         let max_filesize = self.max_filesize;
         // Like curl, we don't differentiate upload and download limit rate, we have
         // only one option.
@@ -459,36 +720,54 @@ impl CliOptions {
             None
         };
         let proxy = self.proxy.clone();
+        let request_charset = self.request_charset.clone();
+        let require_http_version = self.require_http_version;
         let resolves = self.resolves.clone();
+        let response_charset = self.response_charset.clone();
         let retry = self.retry;
+        let retry_backoff = self.retry_backoff.into();
         let retry_interval = self.retry_interval;
+        let retry_max_interval = self.retry_max_interval;
+        let retry_on_status = self.retry_on_status.clone();
         let ssl_no_revoke = self.ssl_no_revoke;
         let timeout = self.timeout;
         let to_entry = self.to_entry;
         let unix_socket = self.unix_socket.clone();
         let user = self.user.clone();
         let user_agent = self.user_agent.clone();
+        let warmup = self.warmup;
 
         RunnerOptionsBuilder::new()
+            .asserts(asserts)
             .aws_sigv4(aws_sigv4)
             .cacert_file(cacert_file)
             .client_cert_file(client_cert_file)
             .client_key_file(client_key_file)
             .delay(delay)
+            .delay_jitter(delay_jitter)
+            .delay_seed(delay_seed)
+            .dns_servers(dns_servers)
+            .fail_if_empty(fail_if_empty)
             .compressed(compressed)
+            .concurrency_per_file(concurrency_per_file)
             .connect_timeout(connect_timeout)
             .connects_to(&connects_to)
             .continue_on_error(continue_on_error)
             .context_dir(&context_dir)
             .cookie_input_file(cookie_input_file)
+            .cookies(cookies)
             .follow_location(follow_location)
             .follow_location_trusted(follow_location_trusted)
             .from_entry(from_entry)
             .headers(headers)
+            .host_headers(host_headers)
             .http_version(http_version)
             .ignore_asserts(ignore_asserts)
+            .ignore_trailing_newline(ignore_trailing_newline)
             .insecure(insecure)
+            .insecure_hosts(&insecure_hosts)
             .ip_resolve(ip_resolve)
+            .max_entries(max_entries)
             .max_filesize(max_filesize)
             .max_recv_speed(max_recv_speed)
             .max_redirect(max_redirect)
@@ -502,15 +781,22 @@ impl CliOptions {
             .post_entry(post_entry)
             .pre_entry(pre_entry)
             .proxy(proxy)
+            .request_charset(request_charset)
+            .require_http_version(require_http_version)
             .resolves(&resolves)
+            .response_charset(response_charset)
             .retry(retry)
+            .retry_backoff(retry_backoff)
             .retry_interval(retry_interval)
+            .retry_max_interval(retry_max_interval)
+            .retry_on_status(&retry_on_status)
             .ssl_no_revoke(ssl_no_revoke)
             .timeout(timeout)
             .to_entry(to_entry)
             .unix_socket(unix_socket)
             .user(user)
             .user_agent(user_agent)
+            .warmup(warmup)
             .build()
     }
 
@@ -524,3 +810,43 @@ impl CliOptions {
             .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_version_json() {
+        let version = get_version_json();
+        let version = serde_json::from_str::<serde_json::Value>(&version).unwrap();
+        let libcurl_version = http::libcurl_version_info();
+
+        assert_eq!(version["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(version["host"], libcurl_version.host.as_str());
+        assert_eq!(
+            version["libcurl"]["version"],
+            libcurl_version.version.as_str()
+        );
+        assert_eq!(
+            version["libcurl"]["libraries"][0],
+            format!("libcurl/{}", libcurl_version.version).as_str()
+        );
+        assert_eq!(
+            version["libcurl"]["features"].as_array().unwrap().len(),
+            libcurl_version.features.len()
+        );
+        assert_eq!(version["features"], json!(["brotli"]));
+    }
+
+    #[test]
+    fn test_get_features() {
+        let features = get_features();
+        let features = features.lines().collect::<Vec<_>>();
+        let libcurl_version = http::libcurl_version_info();
+
+        assert_eq!(features[0], "brotli");
+        for feature in libcurl_version.features {
+            assert!(features.contains(&feature.as_str()));
+        }
+    }
+}
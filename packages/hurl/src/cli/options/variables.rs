@@ -16,6 +16,9 @@
  *
  */
 
+use std::fs;
+use std::path::{Path, PathBuf};
+
 use super::CliOptionsError;
 use crate::runner::{Number, Value};
 use hurl_core::ast::is_variable_reserved;
@@ -41,6 +44,56 @@ pub fn parse(s: &str, inferred: bool) -> Result<(String, Value), CliOptionsError
     }
 }
 
+/// Parses a string "name=filename" as a pair of `String` and `Value`, the value being the content
+/// of the file `filename`.
+///
+/// Relative filenames are resolved against `file_root` if it's defined. Trailing newlines of the
+/// file content are trimmed, except if the name has a `:raw` suffix (`name:raw=filename`).
+pub fn parse_from_file(
+    s: &str,
+    file_root: Option<&str>,
+) -> Result<(String, Value), CliOptionsError> {
+    let Some((name, filename)) = s.split_once('=') else {
+        return Err(CliOptionsError::Error(format!(
+            "Missing file for variable {s}!"
+        )));
+    };
+    let (name, raw) = match name.strip_suffix(":raw") {
+        Some(name) => (name, true),
+        None => (name, false),
+    };
+    if is_variable_reserved(name) {
+        return Err(CliOptionsError::Error(format!(
+            "Variable {name} conflicts with the {name} function, use a different name."
+        )));
+    }
+    let path = match file_root {
+        Some(file_root) => Path::new(file_root).join(filename),
+        None => PathBuf::from(filename),
+    };
+    if !path.exists() {
+        return Err(CliOptionsError::Error(format!(
+            "File {} for variable {name} does not exist",
+            path.display()
+        )));
+    }
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) => {
+            return Err(CliOptionsError::Error(format!(
+                "File {} for variable {name} can not be read: {e}",
+                path.display()
+            )))
+        }
+    };
+    let content = if raw {
+        content
+    } else {
+        content.trim_end_matches(['\n', '\r']).to_string()
+    };
+    Ok((name.to_string(), Value::String(content)))
+}
+
 /// Parses a `value` as a pair of String and Value.
 ///
 /// If `inferred` is `true`, value variant is inferred from the `value`, for instance true is parsed as [`Value::Bool(true)`].
@@ -163,4 +216,31 @@ mod tests {
             CliOptionsError::Error("Value should end with a double quote".to_string())
         );
     }
+
+    #[test]
+    fn test_parse_from_file() {
+        let dir = std::env::temp_dir().join("hurl_test_parse_from_file");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("token.txt"), "abc\n").unwrap();
+        let file_root = dir.to_str();
+
+        assert_eq!(
+            parse_from_file("token=token.txt", file_root).unwrap(),
+            ("token".to_string(), Value::String("abc".to_string()))
+        );
+        assert_eq!(
+            parse_from_file("token:raw=token.txt", file_root).unwrap(),
+            ("token".to_string(), Value::String("abc\n".to_string()))
+        );
+        assert_eq!(
+            parse_from_file("token", file_root).err().unwrap(),
+            CliOptionsError::Error("Missing file for variable token!".to_string())
+        );
+        assert_eq!(
+            parse_from_file("token=unknown.txt", None).err().unwrap(),
+            CliOptionsError::Error(
+                "File unknown.txt for variable token does not exist".to_string()
+            )
+        );
+    }
 }
@@ -21,7 +21,8 @@ use crate::HurlRun;
 
 /// Returns the text summary of this Hurl `runs`.
 ///
-/// This is used in `--test`mode.
+/// This is used in `--test`mode. The number of requests executed without verifying SSL
+/// certificates is only displayed if there are any.
 pub fn summary(runs: &[HurlRun], duration: Duration) -> String {
     let total_files = runs.len();
     let total_requests = requests_count(runs);
@@ -32,16 +33,77 @@ pub fn summary(runs: &[HurlRun], duration: Duration) -> String {
     let success_percent = 100.0 * success_files as f32 / total_files as f32;
     let failed = total_files - success_files;
     let failed_percent = 100.0 * failed as f32 / total_files as f32;
+    let insecure_requests = insecure_requests_count(runs);
+    let insecure = if insecure_requests > 0 {
+        format!("Insecure requests: {insecure_requests}\n")
+    } else {
+        String::new()
+    };
     format!(
         "--------------------------------------------------------------------------------\n\
              Executed files:    {total_files}\n\
              Executed requests: {total_requests} ({requests_rate:.1}/s)\n\
+             {insecure}\
              Succeeded files:   {success_files} ({success_percent:.1}%)\n\
              Failed files:      {failed} ({failed_percent:.1}%)\n\
              Duration:          {duration_in_ms} ms\n"
     )
 }
 
+/// Returns the text latency summary of this Hurl `runs`, with percentiles of each entry.
+///
+/// This is used in `--benchmark` mode. Latencies of an entry are aggregated over all the runs of
+/// its file (with `--repeat` for instance), and are computed from the transfer timings of its calls.
+pub fn benchmark_summary(runs: &[HurlRun]) -> String {
+    let mut latencies: Vec<(String, usize, Vec<Duration>)> = vec![];
+    for run in runs {
+        let filename = run.filename.to_string();
+        for entry in &run.hurl_result.entries {
+            let line = entry.source_info.start.line;
+            let latency = entry.calls.iter().map(|call| call.timings.total).sum();
+            match latencies
+                .iter_mut()
+                .find(|(f, l, _)| *f == filename && *l == line)
+            {
+                Some((_, _, durations)) => durations.push(latency),
+                None => latencies.push((filename.clone(), line, vec![latency])),
+            }
+        }
+    }
+
+    let mut s = String::new();
+    s.push_str(
+        "--------------------------------------------------------------------------------\n",
+    );
+    s.push_str("Benchmark:\n");
+    for (filename, line, mut durations) in latencies {
+        durations.sort();
+        let count = durations.len();
+        let p50 = as_millis(percentile(&durations, 50.0));
+        let p90 = as_millis(percentile(&durations, 90.0));
+        let p99 = as_millis(percentile(&durations, 99.0));
+        s.push_str(&format!("{filename}:{line} ({count} requests)\n"));
+        s.push_str(&format!(
+            "    p50: {p50:.3} ms, p90: {p90:.3} ms, p99: {p99:.3} ms\n"
+        ));
+    }
+    s
+}
+
+/// Returns the `p`-th percentile of a sorted list of `durations`, using the nearest-rank method.
+fn percentile(durations: &[Duration], p: f64) -> Duration {
+    if durations.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (p / 100.0 * durations.len() as f64).ceil() as usize;
+    durations[rank.clamp(1, durations.len()) - 1]
+}
+
+/// Returns this `duration` in milliseconds, with a sub-millisecond precision.
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
 /// Returns the total number of executed HTTP requests in this list of `runs`.
 fn requests_count(runs: &[HurlRun]) -> usize {
     // Each entry has a list of calls. Each call is a pair of HTTP request / response
@@ -58,9 +120,20 @@ fn requests_count(runs: &[HurlRun]) -> usize {
         .sum()
 }
 
+/// Returns the number of HTTP requests executed without verifying SSL certificates in this list
+/// of `runs`.
+pub fn insecure_requests_count(runs: &[HurlRun]) -> usize {
+    runs.iter()
+        .flat_map(|r| r.hurl_result.entries.iter())
+        .map(|e| e.insecure_requests)
+        .sum()
+}
+
 #[cfg(test)]
 pub mod tests {
-    use hurl::http::CurlCmd;
+    use std::str::FromStr;
+
+    use hurl::http::{Call, CurlCmd, HeaderVec, HttpVersion, Request, Response, Timings, Url};
     use hurl::runner::{EntryResult, HurlResult};
     use hurl_core::ast::SourceInfo;
     use hurl_core::input::Input;
@@ -80,7 +153,10 @@ pub mod tests {
                 errors: vec![],
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
+                insecure_requests: 0,
                 curl_cmd: CurlCmd::default(),
+                output: None,
+                name: None,
             };
             HurlRun {
                 content: String::new(),
@@ -119,4 +195,133 @@ pub mod tests {
             Duration:          200 ms\n"
         );
     }
+
+    #[test]
+    fn create_run_summary_with_insecure_requests() {
+        let url = Url::from_str("https://localhost:8000/hello").unwrap();
+        let call = Call {
+            request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+            response: Response::new(
+                HttpVersion::Http11,
+                200,
+                HeaderVec::new(),
+                HeaderVec::new(),
+                vec![],
+                Duration::from_millis(0),
+                url,
+                None,
+            ),
+            timings: Timings::default(),
+        };
+        let entry = EntryResult {
+            entry_index: 1,
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            calls: vec![call.clone(), call],
+            insecure_requests: 1,
+            ..Default::default()
+        };
+        let runs = vec![HurlRun {
+            content: String::new(),
+            filename: Input::new(""),
+            hurl_result: HurlResult {
+                entries: vec![entry.clone(), entry],
+                success: true,
+                ..Default::default()
+            },
+        }];
+        let duration = Duration::from_millis(1000);
+        let s = summary(&runs, duration);
+        assert_eq!(
+            s,
+            "--------------------------------------------------------------------------------\n\
+             Executed files:    1\n\
+             Executed requests: 4 (4.0/s)\n\
+             Insecure requests: 2\n\
+             Succeeded files:   1 (100.0%)\n\
+             Failed files:      0 (0.0%)\n\
+             Duration:          1000 ms\n"
+        );
+    }
+
+    #[test]
+    fn compute_percentiles() {
+        let durations = (1..=100).map(Duration::from_millis).collect::<Vec<_>>();
+        assert_eq!(percentile(&durations, 50.0), Duration::from_millis(50));
+        assert_eq!(percentile(&durations, 90.0), Duration::from_millis(90));
+        assert_eq!(percentile(&durations, 99.0), Duration::from_millis(99));
+
+        let durations = vec![
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+            Duration::from_millis(30),
+        ];
+        assert_eq!(percentile(&durations, 50.0), Duration::from_millis(20));
+        assert_eq!(percentile(&durations, 90.0), Duration::from_millis(30));
+        assert_eq!(percentile(&durations, 99.0), Duration::from_millis(30));
+
+        assert_eq!(
+            percentile(&[Duration::from_millis(5)], 50.0),
+            Duration::from_millis(5)
+        );
+        assert_eq!(percentile(&[], 50.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn create_benchmark_summary() {
+        fn new_run(filename: &str, latencies: &[u64]) -> HurlRun {
+            let url = Url::from_str("http://localhost:8000/hello").unwrap();
+            let entries = latencies
+                .iter()
+                .enumerate()
+                .map(|(index, latency)| {
+                    let call = Call {
+                        request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+                        response: Response::new(
+                            HttpVersion::Http11,
+                            200,
+                            HeaderVec::new(),
+                            HeaderVec::new(),
+                            vec![],
+                            Duration::from_millis(0),
+                            url.clone(),
+                            None,
+                        ),
+                        timings: Timings {
+                            total: Duration::from_millis(*latency),
+                            ..Default::default()
+                        },
+                    };
+                    let line = 1 + 3 * index;
+                    EntryResult {
+                        entry_index: index + 1,
+                        source_info: SourceInfo::new(Pos::new(line, 1), Pos::new(line, 1)),
+                        calls: vec![call],
+                        ..Default::default()
+                    }
+                })
+                .collect::<Vec<_>>();
+            HurlRun {
+                content: String::new(),
+                filename: Input::new(filename),
+                hurl_result: HurlResult {
+                    entries,
+                    success: true,
+                    ..Default::default()
+                },
+            }
+        }
+
+        // Two entries, run 10 times with synthetic latencies.
+        let runs = (1..=10)
+            .map(|i| new_run("tests/hello.hurl", &[10 * i, 100 + i]))
+            .collect::<Vec<_>>();
+        let s = benchmark_summary(&runs);
+        assert_eq!(
+            s,
+            "--------------------------------------------------------------------------------\n\
+             Benchmark:\n\
+             tests/hello.hurl:1 (10 requests)\n    p50: 50.000 ms, p90: 90.000 ms, p99: 100.000 ms\n\
+             tests/hello.hurl:4 (10 requests)\n    p50: 105.000 ms, p90: 109.000 ms, p99: 110.000 ms\n"
+        );
+    }
 }
@@ -31,9 +31,9 @@ use hurl_core::typing::Count;
 
 use crate::http::certificate::Certificate;
 use crate::http::curl_cmd::CurlCmd;
-use crate::http::debug::log_body;
+use crate::http::debug::{log_body, log_headers_out};
 use crate::http::header::{
-    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, EXPECT, LOCATION, USER_AGENT,
+    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, EXPECT, LOCATION, UPGRADE, USER_AGENT,
 };
 use crate::http::options::ClientOptions;
 use crate::http::timings::Timings;
@@ -47,6 +47,9 @@ use crate::runner::Output;
 use crate::util::logger::Logger;
 use crate::util::path::ContextDir;
 
+/// A requested feature was not built into libcurl (not exposed by `curl_sys`).
+const CURLE_NOT_BUILT_IN: curl_sys::CURLcode = 4;
+
 /// Defines an HTTP client to execute HTTP requests.
 ///
 /// Most of the methods are delegated to libcurl functions, while some
@@ -176,7 +179,7 @@ impl Client {
         // prior to everything.
         self.handle.reset();
 
-        let (url, method) = self.configure(request_spec, options, logger)?;
+        let (url, method, user_headers) = self.configure(request_spec, options, logger)?;
 
         let start = Instant::now();
         let start_dt = Utc::now();
@@ -185,6 +188,12 @@ impl Client {
         let mut request_headers = HeaderVec::new();
         let mut status_lines = vec![];
         let mut response_headers = vec![];
+        let mut response_trailers = vec![];
+        // Trailers are headers received after the end of the headers of the last status line.
+        let mut in_trailers = false;
+        // Set to true when the server switches to another protocol (a WebSocket upgrade for
+        // instance): the `101 Switching Protocols` response is terminal and has no body.
+        let mut switching_protocols = false;
         let has_body_data = !request_spec.body.bytes().is_empty()
             || !request_spec.form.is_empty()
             || !request_spec.multipart.is_empty();
@@ -196,7 +205,7 @@ impl Client {
         let mut request_body = Vec::<u8>::new();
         let mut response_body = Vec::<u8>::new();
 
-        {
+        let result = {
             let mut transfer = self.handle.transfer();
 
             transfer.debug_function(|info_type, data| match info_type {
@@ -214,11 +223,7 @@ impl Client {
                     // Logs method, version and request headers now.
                     if verbose {
                         logger.debug_method_version_out(&lines[0]);
-                        let headers = request_headers
-                            .iter()
-                            .map(|h| (h.name.as_str(), h.value.as_str()))
-                            .collect::<Vec<_>>();
-                        logger.debug_headers_out(&headers);
+                        log_headers_out(&request_headers, &user_headers, logger);
                     }
 
                     // If we don't send any data, we log an empty body here instead of relying on
@@ -255,6 +260,20 @@ impl Client {
                 if let Some(s) = decode_header(h) {
                     if s.starts_with("HTTP/") {
                         status_lines.push(s);
+                        in_trailers = false;
+                    } else if s.trim().is_empty() {
+                        in_trailers = true;
+                        // libcurl considers a `101` as an informational response and waits for
+                        // a final response that never comes on an upgraded connection, so we
+                        // abort the transfer here.
+                        if let Some(status_line) = status_lines.last() {
+                            if is_protocol_switch(status_line, &response_headers) {
+                                switching_protocols = true;
+                                return false;
+                            }
+                        }
+                    } else if in_trailers {
+                        response_trailers.push(s);
                     } else {
                         response_headers.push(s);
                     }
@@ -267,7 +286,12 @@ impl Client {
                 Ok(data.len())
             })?;
 
-            if let Err(e) = transfer.perform() {
+            transfer.perform()
+        };
+
+        // A protocol switch aborts the transfer on purpose (see `header_function`).
+        if let Err(e) = result {
+            if !(switching_protocols && e.is_write_error()) {
                 let code = e.code() as i32; // due to windows build
                 let description = match e.extra_description() {
                     None => e.description().to_string(),
@@ -297,6 +321,7 @@ impl Client {
             None => return Err(HttpError::CouldNotParseResponse),
         };
         let headers = self.parse_response_headers(&response_headers);
+        let trailers = self.parse_response_headers(&response_trailers);
         let length = response_body.len();
 
         let certificate = self.cert_info(logger)?;
@@ -315,6 +340,7 @@ impl Client {
             version,
             status,
             headers,
+            trailers,
             response_body,
             duration,
             url,
@@ -353,6 +379,15 @@ impl Client {
             }
         }
 
+        // libcurl silently falls back to an earlier HTTP version if the requested version can't be
+        // negotiated (a failed QUIC connection with HTTP/3 for instance).
+        if options.require_http_version && !options.http_version.is_satisfied_by(version) {
+            return Err(HttpError::UnexpectedHttpVersion {
+                requested: options.http_version,
+                actual: version,
+            });
+        }
+
         Ok(Call {
             request,
             response,
@@ -361,13 +396,14 @@ impl Client {
     }
 
     /// Configure libcurl handle to send a `request_spec`, using `options`.
-    /// If configuration is successful, returns a tuple of the concrete requested URL and method.
+    /// If configuration is successful, returns a tuple of the concrete requested URL, method and
+    /// the headers explicitly set by the user.
     fn configure(
         &mut self,
         request_spec: &RequestSpec,
         options: &ClientOptions,
         logger: &mut Logger,
-    ) -> Result<(String, Method), HttpError> {
+    ) -> Result<(String, Method, HeaderVec), HttpError> {
         // Activates cookie engine.
         // See <https://curl.se/libcurl/c/CURLOPT_COOKIEFILE.html>
         // > It also enables the cookie engine, making libcurl parse and send cookies on subsequent
@@ -421,8 +457,27 @@ impl Client {
             let resolves = to_list(&options.resolves);
             self.handle.resolve(resolves)?;
         }
-        self.handle.ssl_verify_host(!options.insecure)?;
-        self.handle.ssl_verify_peer(!options.insecure)?;
+        if let Some(dns_servers) = &options.dns_servers {
+            if let Err(e) = self.handle.dns_servers(dns_servers) {
+                // `CURLOPT_DNS_SERVERS` is only available if libcurl has been built with c-ares.
+                // Depending on libcurl version, an unknown option or a not built-in feature error
+                // is raised. See <https://curl.se/libcurl/c/CURLOPT_DNS_SERVERS.html>
+                return match e.code() {
+                    CURLE_NOT_BUILT_IN | curl_sys::CURLE_UNKNOWN_OPTION => {
+                        Err(HttpError::LibcurlNotBuiltIn {
+                            option: "dns-servers".to_string(),
+                            feature: "c-ares".to_string(),
+                        })
+                    }
+                    _ => Err(e.into()),
+                };
+            }
+        }
+        // As the handle is reused between requests, certificates verification is configured for
+        // each request, depending on the requested host.
+        let insecure = options.is_insecure(&request_spec.url);
+        self.handle.ssl_verify_host(!insecure)?;
+        self.handle.ssl_verify_peer(!insecure)?;
         if let Some(cacert_file) = &options.cacert_file {
             self.handle.cainfo(cacert_file)?;
             self.handle.ssl_cert_type("PEM")?;
@@ -492,14 +547,10 @@ impl Client {
         // headers: `foo:` and `foo;`. The first one can be used to remove libcurl headers (`Host:`)
         // while the second one is used to send an empty header.
         // See <https://github.com/Orange-OpenSource/hurl/issues/3536>
-        let options_headers = options
-            .headers
-            .iter()
-            .map(|h| h.as_str())
-            .collect::<Vec<&str>>();
-        let headers = &request_spec.headers.aggregate_raw_headers(&options_headers);
+        let options_headers = options.headers(&request_spec.url);
+        let headers = request_spec.headers.aggregate_raw_headers(&options_headers);
         self.set_headers(
-            headers,
+            &headers,
             request_spec.implicit_content_type.as_deref(),
             options,
         )?;
@@ -517,7 +568,7 @@ impl Client {
         if *method == Method("HEAD".to_string()) {
             self.handle.nobody(true)?;
         }
-        Ok((url, method.clone()))
+        Ok((url, method.clone(), headers))
     }
 
     /// Generates URL.
@@ -890,6 +941,21 @@ impl HeaderVec {
     }
 }
 
+/// Returns `true` if `status_line` is a `101 Switching Protocols` response, given its `headers`
+/// lines, to a protocol that is not handled by libcurl.
+///
+/// Upgrades to HTTP/2 (`h2c`) are managed by libcurl which goes on reading the final response.
+fn is_protocol_switch(status_line: &str, headers: &[String]) -> bool {
+    let status = status_line.split_whitespace().nth(1);
+    if status != Some("101") {
+        return false;
+    }
+    !headers
+        .iter()
+        .filter_map(|h| Header::parse(h))
+        .any(|h| h.name_eq(UPGRADE) && h.value.trim().eq_ignore_ascii_case("h2c"))
+}
+
 /// Splits an array of bytes into HTTP lines (\r\n separator).
 fn split_lines(data: &[u8]) -> Vec<String> {
     let mut lines = vec![];
@@ -1006,6 +1072,29 @@ mod tests {
     use std::default::Default;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_is_protocol_switch() {
+        let headers = vec![
+            "Upgrade: websocket".to_string(),
+            "Connection: Upgrade".to_string(),
+        ];
+        assert!(is_protocol_switch(
+            "HTTP/1.1 101 Switching Protocols",
+            &headers
+        ));
+        assert!(!is_protocol_switch("HTTP/1.1 100 Continue", &headers));
+        assert!(!is_protocol_switch("HTTP/1.1 200 OK", &headers));
+
+        let headers = vec![
+            "Upgrade: h2c".to_string(),
+            "Connection: Upgrade".to_string(),
+        ];
+        assert!(!is_protocol_switch(
+            "HTTP/1.1 101 Switching Protocols",
+            &headers
+        ));
+    }
+
     #[test]
     fn test_parse_header() {
         assert_eq!(
@@ -1078,6 +1167,47 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_all_cookies() {
+        let cookie = |domain: &str, name: &str, value: &str| Cookie {
+            domain: domain.to_string(),
+            include_subdomain: "FALSE".to_string(),
+            path: "/".to_string(),
+            https: String::new(),
+            expires: String::new(),
+            name: name.to_string(),
+            value: value.to_string(),
+            http_only: false,
+        };
+        let cookie_storage = vec![
+            cookie("localhost", "cookie2", "valueA"),
+            cookie("example.com", "cookie3", "valueC"),
+        ];
+        let request_spec = RequestSpec {
+            url: Url::from_str("http://localhost:8000/cookies").unwrap(),
+            cookies: vec![RequestCookie {
+                name: "cookie2".to_string(),
+                value: "valueB".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        // Request cookies are merged with the cookies of the storage for the same host.
+        assert_eq!(
+            all_cookies(&cookie_storage, &request_spec),
+            vec![
+                RequestCookie {
+                    name: "cookie2".to_string(),
+                    value: "valueB".to_string(),
+                },
+                RequestCookie {
+                    name: "cookie2".to_string(),
+                    value: "valueA".to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_redirect_method() {
         // Status of the response to be redirected | method of the original request | method of the new request
@@ -62,6 +62,26 @@ impl Param {
     }
 }
 
+impl Cookie {
+    /// Merges successive cookie storages `stores` into a single cookie storage.
+    ///
+    /// A cookie is identified by its domain, path and name: a cookie of a storage replaces the
+    /// same cookie of the previous storages.
+    pub fn merge<'a>(stores: impl IntoIterator<Item = &'a [Cookie]>) -> Vec<Cookie> {
+        let mut merged: Vec<Cookie> = vec![];
+        for cookie in stores.into_iter().flatten() {
+            let same = merged.iter_mut().find(|c| {
+                c.domain == cookie.domain && c.path == cookie.path && c.name == cookie.name
+            });
+            match same {
+                Some(same) => *same = cookie.clone(),
+                None => merged.push(cookie.clone()),
+            }
+        }
+        merged
+    }
+}
+
 impl fmt::Display for Cookie {
     /// Formats this cookie using Netscape cookie format.
     ///
@@ -225,4 +245,35 @@ mod tests {
 
         assert_eq!(Cookie::from_str("xxx").err().unwrap(), ParseCookieError);
     }
+
+    #[test]
+    fn merge_cookies() {
+        let cookie = |domain: &str, name: &str, value: &str| Cookie {
+            domain: domain.to_string(),
+            include_subdomain: "FALSE".to_string(),
+            path: "/".to_string(),
+            https: "FALSE".to_string(),
+            expires: "0".to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+            http_only: false,
+        };
+        let store1 = vec![cookie("localhost", "a", "1"), cookie("localhost", "b", "1")];
+        let store2 = vec![
+            cookie("localhost", "a", "2"),
+            cookie("example.org", "b", "2"),
+        ];
+        assert_eq!(
+            Cookie::merge([store1.as_slice(), store2.as_slice()]),
+            vec![
+                cookie("localhost", "a", "2"),
+                cookie("localhost", "b", "1"),
+                cookie("example.org", "b", "2"),
+            ]
+        );
+        assert_eq!(
+            Cookie::merge([store1.as_slice(), store1.as_slice()]),
+            store1
+        );
+    }
 }
@@ -63,11 +63,7 @@ impl CurlCmd {
         let mut params = method_params(request_spec);
         args.append(&mut params);
 
-        let options_headers = options
-            .headers
-            .iter()
-            .map(|h| h.as_str())
-            .collect::<Vec<&str>>();
+        let options_headers = options.headers(&request_spec.url);
         let headers = &request_spec.headers.aggregate_raw_headers(&options_headers);
         let mut params = headers_params(
             headers,
@@ -85,6 +81,12 @@ impl CurlCmd {
         let mut params = other_options_params(context_dir, output, options);
         args.append(&mut params);
 
+        // Hosts with unverified certificates are not supported by curl, we use `--insecure` for
+        // these hosts.
+        if !options.insecure && options.is_insecure(&request_spec.url) {
+            args.push("--insecure".to_string());
+        }
+
         let mut params = url_param(request_spec);
         args.append(&mut params);
 
@@ -396,6 +398,10 @@ impl ClientOptions {
             arguments.push("--cookie".to_string());
             arguments.push(cookie_file.clone());
         }
+        if let Some(ref dns_servers) = self.dns_servers {
+            arguments.push("--dns-servers".to_string());
+            arguments.push(dns_servers.clone());
+        }
         match self.http_version {
             RequestedHttpVersion::Default => {}
             RequestedHttpVersion::Http10 => arguments.push("--http1.0".to_string()),
@@ -444,6 +450,10 @@ impl ClientOptions {
         if self.netrc {
             arguments.push("--netrc".to_string());
         }
+        if let Some(ref no_proxy) = self.no_proxy {
+            arguments.push("--noproxy".to_string());
+            arguments.push(format!("'{no_proxy}'"));
+        }
         if self.path_as_is {
             arguments.push("--path-as-is".to_string());
         }
@@ -625,6 +635,7 @@ mod tests {
             connect_timeout: Duration::from_secs(20),
             connects_to: vec!["example.com:443:host-47.example.com:443".to_string()],
             cookie_input_file: Some("cookie_file".to_string()),
+            dns_servers: Some("1.1.1.1,8.8.8.8".to_string()),
             follow_location: true,
             follow_location_trusted: false,
             headers: vec![
@@ -632,8 +643,13 @@ mod tests {
                 "Test-Header-2: content-2".to_string(),
                 "Test-Header-Empty:".to_string(),
             ],
+            host_headers: vec![
+                "localhost:Test-Header-3: content-3".to_string(),
+                "example.com:Test-Header-4: content-4".to_string(),
+            ],
             http_version: RequestedHttpVersion::Http10,
             insecure: true,
+            insecure_hosts: vec![],
             ip_resolve: IpResolve::IpV6,
             max_filesize: None,
             max_recv_speed: Some(BytesPerSec(8000)),
@@ -644,7 +660,8 @@ mod tests {
             netrc_optional: true,
             path_as_is: true,
             proxy: Some("localhost:3128".to_string()),
-            no_proxy: None,
+            no_proxy: Some("localhost,127.0.0.1".to_string()),
+            require_http_version: false,
             resolves: vec![
                 "foo.com:80:192.168.0.1".to_string(),
                 "bar.com:443:127.0.0.1".to_string(),
@@ -664,10 +681,12 @@ mod tests {
         --header 'Test-Header-1: content-1' \
         --header 'Test-Header-2: content-2' \
         --header 'Test-Header-Empty;' \
+        --header 'Test-Header-3: content-3' \
         --compressed \
         --connect-timeout 20 \
         --connect-to example.com:443:host-47.example.com:443 \
         --cookie cookie_file \
+        --dns-servers 1.1.1.1,8.8.8.8 \
         --http1.0 \
         --insecure \
         --ipv6 \
@@ -676,6 +695,7 @@ mod tests {
         --max-redirs 10 \
         --netrc-file '/var/run/netrc' \
         --netrc-optional \
+        --noproxy 'localhost,127.0.0.1' \
         --path-as-is \
         --proxy 'localhost:3128' \
         --resolve foo.com:80:192.168.0.1 \
@@ -708,6 +728,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn insecure_host() {
+        let context_dir = &ContextDir::default();
+        let cookies = vec![];
+        let options = ClientOptions {
+            insecure_hosts: vec!["staging.local".to_string()],
+            ..Default::default()
+        };
+        let output = None;
+
+        let request = RequestSpec {
+            method: Method("GET".to_string()),
+            url: Url::from_str("https://staging.local/health").unwrap(),
+            ..Default::default()
+        };
+        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+             --insecure \
+            'https://staging.local/health'"
+        );
+
+        let request = RequestSpec {
+            method: Method("GET".to_string()),
+            url: Url::from_str("https://example.org/health").unwrap(),
+            ..Default::default()
+        };
+        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        assert_eq!(cmd.to_string(), "curl 'https://example.org/health'");
+    }
+
     #[test]
     fn url_with_curl_glob() {
         let request = RequestSpec {
@@ -854,6 +906,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn json_request_with_options_content_type() {
+        let request = RequestSpec {
+            method: Method("POST".to_string()),
+            url: Url::from_str("http://localhost/json").unwrap(),
+            body: Body::Text("{\"foo\":\"bar\"}".to_string()),
+            implicit_content_type: Some("application/json".to_string()),
+            ..Default::default()
+        };
+
+        let context_dir = &ContextDir::default();
+        let cookies = vec![];
+        let options = ClientOptions {
+            headers: vec!["Content-Type: application/vnd.api+json".to_string()],
+            ..Default::default()
+        };
+        let output = None;
+
+        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --header 'Content-Type: application/vnd.api+json' \
+            --data '{\"foo\":\"bar\"}' \
+            'http://localhost/json'"
+        );
+    }
+
     #[test]
     fn post_binary_file() {
         let request = RequestSpec {
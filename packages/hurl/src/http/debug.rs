@@ -17,7 +17,7 @@
  */
 use encoding::DecoderTrap;
 
-use crate::http::{mimetype, HeaderVec};
+use crate::http::{mimetype, Header, HeaderVec};
 use crate::util::logger::Logger;
 
 /// Logs a buffer of bytes representing an HTTP request or response `body`.
@@ -49,6 +49,28 @@ pub fn log_body(body: &[u8], headers: &HeaderVec, debug: bool, logger: &mut Logg
     }
 }
 
+/// Logs request `headers` sent over the wire.
+/// Headers that are not present in `user_headers` have been implicitly added by libcurl or Hurl
+/// (like `Host`, `User-Agent`, `Content-Length` etc...) and are marked as automatic.
+pub fn log_headers_out(headers: &HeaderVec, user_headers: &HeaderVec, logger: &mut Logger) {
+    let headers = headers
+        .iter()
+        .map(|h| {
+            (
+                h.name.as_str(),
+                h.value.as_str(),
+                is_auto_header(h, user_headers),
+            )
+        })
+        .collect::<Vec<_>>();
+    logger.debug_headers_out(&headers);
+}
+
+/// Returns `true` if this `header` has not been explicitly set by the user in `user_headers`.
+fn is_auto_header(header: &Header, user_headers: &HeaderVec) -> bool {
+    !user_headers.iter().any(|h| h.name_eq(&header.name))
+}
+
 /// Debug log text.
 pub fn log_text(text: &str, debug: bool, logger: &mut Logger) {
     if text.is_empty() {
@@ -86,3 +108,57 @@ pub fn log_bytes(bytes: &[u8], max: usize, debug: bool, logger: &mut Logger) {
         logger.info(&log);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::logger::{LoggerOptionsBuilder, Verbosity};
+    use crate::util::term::{Stderr, WriteMode};
+
+    #[test]
+    fn test_log_headers_out_marks_auto_headers() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Host", "localhost:8000"));
+        headers.push(Header::new("Accept", "*/*"));
+        headers.push(Header::new("User-Agent", "hurl/6.1.0"));
+        headers.push(Header::new("x-token", "abc"));
+        headers.push(Header::new("Content-Length", "12"));
+
+        let mut user_headers = HeaderVec::new();
+        user_headers.push(Header::new("Accept", "*/*"));
+        user_headers.push(Header::new("X-Token", "abc"));
+
+        let options = LoggerOptionsBuilder::new()
+            .verbosity(Some(Verbosity::Verbose))
+            .build();
+        let stderr = Stderr::new(WriteMode::Buffered);
+        let mut logger = Logger::new(&options, stderr, &[]);
+
+        log_headers_out(&headers, &user_headers, &mut logger);
+
+        assert_eq!(
+            logger.stderr.buffer(),
+            "> Host: localhost:8000 (auto)\n\
+             > Accept: */*\n\
+             > User-Agent: hurl/6.1.0 (auto)\n\
+             > x-token: abc\n\
+             > Content-Length: 12 (auto)\n\
+             >\n"
+        );
+    }
+
+    #[test]
+    fn test_is_auto_header() {
+        let mut user_headers = HeaderVec::new();
+        user_headers.push(Header::new("User-Agent", "foo"));
+
+        assert!(!is_auto_header(
+            &Header::new("user-agent", "foo"),
+            &user_headers
+        ));
+        assert!(is_auto_header(
+            &Header::new("Host", "localhost"),
+            &user_headers
+        ));
+    }
+}
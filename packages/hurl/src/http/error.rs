@@ -16,7 +16,7 @@
 *
 */
 
-use crate::http::RequestedHttpVersion;
+use crate::http::{HttpVersion, RequestedHttpVersion};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HttpError {
@@ -38,11 +38,21 @@ pub enum HttpError {
         option: String,
         minimum_version: String,
     },
+    /// An option requires a feature that libcurl has not been built with.
+    LibcurlNotBuiltIn {
+        option: String,
+        feature: String,
+    },
     TooManyRedirect,
     UnsupportedContentEncoding {
         description: String,
     },
     UnsupportedHttpVersion(RequestedHttpVersion),
+    /// The HTTP version of the response doesn't match the requested HTTP version.
+    UnexpectedHttpVersion {
+        requested: RequestedHttpVersion,
+        actual: HttpVersion,
+    },
     /// Request URL is invalid (URL and reason)
     InvalidUrl(String, String),
     /// The maximum response size has been exceeded.
@@ -70,9 +80,11 @@ impl HttpError {
             HttpError::InvalidUrl(..) => "Invalid URL".to_string(),
             HttpError::Libcurl { .. } => "HTTP connection".to_string(),
             HttpError::LibcurlUnknownOption { .. } => "HTTP connection".to_string(),
+            HttpError::LibcurlNotBuiltIn { .. } => "HTTP connection".to_string(),
             HttpError::TooManyRedirect => "HTTP connection".to_string(),
             HttpError::UnsupportedContentEncoding { .. } => "Decompression error".to_string(),
             HttpError::UnsupportedHttpVersion(_) => "Unsupported HTTP version".to_string(),
+            HttpError::UnexpectedHttpVersion { .. } => "Unexpected HTTP version".to_string(),
         }
     }
 
@@ -99,10 +111,16 @@ impl HttpError {
                 option,
                 minimum_version,
             } => format!("Option {option} requires libcurl version {minimum_version} or higher"),
+            HttpError::LibcurlNotBuiltIn { option, feature } => {
+                format!("Option {option} requires libcurl to be built with {feature} support")
+            }
             HttpError::TooManyRedirect => "too many redirect".to_string(),
             HttpError::UnsupportedHttpVersion(version) => {
                 format!("{version} is not supported, check --version").to_string()
             }
+            HttpError::UnexpectedHttpVersion { requested, actual } => {
+                format!("{requested} has been requested but {actual} has been used")
+            }
             HttpError::UnsupportedContentEncoding { description } => {
                 format!("compression {description} is not supported").to_string()
             }
@@ -26,14 +26,22 @@ pub const AUTHORIZATION: &str = "Authorization";
 pub const COOKIE: &str = "Cookie";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding>
 pub const CONTENT_ENCODING: &str = "Content-Encoding";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Length>
+pub const CONTENT_LENGTH: &str = "Content-Length";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Type>
 pub const CONTENT_TYPE: &str = "Content-Type";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Expect>
 pub const EXPECT: &str = "Expect";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Link>
+pub const LINK: &str = "Link";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Location>
 pub const LOCATION: &str = "Location";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Server-Timing>
+pub const SERVER_TIMING: &str = "Server-Timing";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie>
 pub const SET_COOKIE: &str = "Set-Cookie";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Upgrade>
+pub const UPGRADE: &str = "Upgrade";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/User-Agent>
 pub const USER_AGENT: &str = "User-Agent";
 
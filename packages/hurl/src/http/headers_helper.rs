@@ -28,6 +28,15 @@ impl HeaderVec {
         self.get(CONTENT_TYPE).map(|h| h.value.as_str())
     }
 
+    /// Returns the explicit Content-type header value if it overrides a different
+    /// `implicit_content_type` (the content type inferred from the request body).
+    ///
+    /// An explicit Content-type header always wins over the implicit content type.
+    pub fn content_type_override(&self, implicit_content_type: &str) -> Option<&str> {
+        self.content_type()
+            .filter(|content_type| *content_type != implicit_content_type)
+    }
+
     /// Returns character encoding from this list of headers.
     ///
     /// If no character encoding can be found, returns UTF-8.
@@ -120,6 +129,23 @@ mod tests {
         assert_eq!(headers.character_encoding().unwrap().name(), "utf-8");
     }
 
+    #[test]
+    fn content_type_override() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Host", "localhost:8000"));
+        assert_eq!(headers.content_type_override("application/json"), None);
+
+        headers.push(Header::new("content-type", "application/vnd.api+json"));
+        assert_eq!(
+            headers.content_type_override("application/json"),
+            Some("application/vnd.api+json")
+        );
+        assert_eq!(
+            headers.content_type_override("application/vnd.api+json"),
+            None
+        );
+    }
+
     #[test]
     fn aggregate_raw_headers() {
         let mut headers = HeaderVec::new();
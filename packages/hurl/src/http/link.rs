@@ -0,0 +1,153 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::http::header::LINK;
+use crate::http::HeaderVec;
+
+/// Represents a link of a `Link` header, like `<https://api.example.com/items?page=2>; rel="next"`.
+///
+/// See <https://www.rfc-editor.org/rfc/rfc8288>
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Link {
+    pub target: String,
+    pub rels: Vec<String>,
+}
+
+impl HeaderVec {
+    /// Returns the list of links of all the `Link` headers.
+    pub fn links(&self) -> Vec<Link> {
+        self.values(LINK)
+            .iter()
+            .flat_map(|value| parse(value))
+            .collect()
+    }
+}
+
+impl Link {
+    /// Returns true if this link has the relation type `rel` (relation types are case-insensitive).
+    pub fn has_rel(&self, rel: &str) -> bool {
+        self.rels.iter().any(|r| r.eq_ignore_ascii_case(rel))
+    }
+}
+
+/// Parses a `Link` header `value` to a list of links.
+///
+/// Each link is made of a target URI enclosed in `<` and `>`, followed by `;` separated
+/// parameters. The `rel` parameter can hold several space separated relation types. Links are
+/// separated by `,`, that can also appear in the target URI and in quoted parameter values.
+fn parse(value: &str) -> Vec<Link> {
+    let mut links = vec![];
+    let mut rest = value;
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let target = rest[start + 1..start + end].trim().to_string();
+        rest = &rest[start + end + 1..];
+
+        // Parameters extend up to the next unquoted `,`.
+        let mut quoted = false;
+        let mut params_end = rest.len();
+        for (i, c) in rest.char_indices() {
+            if c == '"' {
+                quoted = !quoted;
+            } else if c == ',' && !quoted {
+                params_end = i;
+                break;
+            }
+        }
+        let params = &rest[..params_end];
+        rest = &rest[params_end..];
+
+        let rels = params
+            .split(';')
+            .filter_map(|param| param.split_once('='))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("rel"))
+            .map(|(_, value)| {
+                value
+                    .trim()
+                    .trim_matches('"')
+                    .split_whitespace()
+                    .map(|rel| rel.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        links.push(Link { target, rels });
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::Header;
+
+    fn link(target: &str, rels: &[&str]) -> Link {
+        Link {
+            target: target.to_string(),
+            rels: rels.iter().map(|r| r.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(""), vec![]);
+        assert_eq!(
+            parse(
+                "<https://api.example.com/items?page=2>; rel=\"next\", \
+                 <https://api.example.com/items?page=5>; rel=\"last\""
+            ),
+            vec![
+                link("https://api.example.com/items?page=2", &["next"]),
+                link("https://api.example.com/items?page=5", &["last"]),
+            ]
+        );
+        assert_eq!(
+            parse("</items?page=1>; rel=\"first prev\"; title=\"a, b\",</items?page=3>;rel=next"),
+            vec![
+                link("/items?page=1", &["first", "prev"]),
+                link("/items?page=3", &["next"]),
+            ]
+        );
+        assert_eq!(
+            parse("<https://example.org/a,b>; title=\"No rel\""),
+            vec![link("https://example.org/a,b", &[])]
+        );
+    }
+
+    #[test]
+    fn test_has_rel() {
+        let link = link("/items?page=2", &["next", "Prefetch"]);
+        assert!(link.has_rel("next"));
+        assert!(link.has_rel("prefetch"));
+        assert!(!link.has_rel("last"));
+    }
+
+    #[test]
+    fn test_links_multiple_headers() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Link", "</items?page=2>; rel=\"next\""));
+        headers.push(Header::new("link", "</items?page=5>; rel=\"last\""));
+        assert_eq!(
+            headers.links(),
+            vec![
+                link("/items?page=2", &["next"]),
+                link("/items?page=5", &["last"]),
+            ]
+        );
+    }
+}
@@ -30,10 +30,13 @@ pub(crate) use self::error::HttpError;
 pub use self::header::{
     Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, USER_AGENT,
 };
+pub(crate) use self::mimetype::charset;
 pub(crate) use self::options::{ClientOptions, Verbosity};
 pub use self::request::{IpResolve, Request, RequestedHttpVersion};
+pub use self::request_spec::RequestCompression;
 pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
 pub use self::response::{HttpVersion, Response};
+pub(crate) use self::response_decoding::ContentEncoding;
 #[cfg(test)]
 pub use self::tests::*;
 pub use self::timings::Timings;
@@ -51,6 +54,7 @@ mod easy_ext;
 mod error;
 mod header;
 mod headers_helper;
+mod link;
 mod mimetype;
 mod options;
 mod request;
@@ -59,6 +63,7 @@ mod response;
 mod response_cookie;
 mod response_debug;
 mod response_decoding;
+mod server_timing;
 #[cfg(test)]
 mod tests;
 mod timings;
@@ -19,7 +19,7 @@ use hurl_core::typing::{BytesPerSec, Count};
 use std::time::Duration;
 
 use crate::http::request::RequestedHttpVersion;
-use crate::http::IpResolve;
+use crate::http::{IpResolve, Url};
 
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
@@ -31,11 +31,16 @@ pub struct ClientOptions {
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
     pub cookie_input_file: Option<String>,
+    pub dns_servers: Option<String>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
     pub headers: Vec<String>,
+    /// Headers only sent to a given host, as `HOST:HEADER` strings
+    pub host_headers: Vec<String>,
     pub http_version: RequestedHttpVersion,
     pub insecure: bool,
+    /// Hosts for which SSL certificates are not verified
+    pub insecure_hosts: Vec<String>,
     pub ip_resolve: IpResolve,
     pub max_filesize: Option<u64>,
     pub max_recv_speed: Option<BytesPerSec>,
@@ -47,6 +52,8 @@ pub struct ClientOptions {
     pub no_proxy: Option<String>,
     pub path_as_is: bool,
     pub proxy: Option<String>,
+    /// Fails if the response HTTP version doesn't match the requested `http_version`
+    pub require_http_version: bool,
     pub resolves: Vec<String>,
     pub ssl_no_revoke: bool,
     pub timeout: Duration,
@@ -74,11 +81,14 @@ impl Default for ClientOptions {
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
             cookie_input_file: None,
+            dns_servers: None,
             follow_location: false,
             follow_location_trusted: false,
             headers: vec![],
+            host_headers: vec![],
             http_version: RequestedHttpVersion::default(),
             insecure: false,
+            insecure_hosts: vec![],
             ip_resolve: IpResolve::default(),
             max_filesize: None,
             max_recv_speed: None,
@@ -90,6 +100,7 @@ impl Default for ClientOptions {
             no_proxy: None,
             path_as_is: false,
             proxy: None,
+            require_http_version: false,
             resolves: vec![],
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
@@ -100,3 +111,87 @@ impl Default for ClientOptions {
         }
     }
 }
+
+impl ClientOptions {
+    /// Returns true if SSL certificates must not be verified when requesting `url`.
+    ///
+    /// Certificates are not verified for every host with `insecure`, or only for the hosts listed
+    /// in `insecure_hosts`.
+    pub fn is_insecure(&self, url: &Url) -> bool {
+        let host = url.host();
+        self.insecure
+            || self
+                .insecure_hosts
+                .iter()
+                .any(|h| h.eq_ignore_ascii_case(&host))
+    }
+
+    /// Returns the raw headers to add to a request to `url`.
+    ///
+    /// These headers are all the `headers`, followed by the `host_headers` whose host matches
+    /// the host of `url`.
+    pub fn headers(&self, url: &Url) -> Vec<&str> {
+        let host = url.host();
+        let host_headers = self.host_headers.iter().filter_map(|h| {
+            let (header_host, header) = h.split_once(':')?;
+            header_host
+                .eq_ignore_ascii_case(&host)
+                .then_some(header.trim_start())
+        });
+        self.headers
+            .iter()
+            .map(|h| h.as_str())
+            .chain(host_headers)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers() {
+        let api = "https://api.example.com/users".parse::<Url>().unwrap();
+        let other = "https://example.org/".parse::<Url>().unwrap();
+
+        let options = ClientOptions {
+            headers: vec!["X-Trace: 1".to_string()],
+            host_headers: vec![
+                "api.example.com:Authorization: Bearer x".to_string(),
+                "API.example.com: X-Api: 2".to_string(),
+                "example.net:Authorization: Bearer y".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            options.headers(&api),
+            vec!["X-Trace: 1", "Authorization: Bearer x", "X-Api: 2"]
+        );
+        assert_eq!(options.headers(&other), vec!["X-Trace: 1"]);
+    }
+
+    #[test]
+    fn test_is_insecure() {
+        let staging = "https://staging.local/health".parse::<Url>().unwrap();
+        let production = "https://example.org/health".parse::<Url>().unwrap();
+
+        let options = ClientOptions::default();
+        assert!(!options.is_insecure(&staging));
+        assert!(!options.is_insecure(&production));
+
+        let options = ClientOptions {
+            insecure_hosts: vec!["Staging.local".to_string()],
+            ..Default::default()
+        };
+        assert!(options.is_insecure(&staging));
+        assert!(!options.is_insecure(&production));
+
+        let options = ClientOptions {
+            insecure: true,
+            ..Default::default()
+        };
+        assert!(options.is_insecure(&staging));
+        assert!(options.is_insecure(&production));
+    }
+}
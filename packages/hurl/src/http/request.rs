@@ -19,7 +19,7 @@ use std::fmt;
 
 use crate::http::header::{HeaderVec, COOKIE};
 use crate::http::url::Url;
-use crate::http::RequestCookie;
+use crate::http::{HttpVersion, RequestCookie};
 
 /// Represents a runtime HTTP request.
 /// This is a real request, that has been executed by our HTTP client.
@@ -62,6 +62,19 @@ impl fmt::Display for RequestedHttpVersion {
     }
 }
 
+impl RequestedHttpVersion {
+    /// Returns true if a response with HTTP `version` honors this requested version.
+    pub fn is_satisfied_by(&self, version: HttpVersion) -> bool {
+        match self {
+            RequestedHttpVersion::Default => true,
+            RequestedHttpVersion::Http10 => version == HttpVersion::Http10,
+            RequestedHttpVersion::Http11 => version == HttpVersion::Http11,
+            RequestedHttpVersion::Http2 => version == HttpVersion::Http2,
+            RequestedHttpVersion::Http3 => version == HttpVersion::Http3,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 pub enum IpResolve {
     /// Default, can use addresses of all IP versions that your system allows.
@@ -92,6 +105,18 @@ impl Request {
             .flat_map(|h| parse_cookies(h.value.as_str().trim()))
             .collect()
     }
+
+    /// Serializes this request to bytes: a request line with the method and the URL, the headers,
+    /// an empty line and the body.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = format!("{} {}\n", self.method, self.url).into_bytes();
+        for header in &self.headers {
+            bytes.extend(format!("{header}\n").into_bytes());
+        }
+        bytes.push(b'\n');
+        bytes.extend(&self.body);
+        bytes
+    }
 }
 
 fn parse_cookies(s: &str) -> Vec<RequestCookie> {
@@ -168,6 +193,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_bytes() {
+        assert_eq!(
+            std::str::from_utf8(&hello_request().to_bytes()).unwrap(),
+            "GET http://localhost:8000/hello\n\
+             Host: localhost:8000\n\
+             Accept: */*\n\
+             User-Agent: hurl/1.0\n\
+             content-type: application/json\n\
+             \n"
+        );
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "text/plain"));
+        let url = "http://localhost:8000/data".parse().unwrap();
+        let request = Request::new("POST", url, headers, b"Hello".to_vec());
+        assert_eq!(
+            request.to_bytes(),
+            b"POST http://localhost:8000/data\nContent-Type: text/plain\n\nHello"
+        );
+    }
+
     #[test]
     fn test_parse_cookies() {
         assert_eq!(
@@ -195,4 +242,14 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_requested_http_version_is_satisfied_by() {
+        assert!(RequestedHttpVersion::Default.is_satisfied_by(HttpVersion::Http11));
+        assert!(RequestedHttpVersion::Default.is_satisfied_by(HttpVersion::Http3));
+        assert!(RequestedHttpVersion::Http11.is_satisfied_by(HttpVersion::Http11));
+        assert!(!RequestedHttpVersion::Http11.is_satisfied_by(HttpVersion::Http10));
+        assert!(RequestedHttpVersion::Http3.is_satisfied_by(HttpVersion::Http3));
+        assert!(!RequestedHttpVersion::Http3.is_satisfied_by(HttpVersion::Http2));
+    }
 }
@@ -16,9 +16,10 @@
  *
  */
 use core::fmt;
+use std::io::Write;
 
-use crate::http::header::HeaderVec;
-use crate::http::{Param, RequestCookie, Url};
+use crate::http::header::{HeaderVec, CONTENT_ENCODING, CONTENT_LENGTH};
+use crate::http::{Header, Param, RequestCookie, Url};
 
 /// Represents the HTTP request asked to be executed by our user (different from the runtime
 /// executed HTTP request [`crate::http::Request`].
@@ -89,6 +90,78 @@ impl Body {
     }
 }
 
+/// A compression algorithm used to compress a request body.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RequestCompression {
+    /// A format using the Lempel-Ziv coding (LZ77), with a 32-bit CRC.
+    Gzip,
+    /// Using the zlib structure (defined in RFC 1950) with the deflate compression algorithm.
+    Deflate,
+}
+
+impl RequestCompression {
+    /// Returns a compression from a `Content-Encoding` value `s`.
+    pub fn parse(s: &str) -> Option<RequestCompression> {
+        match s {
+            "gzip" => Some(RequestCompression::Gzip),
+            "deflate" => Some(RequestCompression::Deflate),
+            _ => None,
+        }
+    }
+
+    /// Compresses `data` bytes.
+    fn encode(&self, data: &[u8]) -> Vec<u8> {
+        // Writing to a vector never fails, so we can safely unwrap here.
+        match self {
+            RequestCompression::Gzip => {
+                let mut encoder = libflate::gzip::Encoder::new(Vec::new()).unwrap();
+                encoder.write_all(data).unwrap();
+                encoder.finish().into_result().unwrap()
+            }
+            RequestCompression::Deflate => {
+                let mut encoder = libflate::zlib::Encoder::new(Vec::new()).unwrap();
+                encoder.write_all(data).unwrap();
+                encoder.finish().into_result().unwrap()
+            }
+        }
+    }
+}
+
+impl fmt::Display for RequestCompression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            RequestCompression::Gzip => "gzip",
+            RequestCompression::Deflate => "deflate",
+        };
+        write!(f, "{value}")
+    }
+}
+
+impl RequestSpec {
+    /// Compresses the body of this request with `compression` and sets the `Content-Encoding`
+    /// header accordingly.
+    ///
+    /// The body is not compressed if it's empty, or if a `Content-Encoding` header has already
+    /// been set by the user (in this case, we consider that the body is already encoded). If a
+    /// `Content-Length` header has been set, its value is updated with the compressed body size.
+    pub fn compress_body(&mut self, compression: RequestCompression) {
+        let data = self.body.bytes();
+        if data.is_empty() || self.headers.contains_key(CONTENT_ENCODING) {
+            return;
+        }
+        let data = compression.encode(&data);
+        let length = data.len().to_string();
+        let has_length = self.headers.contains_key(CONTENT_LENGTH);
+        self.headers.retain(|h| !h.name_eq(CONTENT_LENGTH));
+        self.headers
+            .push(Header::new(CONTENT_ENCODING, &compression.to_string()));
+        if has_length {
+            self.headers.push(Header::new(CONTENT_LENGTH, &length));
+        }
+        self.body = Body::Binary(data);
+    }
+}
+
 impl fmt::Display for Method {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -113,3 +186,80 @@ impl fmt::Display for FileParam {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::ContentEncoding;
+
+    fn request_spec(body: &str, headers: &[Header]) -> RequestSpec {
+        let mut request = RequestSpec {
+            method: Method("POST".to_string()),
+            body: Body::Text(body.to_string()),
+            ..Default::default()
+        };
+        for header in headers {
+            request.headers.push(header.clone());
+        }
+        request
+    }
+
+    #[test]
+    fn test_compress_body() {
+        let data = "{\"level\":\"info\",\"message\":\"Hello\"}\n";
+
+        let mut request = request_spec(data, &[]);
+        request.compress_body(RequestCompression::Gzip);
+        assert_eq!(
+            request.headers.get(CONTENT_ENCODING),
+            Some(&Header::new(CONTENT_ENCODING, "gzip"))
+        );
+        assert!(!request.headers.contains_key(CONTENT_LENGTH));
+        assert_eq!(
+            ContentEncoding::Gzip.decode(&request.body.bytes()).unwrap(),
+            data.as_bytes()
+        );
+
+        let mut request = request_spec(data, &[]);
+        request.compress_body(RequestCompression::Deflate);
+        assert_eq!(
+            request.headers.get(CONTENT_ENCODING),
+            Some(&Header::new(CONTENT_ENCODING, "deflate"))
+        );
+        assert_eq!(
+            ContentEncoding::Deflate
+                .decode(&request.body.bytes())
+                .unwrap(),
+            data.as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_compress_body_updates_content_length() {
+        let data = "Hello World!";
+        let mut request = request_spec(data, &[Header::new("content-length", "12")]);
+        request.compress_body(RequestCompression::Gzip);
+
+        let length = request.body.bytes().len().to_string();
+        assert_eq!(request.headers.get_all(CONTENT_LENGTH).len(), 1);
+        assert_eq!(
+            request.headers.get(CONTENT_LENGTH),
+            Some(&Header::new(CONTENT_LENGTH, &length))
+        );
+    }
+
+    #[test]
+    fn test_compress_body_not_compressed() {
+        // Empty body is not compressed.
+        let mut request = request_spec("", &[]);
+        request.compress_body(RequestCompression::Gzip);
+        assert!(!request.headers.contains_key(CONTENT_ENCODING));
+        assert_eq!(request.body, Body::Text(String::new()));
+
+        // Body with a user provided `Content-Encoding` is not compressed twice.
+        let mut request = request_spec("Hello", &[Header::new("Content-Encoding", "br")]);
+        request.compress_body(RequestCompression::Gzip);
+        assert_eq!(request.headers.get_all(CONTENT_ENCODING).len(), 1);
+        assert_eq!(request.body, Body::Text("Hello".to_string()));
+    }
+}
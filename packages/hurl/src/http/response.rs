@@ -28,19 +28,26 @@ pub struct Response {
     pub version: HttpVersion,
     pub status: u32,
     pub headers: HeaderVec,
+    /// Trailing headers, sent by the server after the body
+    pub trailers: HeaderVec,
+    /// Body bytes as received, before any content decoding (see [`Response::uncompress_body`])
     pub body: Vec<u8>,
     pub duration: Duration,
     pub url: Url,
     /// The end-user certificate, in the response certificate chain
     pub certificate: Option<Certificate>,
+    /// Charset used to decode the body, overriding the charset of the `Content-Type` header
+    pub charset: Option<String>,
 }
 
 impl Response {
     /// Creates a new HTTP response
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         version: HttpVersion,
         status: u32,
         headers: HeaderVec,
+        trailers: HeaderVec,
         body: Vec<u8>,
         duration: Duration,
         url: Url,
@@ -50,10 +57,12 @@ impl Response {
             version,
             status,
             headers,
+            trailers,
             body,
             duration,
             url,
             certificate,
+            charset: None,
         }
     }
 }
@@ -93,10 +102,12 @@ mod tests {
             version: HttpVersion::Http10,
             status: 200,
             headers,
+            trailers: HeaderVec::new(),
             body: vec![],
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            charset: None,
         };
         assert_eq!(response.headers.values("Content-Length"), vec!["12"]);
         assert!(response.headers.values("Unknown").is_empty());
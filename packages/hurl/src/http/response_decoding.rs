@@ -23,7 +23,7 @@
 /// See https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding
 use std::io::prelude::*;
 
-use encoding::DecoderTrap;
+use encoding::{DecoderTrap, EncodingRef};
 
 use crate::http::{mimetype, HttpError, Response};
 
@@ -73,7 +73,7 @@ impl Response {
         } else {
             &self.uncompress_body()?
         };
-        let character_encoding = self.headers.character_encoding()?;
+        let character_encoding = self.character_encoding()?;
         match character_encoding.decode(body, DecoderTrap::Strict) {
             Ok(s) => Ok(s),
             Err(_) => Err(HttpError::InvalidDecoding {
@@ -82,6 +82,21 @@ impl Response {
         }
     }
 
+    /// Returns the character encoding used to decode the body.
+    ///
+    /// The charset of the response, if any, overrides the charset of the `Content-Type` header.
+    fn character_encoding(&self) -> Result<EncodingRef, HttpError> {
+        match &self.charset {
+            Some(charset) => match encoding::label::encoding_from_whatwg_label(charset) {
+                Some(enc) => Ok(enc),
+                None => Err(HttpError::InvalidCharset {
+                    charset: charset.clone(),
+                }),
+            },
+            None => self.headers.character_encoding(),
+        }
+    }
+
     /// Returns true if response is an HTML response.
     pub fn is_html(&self) -> bool {
         self.headers.content_type().is_some_and(mimetype::is_html)
@@ -169,10 +184,12 @@ pub mod tests {
             version: HttpVersion::Http10,
             status: 200,
             headers: HeaderVec::new(),
+            trailers: HeaderVec::new(),
             body: vec![],
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            charset: None,
         }
     }
 
@@ -398,6 +415,35 @@ pub mod tests {
         );
     }
 
+    #[test]
+    pub fn test_text_with_charset() {
+        // A latin-1 body, labelled as UTF-8 by the server.
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "text/plain; charset=utf-8"));
+        let mut response = Response {
+            headers,
+            body: vec![0x63, 0x61, 0x66, 0xe9],
+            ..default_response()
+        };
+        assert_eq!(
+            response.text().err().unwrap(),
+            HttpError::InvalidDecoding {
+                charset: "utf-8".to_string()
+            }
+        );
+
+        response.charset = Some("ISO-8859-1".to_string());
+        assert_eq!(response.text().unwrap(), "café".to_string());
+
+        response.charset = Some("xxx".to_string());
+        assert_eq!(
+            response.text().err().unwrap(),
+            HttpError::InvalidCharset {
+                charset: "xxx".to_string()
+            }
+        );
+    }
+
     #[test]
     pub fn test_invalid_charset() {
         let mut headers = HeaderVec::new();
@@ -0,0 +1,134 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::http::header::SERVER_TIMING;
+use crate::http::HeaderVec;
+
+/// Represents a metric of a `Server-Timing` header, like `db;dur=53`.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Server-Timing>
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerTimingMetric {
+    pub name: String,
+    pub duration: Option<f64>,
+}
+
+impl HeaderVec {
+    /// Returns the list of metrics of all the `Server-Timing` headers.
+    ///
+    /// Multiple `Server-Timing` headers are concatenated before being parsed.
+    pub fn server_timing(&self) -> Vec<ServerTimingMetric> {
+        let value = self.values(SERVER_TIMING).join(",");
+        parse(&value)
+    }
+}
+
+/// Parses a `Server-Timing` header `value` to a list of metrics.
+///
+/// Metrics are separated by `,` and metric parameters by `;`. Quoted parameter values (like
+/// `desc="a, b"`) can contain separators. Unparsable `dur` values are ignored.
+fn parse(value: &str) -> Vec<ServerTimingMetric> {
+    split_unquoted(value, ',')
+        .iter()
+        .filter_map(|metric| {
+            let params = split_unquoted(metric, ';');
+            let name = params.first()?.trim();
+            if name.is_empty() {
+                return None;
+            }
+            let duration = params.iter().skip(1).find_map(|param| {
+                let (key, value) = param.split_once('=')?;
+                if key.trim().eq_ignore_ascii_case("dur") {
+                    value.trim().trim_matches('"').parse::<f64>().ok()
+                } else {
+                    None
+                }
+            });
+            Some(ServerTimingMetric {
+                name: name.to_string(),
+                duration,
+            })
+        })
+        .collect()
+}
+
+/// Splits `value` on every `separator` that is not enclosed in double quotes.
+fn split_unquoted(value: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut quoted = false;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if c == separator && !quoted {
+            parts.push(&value[start..i]);
+            start = i + c.len_utf8();
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::Header;
+
+    fn metric(name: &str, duration: Option<f64>) -> ServerTimingMetric {
+        ServerTimingMetric {
+            name: name.to_string(),
+            duration,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(""), vec![]);
+        assert_eq!(
+            parse("db;dur=53, app;dur=47.2"),
+            vec![metric("db", Some(53.0)), metric("app", Some(47.2))]
+        );
+        assert_eq!(
+            parse("cache;desc=\"Cache Read\";dur=23.2"),
+            vec![metric("cache", Some(23.2))]
+        );
+        assert_eq!(
+            parse("missedCache, cpu;dur=abc"),
+            vec![metric("missedCache", None), metric("cpu", None)]
+        );
+        assert_eq!(
+            parse("total;desc=\"a, b; c\";dur=\"12\""),
+            vec![metric("total", Some(12.0))]
+        );
+    }
+
+    #[test]
+    fn test_server_timing_multiple_headers() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Server-Timing", "db;dur=53"));
+        headers.push(Header::new("Content-Type", "text/plain"));
+        headers.push(Header::new("server-timing", "app;dur=47.2, cache"));
+        assert_eq!(
+            headers.server_timing(),
+            vec![
+                metric("db", Some(53.0)),
+                metric("app", Some(47.2)),
+                metric("cache", None)
+            ]
+        );
+    }
+}
@@ -28,10 +28,12 @@ fn default_response() -> Response {
         version: HttpVersion::Http10,
         status: 200,
         headers: HeaderVec::new(),
+        trailers: HeaderVec::new(),
         body: vec![],
         duration: Default::default(),
         url: Url::from_str("http://localhost").unwrap(),
         certificate: None,
+        charset: None,
     }
 }
 
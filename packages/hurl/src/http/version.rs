@@ -20,6 +20,7 @@ use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CurlVersionInfo {
+    pub version: String,
     pub host: String,
     pub libraries: Vec<String>,
     pub features: Vec<String>,
@@ -33,7 +34,8 @@ pub struct CurlVersionInfo {
 pub fn libcurl_version_info() -> CurlVersionInfo {
     let version = curl::Version::get();
     let host = version.host().to_string();
-    let mut libraries = vec![format!("libcurl/{}", version.version())];
+    let curl_version = version.version().to_string();
+    let mut libraries = vec![format!("libcurl/{curl_version}")];
     if let Some(s) = version.ssl_version() {
         libraries.push(s.to_string());
     }
@@ -119,6 +121,7 @@ pub fn libcurl_version_info() -> CurlVersionInfo {
     features.sort_by_key(|k| k.to_lowercase());
 
     CurlVersionInfo {
+        version: curl_version,
         host,
         libraries,
         features,
@@ -76,7 +76,11 @@ struct HurlResultJson {
 struct EntryResultJson {
     index: usize,
     line: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
     calls: Vec<CallJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    redirects: Vec<RedirectJson>,
     captures: Vec<CaptureJson>,
     asserts: Vec<AssertJson>,
     time: u64,
@@ -101,6 +105,12 @@ struct CallJson {
     timings: TimingsJson,
 }
 
+#[derive(Deserialize, Serialize)]
+struct RedirectJson {
+    url: String,
+    status: u32,
+}
+
 #[derive(Deserialize, Serialize)]
 struct CaptureJson {
     name: String,
@@ -122,13 +132,21 @@ struct RequestJson {
     headers: Vec<HeaderJson>,
     cookies: Vec<RequestCookieJson>,
     query_string: Vec<ParamJson>,
+    /// Rendered request body, truncated to [`MAX_REQUEST_BODY_SIZE`] bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
 }
 
+/// Maximum size in bytes of a request body serialized in the JSON report.
+const MAX_REQUEST_BODY_SIZE: usize = 64 * 1024;
+
 #[derive(Deserialize, Serialize)]
 struct ResponseJson {
     http_version: String,
     status: u32,
     headers: Vec<HeaderJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    trailers: Vec<HeaderJson>,
     cookies: Vec<ResponseCookieJson>,
     #[serde(skip_serializing_if = "Option::is_none")]
     certificate: Option<CertificateJson>,
@@ -237,6 +255,11 @@ impl EntryResultJson {
             .iter()
             .map(|c| CallJson::from_call(c, response_dir, secrets))
             .collect::<Result<Vec<_>, _>>()?;
+        // The redirection chain is made of all the calls, except the last one.
+        let redirects = entry.calls[..entry.calls.len().saturating_sub(1)]
+            .iter()
+            .map(|c| RedirectJson::from_call(c, secrets))
+            .collect::<Vec<_>>();
         let captures = entry
             .captures
             .iter()
@@ -250,7 +273,9 @@ impl EntryResultJson {
         Ok(EntryResultJson {
             index: entry.entry_index,
             line: entry.source_info.start.line,
+            name: entry.name.as_ref().map(|n| n.redact(secrets)),
             calls,
+            redirects,
             captures,
             asserts,
             time: entry.transfer_duration.as_millis() as u64,
@@ -308,14 +333,35 @@ impl RequestJson {
             .iter()
             .map(|p| ParamJson::from_param(p, secrets))
             .collect::<Vec<_>>();
+        let body = request_body(&request.body, secrets);
         RequestJson {
             method: request.method.clone(),
             url: request.url.to_string().redact(secrets),
             headers,
             cookies,
             query_string,
+            body,
+        }
+    }
+}
+
+/// Returns a textual representation of a request `body`, or `None` if the body is empty.
+///
+/// `secrets` strings are redacted before the body is truncated to [`MAX_REQUEST_BODY_SIZE`] bytes,
+/// so a secret is never partially exported.
+fn request_body(body: &[u8], secrets: &[&str]) -> Option<String> {
+    if body.is_empty() {
+        return None;
+    }
+    let mut body = String::from_utf8_lossy(body).redact(secrets);
+    if body.len() > MAX_REQUEST_BODY_SIZE {
+        let mut end = MAX_REQUEST_BODY_SIZE;
+        while !body.is_char_boundary(end) {
+            end -= 1;
         }
+        body.truncate(end);
     }
+    Some(body)
 }
 
 impl ResponseJson {
@@ -335,6 +381,11 @@ impl ResponseJson {
             .iter()
             .map(|h| HeaderJson::from_header(h, secrets))
             .collect::<Vec<_>>();
+        let trailers = response
+            .trailers
+            .iter()
+            .map(|h| HeaderJson::from_header(h, secrets))
+            .collect::<Vec<_>>();
         let cookies = response
             .cookies()
             .iter()
@@ -371,6 +422,7 @@ impl ResponseJson {
             http_version: http_version.to_string(),
             status: response.status,
             headers,
+            trailers,
             cookies,
             certificate,
             body,
@@ -452,6 +504,15 @@ impl CertificateJson {
     }
 }
 
+impl RedirectJson {
+    fn from_call(call: &Call, secrets: &[&str]) -> Self {
+        RedirectJson {
+            url: call.request.url.to_string().redact(secrets),
+            status: call.response.status,
+        }
+    }
+}
+
 impl CaptureJson {
     fn from_capture(c: &CaptureResult, secrets: &[&str]) -> Self {
         CaptureJson {
@@ -509,3 +570,88 @@ fn write_response(response: &Response, dir: &Path) -> Result<PathBuf, io::Error>
     file.write_all(&response.body)?;
     Ok(relative_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::http::{HeaderVec, Url};
+
+    #[test]
+    fn call_to_json_has_timings() {
+        let url = Url::from_str("http://localhost:8000/hello").unwrap();
+        let call = Call {
+            request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+            response: Response::new(
+                HttpVersion::Http11,
+                200,
+                HeaderVec::new(),
+                HeaderVec::new(),
+                vec![],
+                Duration::from_millis(12),
+                url,
+                None,
+            ),
+            timings: Timings {
+                name_lookup: Duration::from_micros(100),
+                connect: Duration::from_micros(200),
+                app_connect: Duration::from_micros(0),
+                pre_transfer: Duration::from_micros(250),
+                start_transfer: Duration::from_micros(10_000),
+                total: Duration::from_micros(12_000),
+                ..Default::default()
+            },
+        };
+        let call = CallJson::from_call(&call, None, &[]).unwrap();
+        let value = serde_json::to_value(call).unwrap();
+        let timings = value.get("timings").unwrap();
+
+        let expected = [
+            ("name_lookup", 100),
+            ("connect", 200),
+            ("app_connect", 0),
+            ("pre_transfer", 250),
+            ("start_transfer", 10_000),
+            ("total", 12_000),
+        ];
+        for (name, micros) in expected {
+            // Timings are serialized as non-negative integers (in microseconds).
+            assert_eq!(timings.get(name).and_then(|t| t.as_u64()), Some(micros));
+        }
+        assert!(timings.get("begin_call").unwrap().is_string());
+        assert!(timings.get("end_call").unwrap().is_string());
+    }
+
+    #[test]
+    fn request_to_json_has_body() {
+        let url = Url::from_str("http://localhost:8000/login").unwrap();
+        let body = r#"{"user":"bob","password":"s3cr3t"}"#;
+        let request = Request::new("POST", url.clone(), HeaderVec::new(), body.into());
+        let request = RequestJson::from_request(&request, &["s3cr3t"]);
+        let value = serde_json::to_value(request).unwrap();
+        assert_eq!(
+            value.get("body").unwrap(),
+            r#"{"user":"bob","password":"***"}"#
+        );
+
+        // An empty body is not serialized.
+        let request = Request::new("GET", url, HeaderVec::new(), vec![]);
+        let request = RequestJson::from_request(&request, &[]);
+        let value = serde_json::to_value(request).unwrap();
+        assert!(value.get("body").is_none());
+    }
+
+    #[test]
+    fn request_body_is_truncated() {
+        let body = "a".repeat(MAX_REQUEST_BODY_SIZE + 10);
+        let body = request_body(body.as_bytes(), &[]).unwrap();
+        assert_eq!(body.len(), MAX_REQUEST_BODY_SIZE);
+
+        // Truncation doesn't split a multibyte character.
+        let body = format!("{}é", "a".repeat(MAX_REQUEST_BODY_SIZE - 1));
+        let body = request_body(body.as_bytes(), &[]).unwrap();
+        assert_eq!(body.len(), MAX_REQUEST_BODY_SIZE - 1);
+    }
+}
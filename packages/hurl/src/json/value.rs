@@ -27,7 +27,7 @@ use crate::util::redacted::Redact;
 ///
 /// Natural JSON types are used to represent captures: if a [`Value::List`] is captured,
 /// the serialized data will be a JSON list.
-/// `secrets` are redacted from string values.
+/// `secrets` are redacted from string values (including object keys and regex patterns).
 impl Value {
     pub fn to_json(&self, secrets: &[&str]) -> serde_json::Value {
         match self {
@@ -42,7 +42,7 @@ impl Value {
             Value::Object(key_values) => {
                 let mut map = serde_json::Map::new();
                 for (key, value) in key_values {
-                    map.insert(key.to_string(), value.to_json(secrets));
+                    map.insert(key.redact(secrets), value.to_json(secrets));
                 }
                 serde_json::Value::Object(map)
             }
@@ -70,7 +70,7 @@ impl Value {
                 serde_json::Value::String(encoded)
             }
             Value::Null => serde_json::Value::Null,
-            Value::Regex(value) => serde_json::Value::String(value.to_string()),
+            Value::Regex(value) => serde_json::Value::String(value.as_str().redact(secrets)),
             Value::Unit => {
                 // Like nodeset, we don't have a "native" JSON representation for the unit type,
                 // we use a general fallback with `type` field
@@ -103,3 +103,64 @@ impl Number {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::runner::{Number, Value};
+
+    #[test]
+    fn to_json_keeps_natural_types() {
+        assert_eq!(Value::Bool(true).to_json(&[]), json!(true));
+        assert_eq!(Value::Number(Number::Integer(42)).to_json(&[]), json!(42));
+        assert_eq!(Value::Number(Number::Float(1.5)).to_json(&[]), json!(1.5));
+        assert_eq!(Value::Null.to_json(&[]), json!(null));
+        assert_eq!(
+            Value::List(vec![
+                Value::Number(Number::Integer(1)),
+                Value::String("two".to_string()),
+                Value::Null,
+            ])
+            .to_json(&[]),
+            json!([1, "two", null])
+        );
+        assert_eq!(
+            Value::Object(vec![(
+                "user".to_string(),
+                Value::Object(vec![("id".to_string(), Value::Number(Number::Integer(7)))])
+            )])
+            .to_json(&[]),
+            json!({"user": {"id": 7}})
+        );
+        assert_eq!(
+            Value::Nodeset(3).to_json(&[]),
+            json!({"type": "nodeset", "size": 3})
+        );
+    }
+
+    #[test]
+    fn to_json_redacts_secrets() {
+        let secrets = ["s3cr3t"];
+        assert_eq!(
+            Value::String("token=s3cr3t".to_string()).to_json(&secrets),
+            json!("token=***")
+        );
+        assert_eq!(
+            Value::List(vec![Value::String("s3cr3t".to_string())]).to_json(&secrets),
+            json!(["***"])
+        );
+        assert_eq!(
+            Value::Object(vec![(
+                "s3cr3t".to_string(),
+                Value::String("s3cr3t".to_string())
+            )])
+            .to_json(&secrets),
+            json!({"***": "***"})
+        );
+        assert_eq!(
+            Value::Regex(regex::Regex::new("s3cr3t-[0-9]+").unwrap()).to_json(&secrets),
+            json!("***-[0-9]+")
+        );
+    }
+}
@@ -37,12 +37,12 @@ pub enum Selector {
     RecursiveKey(String),
 }
 
-// For the time-being
-// use simple slice start:end (without the step)
+/// Array slice `start:end:step`, each part being optional and possibly negative.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Slice {
     pub start: Option<i64>,
     pub end: Option<i64>,
+    pub step: Option<i64>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -30,18 +30,21 @@ impl Query {
                     result = selector.eval(&value)?;
                 }
                 JsonpathResult::Collection(values) => {
+                    // Elements of a collection that are not matched by the selector are skipped:
+                    // the result is always a collection (possibly empty), keeping elements order.
                     let mut elements = vec![];
                     for value in values {
-                        match selector.eval(&value)? {
-                            JsonpathResult::SingleEntry(new_value) => {
+                        match selector.eval(&value) {
+                            Some(JsonpathResult::SingleEntry(new_value)) => {
                                 elements.push(new_value);
                             }
-                            JsonpathResult::Collection(mut new_values) => {
+                            Some(JsonpathResult::Collection(mut new_values)) => {
                                 elements.append(&mut new_values);
                             }
+                            None => {}
                         }
-                        result = JsonpathResult::Collection(elements.clone());
                     }
+                    result = JsonpathResult::Collection(elements);
                 }
             }
         }
@@ -239,4 +242,53 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    pub fn test_query_collection() {
+        // $.store.book[*].isbn
+        // Books without isbn are skipped, order is preserved.
+        let query = Query {
+            selectors: vec![
+                Selector::NameChild("store".to_string()),
+                Selector::NameChild("book".to_string()),
+                Selector::ArrayWildcard,
+                Selector::NameChild("isbn".to_string()),
+            ],
+        };
+        assert_eq!(
+            query.eval(&json_root()).unwrap(),
+            JsonpathResult::Collection(vec![json!("0-553-21311-3"), json!("0-395-19395-8")])
+        );
+
+        // $.store.book[*].unknown
+        let query = Query {
+            selectors: vec![
+                Selector::NameChild("store".to_string()),
+                Selector::NameChild("book".to_string()),
+                Selector::ArrayWildcard,
+                Selector::NameChild("unknown".to_string()),
+            ],
+        };
+        assert_eq!(
+            query.eval(&json_root()).unwrap(),
+            JsonpathResult::Collection(vec![])
+        );
+
+        // $.items[*].id
+        let query = Query {
+            selectors: vec![
+                Selector::NameChild("items".to_string()),
+                Selector::ArrayWildcard,
+                Selector::NameChild("id".to_string()),
+            ],
+        };
+        assert_eq!(
+            query.eval(&json!({ "items": [] })).unwrap(),
+            JsonpathResult::Collection(vec![])
+        );
+        assert_eq!(
+            query.eval(&json!({ "items": [{ "id": 1 }] })).unwrap(),
+            JsonpathResult::Collection(vec![json!(1)])
+        );
+    }
 }
@@ -44,23 +44,11 @@ impl Selector {
                 }
                 Some(JsonpathResult::Collection(elements))
             }
-            Selector::ArraySlice(Slice { start, end }) => {
+            Selector::ArraySlice(slice) => {
                 let mut elements = vec![];
                 if let serde_json::Value::Array(values) = root {
-                    for (i, value) in values.iter().enumerate() {
-                        if let Some(n) = start {
-                            let n = if *n < 0 { values.len() as i64 + n } else { *n };
-                            if (i as i64) < n {
-                                continue;
-                            }
-                        }
-                        if let Some(n) = end {
-                            let n = if *n < 0 { values.len() as i64 + n } else { *n };
-                            if (i as i64) >= n {
-                                continue;
-                            }
-                        }
-                        elements.push(value.clone());
+                    for i in slice.indices(values.len()) {
+                        elements.push(values[i].clone());
                     }
                 }
                 Some(JsonpathResult::Collection(elements))
@@ -204,6 +192,37 @@ fn extract_value(obj: serde_json::Value, key_path: Vec<String>) -> Option<serde_
     Some(value)
 }
 
+impl Slice {
+    /// Returns the indices selected by this slice in an array of `len` elements.
+    ///
+    /// Negative `start` and `end` are counted from the end of the array, and out of range bounds
+    /// are clamped to the array (see <https://www.rfc-editor.org/rfc/rfc9535#name-array-slice-selector>).
+    fn indices(&self, len: usize) -> Vec<usize> {
+        let len = len as i64;
+        let step = self.step.unwrap_or(1);
+        let normalize = |i: i64| if i >= 0 { i } else { len + i };
+        let mut indices = vec![];
+        if step > 0 {
+            let start = normalize(self.start.unwrap_or(0)).clamp(0, len);
+            let end = normalize(self.end.unwrap_or(len)).clamp(0, len);
+            let mut i = start;
+            while i < end {
+                indices.push(i as usize);
+                i += step;
+            }
+        } else if step < 0 {
+            let start = self.start.map_or(len - 1, normalize).clamp(-1, len - 1);
+            let end = self.end.map_or(-1, normalize).clamp(-1, len - 1);
+            let mut i = start;
+            while i > end {
+                indices.push(i as usize);
+                i += step;
+            }
+        }
+        indices
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -316,6 +335,7 @@ mod tests {
             Selector::ArraySlice(Slice {
                 start: None,
                 end: Some(2),
+                step: None,
             })
             .eval(&json_books())
             .unwrap(),
@@ -323,6 +343,32 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_slice_indices() {
+        let slice = |start, end, step| Slice { start, end, step };
+        assert_eq!(slice(None, None, None).indices(5), vec![0, 1, 2, 3, 4]);
+        assert_eq!(slice(None, None, Some(2)).indices(5), vec![0, 2, 4]);
+        assert_eq!(slice(Some(1), Some(4), Some(2)).indices(5), vec![1, 3]);
+        assert_eq!(slice(Some(-3), None, None).indices(5), vec![2, 3, 4]);
+        assert_eq!(slice(None, Some(-3), None).indices(5), vec![0, 1]);
+        assert_eq!(slice(None, None, Some(-1)).indices(5), vec![4, 3, 2, 1, 0]);
+        assert_eq!(slice(Some(3), Some(0), Some(-2)).indices(5), vec![3, 1]);
+        assert_eq!(
+            slice(Some(-1), Some(-4), Some(-1)).indices(5),
+            vec![4, 3, 2]
+        );
+
+        // Out of range slices are clamped.
+        assert_eq!(slice(Some(-10), Some(2), None).indices(5), vec![0, 1]);
+        assert_eq!(slice(Some(10), None, None).indices(5), Vec::<usize>::new());
+        assert_eq!(slice(Some(10), None, Some(-1)).indices(3), vec![2, 1, 0]);
+        assert_eq!(
+            slice(Some(2), Some(1), None).indices(5),
+            Vec::<usize>::new()
+        );
+        assert_eq!(slice(None, None, None).indices(0), Vec::<usize>::new());
+    }
+
     #[test]
     pub fn test_recursive_key() {
         assert_eq!(
@@ -130,8 +130,27 @@ fn selector_array_slice(reader: &mut Reader) -> Result<Selector, ParseError> {
         }
         Ok(v) => Some(v),
     };
+    let save = reader.cursor();
+    let step = if try_literal(":", reader).is_ok() {
+        let save = reader.cursor();
+        match integer(reader) {
+            Err(_) => {
+                reader.seek(save);
+                None
+            }
+            Ok(0) => {
+                let kind = ParseErrorKind::Expecting("non-zero step".to_string());
+                let error = ParseError::new(save.pos, false, kind);
+                return Err(error);
+            }
+            Ok(v) => Some(v),
+        }
+    } else {
+        reader.seek(save);
+        None
+    };
     literal("]", reader)?;
-    Ok(Selector::ArraySlice(Slice { start, end }))
+    Ok(Selector::ArraySlice(Slice { start, end, step }))
 }
 
 fn selector_filter(reader: &mut Reader) -> Result<Selector, ParseError> {
@@ -485,7 +504,8 @@ mod tests {
             selector(&mut reader).unwrap(),
             Selector::ArraySlice(Slice {
                 start: Some(1),
-                end: None
+                end: None,
+                step: None
             })
         );
         assert_eq!(reader.cursor().index, 4);
@@ -495,7 +515,8 @@ mod tests {
             selector(&mut reader).unwrap(),
             Selector::ArraySlice(Slice {
                 start: Some(-1),
-                end: None
+                end: None,
+                step: None
             })
         );
         assert_eq!(reader.cursor().index, 5);
@@ -505,10 +526,53 @@ mod tests {
             selector(&mut reader).unwrap(),
             Selector::ArraySlice(Slice {
                 start: None,
-                end: Some(2)
+                end: Some(2),
+                step: None
             })
         );
         assert_eq!(reader.cursor().index, 4);
+
+        let mut reader = Reader::new("[::2]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: None,
+                end: None,
+                step: Some(2)
+            })
+        );
+        assert_eq!(reader.cursor().index, 5);
+
+        let mut reader = Reader::new("[5:1:-2]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: Some(5),
+                end: Some(1),
+                step: Some(-2)
+            })
+        );
+        assert_eq!(reader.cursor().index, 8);
+
+        let mut reader = Reader::new("[1:3:]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: Some(1),
+                end: Some(3),
+                step: None
+            })
+        );
+        assert_eq!(reader.cursor().index, 6);
+
+        let mut reader = Reader::new("[::0]");
+        let error = selector(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 4));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting("non-zero step".to_string())
+        );
+        assert!(!error.recoverable);
     }
 
     #[test]
@@ -239,6 +239,18 @@ fn test_bookstore_path() {
         JsonpathResult::Collection(vec![book0_value(), book1_value()])
     );
 
+    // every other book, and the books in reverse order
+    let expr = jsonpath::parse("$..book[::2]").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![book0_value(), book2_value()])
+    );
+    let expr = jsonpath::parse("$..book[-3::-1]").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![book1_value(), book0_value()])
+    );
+
     // filter all books with isbn number
     let expr = jsonpath::parse("$..book[?(@.isbn)]").unwrap();
     assert_eq!(
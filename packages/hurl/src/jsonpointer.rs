@@ -0,0 +1,185 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! JSON Pointer, as specified by [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901).
+//!
+//! A JSON Pointer is a string of zero or more reference tokens, each prefixed by `/`:
+//!
+//! - the empty pointer `""` references the whole document,
+//! - `/book/0/author` references the `author` key of the first element of the `book` array.
+//!
+//! In a reference token, `~` is escaped with `~0` and `/` is escaped with `~1`. Any other
+//! character following a `~` makes the pointer invalid.
+
+use serde_json::Value;
+
+/// A parsed JSON Pointer, ready to be evaluated against a JSON value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonPointer {
+    tokens: Vec<String>,
+}
+
+/// Parses a JSON Pointer string `s`.
+///
+/// Returns `None` if `s` is not a valid JSON Pointer.
+pub fn parse(s: &str) -> Option<JsonPointer> {
+    if s.is_empty() {
+        return Some(JsonPointer { tokens: vec![] });
+    }
+    let s = s.strip_prefix('/')?;
+    let tokens = s.split('/').map(unescape).collect::<Option<Vec<_>>>()?;
+    Some(JsonPointer { tokens })
+}
+
+/// Unescapes a reference `token`, replacing `~1` by `/` and `~0` by `~`.
+fn unescape(token: &str) -> Option<String> {
+    let mut value = String::new();
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        if c == '~' {
+            match chars.next() {
+                Some('0') => value.push('~'),
+                Some('1') => value.push('/'),
+                _ => return None,
+            }
+        } else {
+            value.push(c);
+        }
+    }
+    Some(value)
+}
+
+impl JsonPointer {
+    /// Evaluates this pointer against a JSON `value`, returning the referenced value or `None` if
+    /// there is no such value.
+    pub fn eval<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.tokens
+            .iter()
+            .try_fold(value, |value, token| match value {
+                Value::Object(map) => map.get(token),
+                Value::Array(values) => array_index(token).and_then(|i| values.get(i)),
+                _ => None,
+            })
+    }
+}
+
+/// Returns the array index referenced by `token`.
+///
+/// An array index is a sequence of digits, without leading zeros.
+fn array_index(token: &str) -> Option<usize> {
+    if token.is_empty()
+        || !token.chars().all(|c| c.is_ascii_digit())
+        || (token.len() > 1 && token.starts_with('0'))
+    {
+        return None;
+    }
+    token.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn bookstore_value() -> Value {
+        let s = read_to_string("tests/bookstore.json").expect("could not read string from file");
+        serde_json::from_str(s.as_str()).expect("could not parse json file")
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("").unwrap().tokens, Vec::<String>::new());
+        assert_eq!(parse("/").unwrap().tokens, vec![""]);
+        assert_eq!(
+            parse("/store/book/0/author").unwrap().tokens,
+            vec!["store", "book", "0", "author"]
+        );
+        assert_eq!(parse("/a~1b/m~0n").unwrap().tokens, vec!["a/b", "m~n"]);
+        assert_eq!(parse("/~01").unwrap().tokens, vec!["~1"]);
+
+        assert!(parse("store").is_none());
+        assert!(parse("/a~2b").is_none());
+        assert!(parse("/a~").is_none());
+    }
+
+    #[test]
+    fn test_eval_store() {
+        let value = bookstore_value();
+
+        let pointer = parse("/store/book/0/author").unwrap();
+        assert_eq!(pointer.eval(&value), Some(&json!("Nigel Rees")));
+
+        let pointer = parse("/store/book/2/isbn").unwrap();
+        assert_eq!(pointer.eval(&value), Some(&json!("0-553-21311-3")));
+
+        let pointer = parse("/store/bicycle/price").unwrap();
+        assert_eq!(pointer.eval(&value), Some(&json!(19.95)));
+
+        let pointer = parse("/store/bicycle").unwrap();
+        assert_eq!(
+            pointer.eval(&value),
+            Some(&json!({"color": "red", "price": 19.95}))
+        );
+
+        let pointer = parse("").unwrap();
+        assert_eq!(pointer.eval(&value), Some(&value));
+
+        // Missing values
+        assert_eq!(parse("/store/book/0/isbn").unwrap().eval(&value), None);
+        assert_eq!(parse("/store/book/10").unwrap().eval(&value), None);
+        assert_eq!(parse("/store/book/-").unwrap().eval(&value), None);
+        assert_eq!(parse("/store/book/01").unwrap().eval(&value), None);
+        assert_eq!(parse("/store/book/author").unwrap().eval(&value), None);
+        assert_eq!(parse("/store/bicycle/color/0").unwrap().eval(&value), None);
+    }
+
+    #[test]
+    fn test_eval_escaped_tokens() {
+        // Examples from <https://datatracker.ietf.org/doc/html/rfc6901#section-5>
+        let value = json!({
+            "foo": ["bar", "baz"],
+            "": 0,
+            "a/b": 1,
+            "c%d": 2,
+            "e^f": 3,
+            "g|h": 4,
+            "i\\j": 5,
+            "k\"l": 6,
+            " ": 7,
+            "m~n": 8
+        });
+        assert_eq!(
+            parse("/foo").unwrap().eval(&value),
+            Some(&json!(["bar", "baz"]))
+        );
+        assert_eq!(parse("/foo/0").unwrap().eval(&value), Some(&json!("bar")));
+        assert_eq!(parse("/").unwrap().eval(&value), Some(&json!(0)));
+        assert_eq!(parse("/a~1b").unwrap().eval(&value), Some(&json!(1)));
+        assert_eq!(parse("/c%d").unwrap().eval(&value), Some(&json!(2)));
+        assert_eq!(parse("/e^f").unwrap().eval(&value), Some(&json!(3)));
+        assert_eq!(parse("/g|h").unwrap().eval(&value), Some(&json!(4)));
+        assert_eq!(parse("/i\\j").unwrap().eval(&value), Some(&json!(5)));
+        assert_eq!(parse("/k\"l").unwrap().eval(&value), Some(&json!(6)));
+        assert_eq!(parse("/ ").unwrap().eval(&value), Some(&json!(7)));
+        assert_eq!(parse("/m~0n").unwrap().eval(&value), Some(&json!(8)));
+        assert_eq!(parse("/a/b").unwrap().eval(&value), None);
+    }
+}
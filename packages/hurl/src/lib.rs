@@ -42,6 +42,7 @@ mod html;
 pub mod http;
 mod json;
 mod jsonpath;
+mod jsonpointer;
 pub mod output;
 #[doc(hidden)]
 pub mod parallel;
@@ -24,14 +24,14 @@ use std::path::Path;
 use std::time::Instant;
 use std::{env, process, thread};
 
-use hurl::report::{curl, html, json, junit, tap};
+use hurl::report::{cookie, curl, html, json, junit, tap};
 use hurl::runner;
 use hurl::runner::HurlResult;
 use hurl::util::redacted::Redact;
 use hurl_core::input::Input;
 use hurl_core::text;
 
-use crate::cli::options::{CliOptions, CliOptionsError};
+use crate::cli::options::{CliOptions, CliOptionsError, CookieOutputFormat};
 use crate::cli::{BaseLogger, CliError};
 
 const EXIT_OK: i32 = 0;
@@ -73,6 +73,15 @@ fn main() {
     // We'll use a more advanced logger for rich error report when running Hurl files.
     let verbose = opts.verbose || opts.very_verbose || opts.interactive;
     let base_logger = BaseLogger::new(opts.color, verbose);
+    if opts.insecure {
+        base_logger.warning("--insecure is enabled: SSL certificates are not verified");
+    }
+    if !opts.insecure_hosts.is_empty() {
+        let hosts = opts.insecure_hosts.join(", ");
+        base_logger.warning(&format!(
+            "--insecure-host is enabled: SSL certificates of {hosts} are not verified"
+        ));
+    }
     let current_dir = env::current_dir();
     let current_dir = unwrap_or_exit(current_dir, EXIT_ERROR_UNDEFINED, &base_logger);
     let current_dir = current_dir.as_path();
@@ -103,6 +112,15 @@ fn main() {
     // Compute duration of the test here to not take reports writings into account.
     let duration = start.elapsed();
 
+    // Requests can also be made insecure by an `insecure` entry option, that we only know of
+    // after the run.
+    let insecure_requests = cli::insecure_requests_count(&runs);
+    if !opts.insecure && opts.insecure_hosts.is_empty() && insecure_requests > 0 {
+        base_logger.warning(&format!(
+            "{insecure_requests} request(s) executed without verifying SSL certificates"
+        ));
+    }
+
     // Write HTML, JUnit, TAP reports on disk.
     if has_report(&opts) {
         let ret = export_results(&runs, &opts, &base_logger);
@@ -114,6 +132,11 @@ fn main() {
         base_logger.info(summary.as_str());
     }
 
+    if opts.benchmark {
+        let summary = cli::benchmark_summary(&runs);
+        base_logger.info(summary.as_str());
+    }
+
     process::exit(exit_code(&runs));
 }
 
@@ -167,12 +190,12 @@ fn export_results(
     }
     if let Some(file) = &opts.junit_file {
         logger.debug(&format!("Writing JUnit report to {}", file.display()));
-        create_junit_report(runs, file, &secrets)?;
+        create_junit_report(runs, file, opts.junit_classname.as_deref(), &secrets)?;
     }
     if let Some(file) = &opts.tap_file {
         // TAP files doesn't need to be redacted, they don't expose any logs apart from files names.
         logger.debug(&format!("Writing TAP report to {}", file.display()));
-        create_tap_report(runs, file)?;
+        create_tap_report(runs, file, opts.tap_granularity.into(), opts.tap_verbose)?;
     }
     if let Some(dir) = &opts.html_dir {
         logger.debug(&format!("Writing HTML report to {}", dir.display()));
@@ -184,7 +207,10 @@ fn export_results(
     }
     if let Some(file) = &opts.cookie_output_file {
         logger.debug(&format!("Writing cookies to {}", file.display()));
-        create_cookies_file(runs, file, &secrets)?;
+        match opts.cookie_output_format {
+            CookieOutputFormat::Netscape => create_cookies_file(runs, file, &secrets)?,
+            CookieOutputFormat::Json => create_cookies_json_file(runs, file, &secrets)?,
+        }
     }
     Ok(())
 }
@@ -197,24 +223,37 @@ fn create_curl_export(runs: &[HurlRun], filename: &Path, secrets: &[&str]) -> Re
 }
 
 /// Creates a JUnit report for this run.
+///
+/// If defined, `classname` is a template used to compute the `classname` attribute of each testcase.
 fn create_junit_report(
     runs: &[HurlRun],
     filename: &Path,
+    classname: Option<&str>,
     secrets: &[&str],
 ) -> Result<(), CliError> {
     let testcases = runs
         .iter()
-        .map(|r| junit::Testcase::from(&r.hurl_result, &r.content, &r.filename))
+        .map(|r| junit::Testcase::from(&r.hurl_result, &r.content, &r.filename, classname))
         .collect::<Vec<_>>();
     junit::write_report(filename, &testcases, secrets)?;
     Ok(())
 }
 
-/// Creates a TAP report for this run.
-fn create_tap_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
+/// Creates a TAP report for this run, with one testpoint per file, entry or assert depending on
+/// `granularity`.
+///
+/// If `verbose` is true, each testpoint is followed by a YAML diagnostic block.
+fn create_tap_report(
+    runs: &[HurlRun],
+    filename: &Path,
+    granularity: tap::Granularity,
+    verbose: bool,
+) -> Result<(), CliError> {
     let testcases = runs
         .iter()
-        .map(|r| tap::Testcase::from(&r.hurl_result, &r.filename))
+        .flat_map(|r| {
+            tap::Testcase::from_granularity(&r.hurl_result, &r.filename, granularity, verbose)
+        })
         .collect::<Vec<_>>();
     tap::write_report(filename, &testcases)?;
     Ok(())
@@ -250,6 +289,9 @@ fn create_json_report(runs: &[HurlRun], dir_path: &Path, secrets: &[&str]) -> Re
 
     let index_path = dir_path.join("report.json");
     json::write_report(&index_path, &testcases, &store_path, secrets)?;
+
+    let summary_path = dir_path.join("summary.json");
+    json::write_summary(&summary_path, &testcases)?;
     Ok(())
 }
 
@@ -260,6 +302,10 @@ fn exit_code(runs: &[HurlRun]) -> i32 {
     for run in runs.iter() {
         let errors = run.hurl_result.errors();
         if errors.is_empty() {
+            // A file can fail without any error (see `--fail-if-empty`).
+            if !run.hurl_result.success {
+                count_errors_runner += 1;
+            }
         } else if errors.iter().filter(|(error, _)| !error.assert).count() == 0 {
             count_errors_assert += 1;
         } else {
@@ -275,6 +321,21 @@ fn exit_code(runs: &[HurlRun]) -> i32 {
     }
 }
 
+/// Export cookies for this run to `filename` file, as a JSON array.
+fn create_cookies_json_file(
+    runs: &[HurlRun],
+    filename: &Path,
+    secrets: &[&str],
+) -> Result<(), CliError> {
+    // In a sequential run, the cookie storage is shared between files, so the last run holds the
+    // cookies of the whole session.
+    let Some(run) = runs.last() else {
+        return Err(CliError::IO("Issue fetching results".to_string()));
+    };
+    cookie::write_json(&run.hurl_result.cookies, filename, secrets)?;
+    Ok(())
+}
+
 /// Export cookies for this run to `filename` file.
 ///
 /// The file format for the cookies is [Netscape cookie format](http://www.cookiecentral.com/faq/#3.5).
@@ -298,9 +359,9 @@ fn create_cookies_file(
 "#
     .to_string();
 
-    // TODO: We only serialize the first file. I don't see any valid reason why we're not
-    // serializing every file see issue <https://github.com/Orange-OpenSource/hurl/issues/2537>
-    match runs.first() {
+    // In a sequential run, the cookie storage is shared between files, so the last run holds the
+    // cookies of the whole session.
+    match runs.last() {
         None => {
             return Err(CliError::IO("Issue fetching results".to_string()));
         }
@@ -33,6 +33,8 @@ pub enum OutputErrorKind {
     Http(HttpError),
     Binary,
     Io(String),
+    /// The `--output` filename template can not be rendered.
+    Template(String),
 }
 
 impl OutputError {
@@ -52,6 +54,7 @@ impl DisplaySourceError for OutputError {
             OutputErrorKind::Http(http_error) => http_error.description(),
             OutputErrorKind::Binary => "Binary Error".to_string(),
             OutputErrorKind::Io(_) => "IO Error".to_string(),
+            OutputErrorKind::Template(_) => "Invalid output filename".to_string(),
         }
     }
 
@@ -72,6 +75,10 @@ impl DisplaySourceError for OutputError {
                 let message = hurl_core::error::add_carets(message, self.source_info, content);
                 color_red(&message)
             }
+            OutputErrorKind::Template(message) => {
+                let message = hurl_core::error::add_carets(message, self.source_info, content);
+                color_red(&message)
+            }
         }
     }
 }
@@ -23,10 +23,13 @@
 //!   [`self::json::write_json`]
 //! - raw: the last response of a run is serialized to a file. The body can be automatically uncompress
 //!   or written as it [`self::raw::write_last_body`]
+//! - raw per entry: the last response of each entry is serialized to a file in a directory
+//!   [`self::raw::write_bodies`]
+//! - raw request: the last request of a run is serialized to a file [`self::raw::write_last_request`]
 mod error;
 mod json;
 mod raw;
 
 pub use self::error::OutputError;
 pub use self::json::write_json;
-pub use self::raw::write_last_body;
+pub use self::raw::{write_bodies, write_last_body, write_last_request};
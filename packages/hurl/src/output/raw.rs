@@ -16,11 +16,14 @@
  *
  */
 use std::cmp::min;
+use std::fs;
 use std::io::IsTerminal;
+use std::path::Path;
 
+use crate::http::Response;
 use crate::output::error::OutputErrorKind;
 use crate::output::OutputError;
-use crate::runner::{HurlResult, Output};
+use crate::runner::{EntryResult, HurlResult, Output};
 use crate::util::term::Stdout;
 
 /// Writes the `hurl_result` last response to the file `filename_out`.
@@ -53,20 +56,8 @@ pub fn write_last_body(
         text.push('\n');
         output.append(&mut text.into_bytes());
     }
-    if last_entry.compressed {
-        let mut bytes = match response.uncompress_body() {
-            Ok(b) => b,
-            Err(e) => {
-                let source_info = last_entry.source_info;
-                let kind = OutputErrorKind::Http(e);
-                return Err(OutputError::new(source_info, kind));
-            }
-        };
-        output.append(&mut bytes);
-    } else {
-        let bytes = &response.body;
-        output.extend(bytes);
-    }
+    let mut bytes = body(last_entry, response)?;
+    output.append(&mut bytes);
     // We replicate curl's checks for binary output: a warning is displayed when user hasn't
     // used `--output` option and the response is considered as a binary content. If user has used
     // `--output` whether to save to a file, or to redirect output to standard output (`--output -`)
@@ -84,20 +75,95 @@ pub fn write_last_body(
                 OutputError::new(source_info, kind)
             })?;
         }
-        Some(out) => out.write(&output, stdout, append).map_err(|e| {
-            let filename = if let Output::File(filename) = out {
-                filename.display().to_string()
-            } else {
-                "stdout".to_string()
-            };
-            let source_info = last_entry.source_info;
-            let kind = OutputErrorKind::Io(format!("{filename} can not be written ({})", e));
+        Some(out) => {
+            // The output filename can reference variables captured during the run.
+            let out = out.render(&hurl_result.variables).map_err(|message| {
+                let source_info = last_entry.source_info;
+                let kind = OutputErrorKind::Template(message);
+                OutputError::new(source_info, kind)
+            })?;
+            out.write(&output, stdout, append).map_err(|e| {
+                let filename = if let Output::File(filename) = &out {
+                    filename.display().to_string()
+                } else {
+                    "stdout".to_string()
+                };
+                let source_info = last_entry.source_info;
+                let kind = OutputErrorKind::Io(format!("{filename} can not be written ({})", e));
+                OutputError::new(source_info, kind)
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the last response body of each entry of `hurl_result` to the directory `dir`.
+///
+/// Bodies are written to files named after the entries index (`1.bin`, `2.bin` etc...), or after
+/// the file name of the `output` option of the entry `[Options]` section, if any. A retried entry
+/// is written with its last run. The directory `dir` is created if it doesn't exist.
+pub fn write_bodies(hurl_result: &HurlResult, dir: &Path) -> Result<(), OutputError> {
+    let Some(first_entry) = hurl_result.entries.first() else {
+        return Ok(());
+    };
+    fs::create_dir_all(dir).map_err(|e| {
+        let source_info = first_entry.source_info;
+        let kind = OutputErrorKind::Io(format!("{} can not be created ({e})", dir.display()));
+        OutputError::new(source_info, kind)
+    })?;
+    for entry in &hurl_result.entries {
+        let Some(call) = entry.calls.last() else {
+            continue;
+        };
+        let bytes = body(entry, &call.response)?;
+        let filename = match &entry.output {
+            Some(Output::File(path)) if path.file_name().is_some() => {
+                dir.join(path.file_name().unwrap())
+            }
+            _ => dir.join(format!("{}.bin", entry.entry_index)),
+        };
+        fs::write(&filename, bytes).map_err(|e| {
+            let source_info = entry.source_info;
+            let kind =
+                OutputErrorKind::Io(format!("{} can not be written ({e})", filename.display()));
             OutputError::new(source_info, kind)
-        })?,
+        })?;
     }
     Ok(())
 }
 
+/// Writes the last HTTP request of `hurl_result` to the file `filename`.
+///
+/// The request is serialized with its method, URL, headers and body (see
+/// [`crate::http::Request::to_bytes`]). Nothing is written if no request has been sent.
+pub fn write_last_request(hurl_result: &HurlResult, filename: &Path) -> Result<(), OutputError> {
+    let Some(last_entry) = hurl_result.entries.last() else {
+        return Ok(());
+    };
+    let Some(call) = last_entry.calls.last() else {
+        return Ok(());
+    };
+    fs::write(filename, call.request.to_bytes()).map_err(|e| {
+        let source_info = last_entry.source_info;
+        let kind = OutputErrorKind::Io(format!("{} can not be written ({e})", filename.display()));
+        OutputError::new(source_info, kind)
+    })
+}
+
+/// Returns the body of this `response`, uncompressed if the `entry` has been run with
+/// `compressed` option.
+fn body(entry: &EntryResult, response: &Response) -> Result<Vec<u8>, OutputError> {
+    if entry.compressed {
+        response.uncompress_body().map_err(|e| {
+            let source_info = entry.source_info;
+            let kind = OutputErrorKind::Http(e);
+            OutputError::new(source_info, kind)
+        })
+    } else {
+        Ok(response.body.clone())
+    }
+}
+
 /// Returns `true` if `bytes` is a binary content, false otherwise.
 ///
 /// For the implementation, we use a simple heuristic on the buffer: just check the presence of NULL
@@ -133,10 +199,12 @@ mod tests {
             version: HttpVersion::Http10,
             status: 200,
             headers: HeaderVec::new(),
+            trailers: HeaderVec::new(),
             body: vec![],
             duration: Default::default(),
             url: Url::from_str("http://localhost").unwrap(),
             certificate: None,
+            charset: None,
         }
     }
 
@@ -194,10 +262,12 @@ mod tests {
                             version: HttpVersion::Http3,
                             status: 204,
                             headers,
+                            trailers: HeaderVec::new(),
                             body: b"{\"say\": \"Hello World!\"}".into(),
                             duration: Default::default(),
                             url: Url::from_str("https://baz.com").unwrap(),
                             certificate: None,
+                            charset: None,
                         },
                         timings: Default::default(),
                     }],
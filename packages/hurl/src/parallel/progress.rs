@@ -18,6 +18,7 @@
 use std::time::{Duration, Instant};
 
 use hurl_core::text::{Format, Style, StyledString};
+use serde::Serialize;
 
 use crate::parallel::job::JobResult;
 use crate::parallel::runner::WorkerState;
@@ -46,6 +47,18 @@ pub enum Mode {
     TestWithProgress,
     /// Run with --test and no progress bar
     TestWithoutProgress,
+    /// Run with --progress-format json: one JSON event per completed job, and no progress bar
+    Json,
+}
+
+/// Format of the progress reporting.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ProgressFormat {
+    /// Human-readable progress bar and completion messages
+    #[default]
+    Human,
+    /// One JSON line per completed job, suitable for machine consumption
+    Json,
 }
 
 /// The minimum duration between two progress bar redraw (to avoid flickering).
@@ -110,8 +123,13 @@ impl ParProgress {
 
     /// Displays the completion of a job `result`.
     pub fn print_completed(&mut self, result: &JobResult, stderr: &mut Stderr) {
-        if matches!(self.mode, Mode::Default) {
-            return;
+        match self.mode {
+            Mode::Default => return,
+            Mode::Json => {
+                stderr.eprintln(&completed_event(result));
+                return;
+            }
+            Mode::TestWithProgress | Mode::TestWithoutProgress => {}
         }
         let count = result
             .hurl_result
@@ -149,7 +167,10 @@ impl ParProgress {
 }
 
 impl Mode {
-    pub fn new(test: bool, progress_bar: bool) -> Self {
+    pub fn new(test: bool, progress_bar: bool, format: ProgressFormat) -> Self {
+        if format == ProgressFormat::Json {
+            return Mode::Json;
+        }
         match (test, progress_bar) {
             (true, true) => Mode::TestWithProgress,
             (true, false) => Mode::TestWithoutProgress,
@@ -158,6 +179,29 @@ impl Mode {
     }
 }
 
+/// A JSON event emitted on standard error when a job is completed (see `--progress-format`).
+#[derive(Serialize)]
+struct CompletedEvent {
+    file: String,
+    status: &'static str,
+    duration_ms: u64,
+}
+
+/// Returns the JSON event (on a single line) of a completed job `result`.
+fn completed_event(result: &JobResult) -> String {
+    let status = if result.hurl_result.success {
+        "success"
+    } else {
+        "failure"
+    };
+    let event = CompletedEvent {
+        file: result.job.filename.to_string(),
+        status,
+        duration_ms: result.hurl_result.duration.as_millis() as u64,
+    };
+    serde_json::to_string(&event).unwrap()
+}
+
 /// Records the instant when a progress bar is refreshed on the terminal.
 /// We don't want to update the progress bar too often as it can cause excessive performance loss
 /// just putting stuff onto the terminal. We also want to avoid flickering by not drawing anything
@@ -322,15 +366,16 @@ fn progress_bar(index: usize, count: usize) -> String {
 #[cfg(test)]
 mod tests {
     use std::sync::{mpsc, Arc, Mutex};
+    use std::time::Duration;
 
     use hurl_core::input::Input;
     use hurl_core::text::Format;
 
-    use crate::parallel::job::Job;
-    use crate::parallel::progress::{build_progress, progress_bar};
+    use crate::parallel::job::{Job, JobResult};
+    use crate::parallel::progress::{build_progress, completed_event, progress_bar};
     use crate::parallel::runner::WorkerState;
     use crate::parallel::worker::{Worker, WorkerId};
-    use crate::runner::{RunnerOptionsBuilder, VariableSet};
+    use crate::runner::{HurlResult, RunnerOptionsBuilder, VariableSet};
     use crate::util::logger::LoggerOptionsBuilder;
 
     fn new_workers() -> (Worker, Worker, Worker, Worker, Worker) {
@@ -543,4 +588,30 @@ Executed files: 75/100 (75%)\n\
         // Progress strings with 1 entry:
         assert_eq!(progress_bar(1, 1), "[>                       ] 1/1");
     }
+
+    #[test]
+    fn test_completed_event() {
+        let jobs = new_jobs();
+        let hurl_result = HurlResult {
+            duration: Duration::from_millis(42),
+            success: true,
+            ..Default::default()
+        };
+        let result = JobResult::new(jobs[0].clone(), String::new(), hurl_result);
+        assert_eq!(
+            completed_event(&result),
+            r#"{"file":"a.hurl","status":"success","duration_ms":42}"#
+        );
+
+        let hurl_result = HurlResult {
+            duration: Duration::from_millis(7),
+            success: false,
+            ..Default::default()
+        };
+        let result = JobResult::new(jobs[1].clone(), String::new(), hurl_result);
+        assert_eq!(
+            completed_event(&result),
+            r#"{"file":"b.hurl","status":"failure","duration_ms":7}"#
+        );
+    }
 }
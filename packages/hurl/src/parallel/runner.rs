@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+use std::path::PathBuf;
 use std::sync::mpsc::{Receiver, Sender};
 use std::sync::{mpsc, Arc, Mutex};
 
@@ -27,8 +28,11 @@ use crate::parallel::job::{Job, JobQueue, JobResult};
 use crate::parallel::message::WorkerMessage;
 use crate::parallel::progress::{Mode, ParProgress};
 use crate::parallel::worker::{Worker, WorkerId};
+use crate::report::json;
 use crate::util::term::{Stderr, Stdout, WriteMode};
 
+pub use crate::parallel::progress::ProgressFormat;
+
 /// A parallel runner manages a list of `Worker`. Each worker is either idle or is running a
 /// [`Job`]. To run jobs, the [`ParallelRunner::run`] method much be executed on the main thread.
 /// Each worker has its own thread that it uses to run a Hurl file, and communicates with the main
@@ -55,6 +59,12 @@ pub struct ParallelRunner {
     output_type: OutputType,
     /// Repeat mode for the runner: infinite or finite.
     repeat: Count,
+    /// If true, no new job is started after the first failed job.
+    fail_fast: bool,
+    /// Optional line-delimited JSON report, to which each completed job is appended.
+    ndjson_report: Option<PathBuf>,
+    /// If true, the output file is appended instead of being truncated on first write.
+    output_append: bool,
 }
 
 /// Represents a worker's state.
@@ -104,15 +114,32 @@ impl ParallelRunner {
     /// parallel run progression can be used. When the progress bar is displayed, it's wrapped with
     /// new lines at width `max_width`.
     ///
+    /// If `progress_format` is [`ProgressFormat::Json`], there is no progress bar and the completion
+    /// of each job is reported as a single JSON line on standard error, whether in test mode or not.
+    ///
     /// `color` determines if color if used in standard error.
+    ///
+    /// If `fail_fast` is `true`, the runner stops taking new jobs after the first failed job: the
+    /// jobs being run by the workers are completed, and the remaining jobs are cancelled.
+    ///
+    /// If `ndjson_report` is defined, each completed job is appended to this line-delimited JSON
+    /// report file.
+    ///
+    /// If `output_append` is `true`, an existing output file is appended instead of being truncated
+    /// on the first write.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         workers_count: usize,
         output_type: OutputType,
         repeat: Count,
         test: bool,
         progress_bar: bool,
+        progress_format: ProgressFormat,
         color: bool,
         max_width: Option<usize>,
+        fail_fast: bool,
+        ndjson_report: Option<PathBuf>,
+        output_append: bool,
     ) -> Self {
         // Worker are running on theirs own thread, while parallel runner is running in the main
         // thread.
@@ -131,7 +158,7 @@ impl ParallelRunner {
             })
             .collect::<Vec<_>>();
 
-        let mode = Mode::new(test, progress_bar);
+        let mode = Mode::new(test, progress_bar, progress_format);
         let progress = ParProgress::new(MAX_RUNNING_DISPLAYED, mode, color, max_width);
 
         ParallelRunner {
@@ -141,6 +168,9 @@ impl ParallelRunner {
             progress,
             output_type,
             repeat,
+            fail_fast,
+            ndjson_report,
+            output_append,
         }
     }
 
@@ -161,15 +191,21 @@ impl ParallelRunner {
         let jobs_count = queue.jobs_count();
 
         // Initiate the runner, fill our workers:
+        let mut running = 0;
         self.workers.iter().for_each(|_| {
             if let Some(job) = queue.next() {
                 _ = self.tx.as_ref().unwrap().send(job);
+                running += 1;
             }
         });
 
-        // When dumped HTTP responses, we truncate existing output file on first save, then append
-        // it on subsequent write.
-        let mut append = false;
+        // In fail fast mode, set to true when a job has failed: we stop taking new jobs and wait
+        // for the running jobs to complete.
+        let mut cancelled = false;
+
+        // When dumped HTTP responses, we truncate existing output file on first save (unless
+        // `output_append` is set), then append it on subsequent write.
+        let mut append = self.output_append;
 
         // Start the message pump:
         let mut results = vec![];
@@ -222,6 +258,7 @@ impl ParallelRunner {
 
                     // The worker is becoming idle.
                     self.workers[msg.worker_id.0].1 = WorkerState::Idle;
+                    running -= 1;
 
                     // First, we display the job standard error, then the job standard output
                     // (similar to the sequential runner).
@@ -240,9 +277,16 @@ impl ParallelRunner {
                     self.print_output(&msg.result, &mut stdout, append)?;
                     append = true;
 
+                    // The job is appended to the line-delimited JSON report as soon as it's
+                    // completed.
+                    self.write_ndjson_report(&msg.result)?;
+
                     // Report the completion of this job and update the progress.
                     self.progress.print_completed(&msg.result, &mut stderr);
 
+                    if self.fail_fast && !msg.result.hurl_result.success {
+                        cancelled = true;
+                    }
                     results.push(msg.result);
 
                     self.progress.update_progress_bar(
@@ -257,11 +301,21 @@ impl ParallelRunner {
                     // running messages received leads to a progress bar refresh.
                     self.progress.force_next_update();
 
+                    // In fail fast mode, after a failure, we drain the running jobs without spawning
+                    // new ones.
+                    if cancelled {
+                        if running == 0 {
+                            break;
+                        }
+                        continue;
+                    }
+
                     // We run the next job to process:
                     let job = queue.next();
                     match job {
                         Some(job) => {
                             _ = self.tx.as_ref().unwrap().send(job);
+                            running += 1;
                         }
                         None => {
                             // If we have received all the job results, we can stop the run.
@@ -347,4 +401,17 @@ impl ParallelRunner {
         }
         Ok(())
     }
+
+    /// Appends a job `result` to the line-delimited JSON report, if any.
+    fn write_ndjson_report(&self, result: &JobResult) -> Result<(), JobError> {
+        let Some(filename) = &self.ndjson_report else {
+            return Ok(());
+        };
+        let secrets = result.hurl_result.variables.secrets();
+        let secrets = secrets.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+        let testcase =
+            json::Testcase::new(&result.hurl_result, &result.content, &result.job.filename);
+        json::append_ndjson_report(filename, &testcase, &secrets)
+            .map_err(|e| JobError::IO(e.to_string()))
+    }
 }
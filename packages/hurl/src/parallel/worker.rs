@@ -87,6 +87,9 @@ impl Worker {
             // mutated on each entry).
             let secrets = job.variables.secrets();
             let mut logger = Logger::new(&job.logger_options, stderr, &secrets);
+            // Each log line is tagged with the worker and the file, to help debugging parallel runs.
+            let correlation_id = format!("worker={worker_id} file={}", job.filename);
+            logger.set_correlation_id(&correlation_id);
 
             // Create a worker progress listener.
             let progress = WorkerProgress::new(worker_id, &job, &tx);
@@ -0,0 +1,137 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! JSON export of cookies.
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::http::Cookie;
+use crate::report::ReportError;
+use crate::util::redacted::Redact;
+
+#[derive(Serialize)]
+struct CookieJson {
+    name: String,
+    value: String,
+    domain: String,
+    path: String,
+    /// Expiration date as a UNIX timestamp, `0` for a session cookie.
+    expires: i64,
+    include_subdomain: bool,
+    secure: bool,
+    http_only: bool,
+}
+
+impl CookieJson {
+    fn new(cookie: &Cookie, secrets: &[&str]) -> Self {
+        CookieJson {
+            name: cookie.name.clone(),
+            value: cookie.value.redact(secrets),
+            domain: cookie.domain.clone(),
+            path: cookie.path.clone(),
+            expires: cookie.expires.parse().unwrap_or(0),
+            include_subdomain: cookie.include_subdomain == "TRUE",
+            secure: cookie.https == "TRUE",
+            http_only: cookie.http_only,
+        }
+    }
+}
+
+/// Writes `cookies` to `filename` as a JSON array.
+///
+/// `secrets` strings are redacted from the cookies values.
+pub fn write_json(
+    cookies: &[Cookie],
+    filename: &Path,
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    let json = to_json(cookies, secrets);
+    let mut file = File::create(filename)
+        .map_err(|e| ReportError::from_error(e, filename, "Issue writing cookies"))?;
+    file.write_all(json.as_bytes())
+        .map_err(|e| ReportError::from_error(e, filename, "Issue writing cookies"))?;
+    Ok(())
+}
+
+fn to_json(cookies: &[Cookie], secrets: &[&str]) -> String {
+    let cookies = cookies
+        .iter()
+        .map(|c| CookieJson::new(c, secrets))
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&cookies).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let cookies = vec![
+            Cookie {
+                domain: "localhost".to_string(),
+                include_subdomain: "FALSE".to_string(),
+                path: "/".to_string(),
+                https: "FALSE".to_string(),
+                expires: "0".to_string(),
+                name: "session".to_string(),
+                value: "abc123".to_string(),
+                http_only: false,
+            },
+            Cookie {
+                domain: "example.com".to_string(),
+                include_subdomain: "TRUE".to_string(),
+                path: "/api".to_string(),
+                https: "TRUE".to_string(),
+                expires: "1893456000".to_string(),
+                name: "token".to_string(),
+                value: "s3cr3t".to_string(),
+                http_only: true,
+            },
+        ];
+        let json = to_json(&cookies, &["s3cr3t"]);
+        let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {
+                    "name": "session",
+                    "value": "abc123",
+                    "domain": "localhost",
+                    "path": "/",
+                    "expires": 0,
+                    "include_subdomain": false,
+                    "secure": false,
+                    "http_only": false
+                },
+                {
+                    "name": "token",
+                    "value": "***",
+                    "domain": "example.com",
+                    "path": "/api",
+                    "expires": 1893456000,
+                    "include_subdomain": true,
+                    "secure": true,
+                    "http_only": true
+                }
+            ])
+        );
+    }
+}
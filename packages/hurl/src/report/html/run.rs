@@ -77,7 +77,13 @@ impl Testcase {
 /// Returns an HTML view of an `entry` information as HTML (title, `entry_index` and captures).
 fn get_entry_html(entry: &EntryResult, entry_index: usize, secrets: &[&str]) -> String {
     let mut text = String::new();
-    text.push_str(&format!("<summary>Entry {entry_index}</summary>"));
+    match &entry.name {
+        Some(name) => {
+            let name = name.redact(secrets);
+            text.push_str(&format!("<summary>Entry {entry_index}: {name}</summary>"));
+        }
+        None => text.push_str(&format!("<summary>Entry {entry_index}</summary>")),
+    }
 
     let cmd = entry.curl_cmd.to_string().redact(secrets);
     let table = new_table("Debug", &[("Command", &cmd)]);
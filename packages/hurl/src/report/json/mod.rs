@@ -20,20 +20,27 @@
 //! The JSON report is organised as follows:
 //!
 //! - `report.json`: list of each file of a run exported to JSON
+//! - `summary.json`: aggregated stats of all the files of `report.json` (see [`write_summary`])
 //! - `store/foo_response.{json,xml,html}`: an HTTP response referenced in `report.json`
 //!
 //! ```text
 //! report
 //! ├── report.json
+//! ├── summary.json
 //! └── store
 //!     ├── 1fe9d647-5689-4130-b4ea-dc120c2536ba_response.html
 //!     ├── 35f49c69-15f9-43df-a672-a1ff5f68c935_response.json
 //!     ...
 //!     └── ce7f1326-2e2a-46e9-befd-ee0d85084814_response.json
 //! ```
+//!
+//! A line-delimited variant (see [`append_ndjson_report`]) can also be used: each file of a run is
+//! appended to the report file as a single line JSON object, responses being stored in a `store`
+//! directory next to the report file.
 mod deserialize;
+mod summary;
 
-use std::fs::File;
+use std::fs::{File, OpenOptions};
 use std::io;
 use std::io::Write;
 use std::path::Path;
@@ -43,6 +50,8 @@ use hurl_core::input::Input;
 use crate::report::ReportError;
 use crate::runner::HurlResult;
 
+pub use self::summary::{write_summary, Summary};
+
 /// Exports a list of [`Testcase`] to a JSON file `filename`.
 ///
 /// Response file are saved under the `response_dir` directory and referenced by path in JSON report
@@ -77,6 +86,36 @@ pub fn write_report(
     }
 }
 
+/// Appends a [`Testcase`] to a line-delimited JSON file `filename` (one JSON object per line).
+///
+/// Contrary to [`write_report`], any existing report is not parsed: `filename` is opened in append
+/// mode and only `testcase` is serialized. Response files are saved under a `store` directory,
+/// next to `filename`, and referenced by path in the JSON object. `secrets` strings are redacted
+/// from the JSON fields.
+pub fn append_ndjson_report(
+    filename: &Path,
+    testcase: &Testcase,
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    let response_dir = filename.with_file_name("store");
+    std::fs::create_dir_all(&response_dir)?;
+
+    let json = testcase.to_json(&response_dir, secrets)?;
+    let serialized = serde_json::to_string(&json)?;
+    let bytes = format!("{serialized}\n");
+    let bytes = bytes.into_bytes();
+    let file_out = OpenOptions::new().create(true).append(true).open(filename);
+    let result = file_out.and_then(|mut file_out| file_out.write_all(&bytes));
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing JSON report",
+        )),
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase<'a> {
     result: &'a HurlResult,
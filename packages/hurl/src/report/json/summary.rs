@@ -0,0 +1,212 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::report::json::Testcase;
+use crate::report::ReportError;
+
+/// Aggregated stats of a list of [`Testcase`], serialized in the `summary.json` file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Summary {
+    /// Number of executed files.
+    pub total_files: usize,
+    /// Number of successful files.
+    pub succeeded_files: usize,
+    /// Number of failed files.
+    pub failed_files: usize,
+    /// Sum of the files run duration, in milliseconds.
+    pub duration: u64,
+    /// Number of executed HTTP requests, including retries and redirects.
+    pub total_requests: usize,
+    /// Number of bytes transferred (request and response bodies).
+    pub total_bytes: usize,
+}
+
+impl Summary {
+    /// Creates a new summary from a list of `testcases`.
+    pub fn new(testcases: &[Testcase]) -> Self {
+        let mut summary = Summary::default();
+        for testcase in testcases {
+            let result = testcase.result;
+            summary.total_files += 1;
+            if result.success {
+                summary.succeeded_files += 1;
+            } else {
+                summary.failed_files += 1;
+            }
+            summary.duration += result.duration.as_millis() as u64;
+            for call in result.entries.iter().flat_map(|e| &e.calls) {
+                summary.total_requests += 1;
+                summary.total_bytes += call.request.body.len() + call.response.body.len();
+            }
+        }
+        summary
+    }
+
+    /// Adds the stats of `other` to this summary.
+    fn merge(&mut self, other: &Summary) {
+        self.total_files += other.total_files;
+        self.succeeded_files += other.succeeded_files;
+        self.failed_files += other.failed_files;
+        self.duration += other.duration;
+        self.total_requests += other.total_requests;
+        self.total_bytes += other.total_bytes;
+    }
+}
+
+/// Exports the aggregated stats of a list of [`Testcase`] to a JSON file `filename`.
+///
+/// If `filename` already exists, its stats are updated with the stats of `testcases`, in the same
+/// way the JSON report is extended with new results.
+pub fn write_summary(filename: &Path, testcases: &[Testcase]) -> Result<(), ReportError> {
+    let mut summary = parse_summary(filename)?;
+    summary.merge(&Summary::new(testcases));
+
+    let serialized = serde_json::to_string(&summary)?;
+    let bytes = format!("{serialized}\n");
+    let bytes = bytes.into_bytes();
+    let mut file_out = File::create(filename)?;
+    match file_out.write_all(&bytes) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing JSON summary",
+        )),
+    }
+}
+
+/// Parses an existing summary file. If the file doesn't exist or is not a valid summary, an empty
+/// summary is returned.
+fn parse_summary(filename: &Path) -> Result<Summary, ReportError> {
+    if !filename.exists() {
+        return Ok(Summary::default());
+    }
+    let s = match fs::read_to_string(filename) {
+        Ok(s) => s,
+        Err(e) => {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue reading JSON summary",
+            ))
+        }
+    };
+    Ok(serde_json::from_str(&s).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use hurl_core::ast::SourceInfo;
+    use hurl_core::input::Input;
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::http::{Call, CurlCmd, HeaderVec, HttpVersion, Request, Response, Timings};
+    use crate::runner::{EntryResult, HurlResult};
+
+    fn call(request_body: &[u8], response_body: &[u8]) -> Call {
+        let request = Request::new(
+            "POST",
+            "http://localhost:8000/hello".parse().unwrap(),
+            HeaderVec::new(),
+            request_body.to_vec(),
+        );
+        let response = Response::new(
+            HttpVersion::Http11,
+            200,
+            HeaderVec::new(),
+            HeaderVec::new(),
+            response_body.to_vec(),
+            Duration::from_millis(5),
+            "http://localhost:8000/hello".parse().unwrap(),
+            None,
+        );
+        Call {
+            request,
+            response,
+            timings: Timings::default(),
+        }
+    }
+
+    fn hurl_result(success: bool, duration: u64, calls: Vec<Call>) -> HurlResult {
+        HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                calls,
+                captures: vec![],
+                asserts: vec![],
+                errors: vec![],
+                transfer_duration: Duration::from_millis(0),
+                compressed: false,
+                insecure_requests: 0,
+                curl_cmd: CurlCmd::default(),
+                output: None,
+                name: None,
+            }],
+            duration: Duration::from_millis(duration),
+            success,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let filename = Input::new("test.hurl");
+        let result_ok = hurl_result(true, 100, vec![call(b"", b"Hello"), call(b"abc", b"")]);
+        let result_ko = hurl_result(false, 20, vec![call(b"", b"Bye")]);
+        let testcases = [
+            Testcase::new(&result_ok, "", &filename),
+            Testcase::new(&result_ko, "", &filename),
+        ];
+
+        let mut summary = Summary::new(&testcases);
+        assert_eq!(
+            summary,
+            Summary {
+                total_files: 2,
+                succeeded_files: 1,
+                failed_files: 1,
+                duration: 120,
+                total_requests: 3,
+                total_bytes: 11,
+            }
+        );
+
+        summary.merge(&Summary::new(&testcases[..1]));
+        assert_eq!(
+            summary,
+            Summary {
+                total_files: 3,
+                succeeded_files: 2,
+                failed_files: 1,
+                duration: 220,
+                total_requests: 5,
+                total_bytes: 19,
+            }
+        );
+    }
+}
@@ -177,7 +177,7 @@ mod tests {
             success: true,
             ..Default::default()
         };
-        let tc = Testcase::from(&res, content, &filename);
+        let tc = Testcase::from(&res, content, &filename, None);
         testcases.push(tc);
 
         let res = HurlResult {
@@ -197,7 +197,7 @@ mod tests {
             success: true,
             ..Default::default()
         };
-        let tc = Testcase::from(&res, content, &filename);
+        let tc = Testcase::from(&res, content, &filename, None);
         testcases.push(tc);
 
         let res = HurlResult {
@@ -218,7 +218,7 @@ mod tests {
             success: true,
             ..Default::default()
         };
-        let tc = Testcase::from(&res, content, &filename);
+        let tc = Testcase::from(&res, content, &filename, None);
         testcases.push(tc);
 
         let suite = create_testsuite(&testcases, &secrets);
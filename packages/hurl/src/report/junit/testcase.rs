@@ -16,7 +16,7 @@
  *
  */
 use hurl_core::error::{DisplaySourceError, OutputFormat};
-use hurl_core::input::Input;
+use hurl_core::input::{Input, InputKind};
 
 use crate::report::junit::xml::Element;
 use crate::runner::HurlResult;
@@ -26,36 +26,72 @@ use crate::util::redacted::Redact;
 pub struct Testcase {
     id: String,
     name: String,
+    classname: Option<String>,
     time_in_ms: u128,
-    failures: Vec<String>,
-    errors: Vec<String>,
+    failures: Vec<Problem>,
+    errors: Vec<Problem>,
+}
+
+/// A failure or an error of a testcase.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Problem {
+    /// Short description of the problem (e.g. "Assert status code").
+    description: String,
+    /// Full error message, with source context.
+    text: String,
+    /// Name of the entry in failure or in error if any.
+    entry_name: Option<String>,
 }
 
 impl Testcase {
     /// Creates an XML Junit &lt;testcase&gt; from an [`HurlResult`].
-    pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
+    ///
+    /// If defined, the `classname` template is rendered to the `classname` attribute of the testcase.
+    /// The placeholders `{dir}`, `{file}` and `{stem}` are replaced respectively by the parent
+    /// directory, the file name and the file name without extension of `filename`.
+    ///
+    /// Failures and errors of entries named with the `name` option are reported with a `message`
+    /// attribute set to the error description and a `name` attribute set to the entry name.
+    pub fn from(
+        hurl_result: &HurlResult,
+        content: &str,
+        filename: &Input,
+        classname: Option<&str>,
+    ) -> Testcase {
         let id = filename.to_string();
         let name = filename.to_string();
+        let classname = classname.map(|template| render_classname(template, filename));
         let time_in_ms = hurl_result.duration.as_millis();
         let mut failures = vec![];
         let mut errors = vec![];
 
         for (error, entry_src_info) in hurl_result.errors() {
-            let message = error.to_string(
+            let text = error.to_string(
                 &name,
                 content,
                 Some(entry_src_info),
                 OutputFormat::Terminal(false),
             );
+            let entry_name = hurl_result
+                .entries
+                .iter()
+                .find(|e| e.source_info == entry_src_info)
+                .and_then(|e| e.name.clone());
+            let problem = Problem {
+                description: error.description(),
+                text,
+                entry_name,
+            };
             if error.assert {
-                failures.push(message);
+                failures.push(problem);
             } else {
-                errors.push(message);
+                errors.push(problem);
             };
         }
         Testcase {
             id,
             name,
+            classname,
             time_in_ms,
             failures,
             errors,
@@ -68,17 +104,18 @@ impl Testcase {
 
         let mut element = Element::new("testcase")
             .attr("id", &self.id)
-            .attr("name", &self.name)
-            .attr("time", &time_in_seconds);
+            .attr("name", &self.name);
+        if let Some(classname) = &self.classname {
+            element = element.attr("classname", classname);
+        }
+        element = element.attr("time", &time_in_seconds);
 
         for failure in self.failures.iter() {
-            let failure = failure.redact(secrets);
-            element = element.add_child(Element::new("failure").text(&failure));
+            element = element.add_child(failure.to_xml("failure", secrets));
         }
 
         for error in self.errors.iter() {
-            let error = error.redact(secrets);
-            element = element.add_child(Element::new("error").text(&error));
+            element = element.add_child(error.to_xml("error", secrets));
         }
         element
     }
@@ -92,6 +129,48 @@ impl Testcase {
     }
 }
 
+impl Problem {
+    /// Serializes this problem to a &lt;failure&gt; or &lt;error&gt; element (given by `tag`).
+    ///
+    /// If the entry is named, the element has a `message` attribute set to the description of
+    /// the problem and a `name` attribute set to the name of the entry.
+    fn to_xml(&self, tag: &str, secrets: &[&str]) -> Element {
+        let mut element = Element::new(tag);
+        if let Some(entry_name) = &self.entry_name {
+            element = element
+                .attr("message", &self.description.redact(secrets))
+                .attr("name", &entry_name.redact(secrets));
+        }
+        element.text(&self.text.redact(secrets))
+    }
+}
+
+/// Renders a `classname` attribute from a `template` and the `filename` of a Hurl file.
+fn render_classname(template: &str, filename: &Input) -> String {
+    let (dir, file, stem) = match filename.kind() {
+        InputKind::File(path) => {
+            let dir = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.display().to_string(),
+                _ => ".".to_string(),
+            };
+            let file = path
+                .file_name()
+                .map(|f| f.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let stem = path
+                .file_stem()
+                .map(|f| f.to_string_lossy().to_string())
+                .unwrap_or_default();
+            (dir, file, stem)
+        }
+        InputKind::Stdin(_) => (".".to_string(), filename.to_string(), filename.to_string()),
+    };
+    template
+        .replace("{dir}", &dir)
+        .replace("{file}", &file)
+        .replace("{stem}", &stem)
+}
+
 #[cfg(test)]
 mod test {
     use std::time::Duration;
@@ -101,7 +180,7 @@ mod test {
     use hurl_core::reader::Pos;
 
     use crate::http::HttpError;
-    use crate::report::junit::testcase::Testcase;
+    use crate::report::junit::testcase::{render_classname, Testcase};
     use crate::report::junit::xml::XmlDocument;
     use crate::runner::{EntryResult, HurlResult, RunnerError, RunnerErrorKind};
 
@@ -116,7 +195,7 @@ mod test {
         let content = "";
         let secrets = [];
         let filename = Input::new("test.hurl");
-        let element = Testcase::from(&hurl_result, content, &filename).to_xml(&secrets);
+        let element = Testcase::from(&hurl_result, content, &filename, None).to_xml(&secrets);
         let doc = XmlDocument::new(element);
         assert_eq!(
             doc.to_string().unwrap(),
@@ -124,6 +203,41 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_create_testcase_with_classname() {
+        let hurl_result = HurlResult {
+            duration: Duration::from_millis(230),
+            success: true,
+            ..Default::default()
+        };
+
+        let content = "";
+        let secrets = [];
+        let filename = Input::new("tests/api/login.hurl");
+        let element =
+            Testcase::from(&hurl_result, content, &filename, Some("hurl.{dir}")).to_xml(&secrets);
+        let doc = XmlDocument::new(element);
+        assert_eq!(
+            doc.to_string().unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="tests/api/login.hurl" name="tests/api/login.hurl" classname="hurl.tests/api" time="0.230" />"#
+        );
+    }
+
+    #[test]
+    fn test_render_classname() {
+        let filename = Input::new("tests/api/login.hurl");
+        assert_eq!(render_classname("{dir}", &filename), "tests/api");
+        assert_eq!(render_classname("{file}", &filename), "login.hurl");
+        assert_eq!(
+            render_classname("{dir}.{stem}", &filename),
+            "tests/api.login"
+        );
+        assert_eq!(render_classname("smoke", &filename), "smoke");
+
+        let filename = Input::new("login.hurl");
+        assert_eq!(render_classname("{dir}/{stem}", &filename), "./login");
+    }
+
     #[test]
     fn test_create_testcase_failure() {
         let content = r#"GET http://localhost:8000/not_found
@@ -149,7 +263,7 @@ HTTP/1.0 200
             ..Default::default()
         };
 
-        let element = Testcase::from(&hurl_result, content, &filename).to_xml(&secrets);
+        let element = Testcase::from(&hurl_result, content, &filename, None).to_xml(&secrets);
         let doc = XmlDocument::new(element);
         assert_eq!(
             doc.to_string().unwrap(),
@@ -186,7 +300,7 @@ HTTP/1.0 200
             success: false,
             ..Default::default()
         };
-        let element = Testcase::from(&hurl_result, content, &filename).to_xml(&secrets);
+        let element = Testcase::from(&hurl_result, content, &filename, None).to_xml(&secrets);
         let doc = XmlDocument::new(element);
         assert_eq!(
             doc.to_string().unwrap(),
@@ -198,4 +312,44 @@ HTTP/1.0 200
    |</error></testcase>"#
         );
     }
+
+    #[test]
+    fn test_create_testcase_with_entry_name() {
+        let content = r#"GET http://localhost:8000/not_found
+HTTP/1.0 200
+"#;
+        let filename = Input::new("test.hurl");
+        let secrets = [];
+        let hurl_result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 35)),
+                errors: vec![RunnerError::new(
+                    SourceInfo::new(Pos::new(2, 10), Pos::new(2, 13)),
+                    RunnerErrorKind::AssertStatus {
+                        actual: "404".to_string(),
+                    },
+                    true,
+                )],
+                name: Some("Get not found".to_string()),
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(230),
+            success: false,
+            ..Default::default()
+        };
+
+        let element = Testcase::from(&hurl_result, content, &filename, None).to_xml(&secrets);
+        let doc = XmlDocument::new(element);
+        assert_eq!(
+            doc.to_string().unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><failure message="Assert status code" name="Get not found">Assert status code
+  --&gt; test.hurl:2:10
+   |
+   | GET http://localhost:8000/not_found
+ 2 | HTTP/1.0 200
+   |          ^^^ actual value is &lt;404&gt;
+   |</failure></testcase>"#
+        );
+    }
 }
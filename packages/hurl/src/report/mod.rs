@@ -19,6 +19,7 @@
 //! Various reports for Hurl runs (JUnit, HTML etc...) A report aggregates multiple runs into
 //! a single unit.
 
+pub mod cookie;
 pub mod curl;
 mod error;
 pub mod html;
@@ -20,4 +20,4 @@ mod report;
 mod testcase;
 
 pub use self::report::write_report;
-pub use self::testcase::Testcase;
+pub use self::testcase::{Granularity, Testcase};
@@ -22,11 +22,15 @@ use std::path::Path;
 
 use regex::Regex;
 
+use super::testcase::Diagnostic;
 use super::Testcase;
 use crate::report::ReportError;
 
 /// See <https://testanything.org/tap-version-13-specification.html>
 const TAP_REPORT_VERSION_MARKER: &str = "TAP version 13";
+/// Start and end markers of a YAML diagnostic block.
+const DIAGNOSTIC_START: &str = "---";
+const DIAGNOSTIC_END: &str = "...";
 
 /// Creates/Append a Tap report from a list of `testcases`
 pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), ReportError> {
@@ -74,10 +78,7 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
     s.push_str(format!("{start}..{end}\n").as_str());
 
     for (i, testcase) in testcases.iter().enumerate() {
-        let state = if testcase.success { "ok" } else { "not ok" };
-        let number = i + 1;
-        let description = &testcase.description;
-        s.push_str(format!("{state} {number} - {description}\n").as_str());
+        s.push_str(&testcase.to_tap(i + 1));
     }
     match file.write_all(s.as_bytes()) {
         Ok(_) => Ok(()),
@@ -109,7 +110,7 @@ fn parse_tap_file(filename: &Path) -> Result<Vec<Testcase>, ReportError> {
 
 /// Parse Tap report
 fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
-    let mut testcases = vec![];
+    let mut testcases: Vec<Testcase> = vec![];
     let mut lines: Vec<&str> = s.lines().collect::<Vec<&str>>();
     if !lines.is_empty() {
         let mut header = lines.remove(0);
@@ -123,9 +124,22 @@ fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
                 "Invalid TAP Header <{header}>"
             )));
         }
-        for line in lines {
+        let mut lines = lines.into_iter();
+        while let Some(line) = lines.next() {
             let line = line.trim();
-            if !line.is_empty() {
+            if line == DIAGNOSTIC_START {
+                // A YAML diagnostic block belongs to the preceding testcase.
+                let block = lines
+                    .by_ref()
+                    .take_while(|l| l.trim() != DIAGNOSTIC_END)
+                    .collect::<Vec<_>>();
+                let Some(testcase) = testcases.last_mut() else {
+                    return Err(ReportError::from_string(
+                        "Invalid TAP report - diagnostic without testcase",
+                    ));
+                };
+                testcase.diagnostic = Some(Diagnostic::parse(&block)?);
+            } else if !line.is_empty() {
                 let testcase = Testcase::parse(line)?;
                 testcases.push(testcase);
             }
@@ -151,15 +165,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostic: None
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostic: None
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostic: None
                 }
             ]
         );
@@ -179,15 +196,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostic: None
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostic: None
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostic: None
                 }
             ]
         );
@@ -205,28 +225,77 @@ ok 5 - test.5.hurl
             vec![
                 Testcase {
                     description: "test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostic: None
                 },
                 Testcase {
                     description: "test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostic: None
                 },
                 Testcase {
                     description: "test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostic: None
                 },
                 Testcase {
                     description: "test.4.hurl".to_string(),
-                    success: false
+                    success: false,
+                    diagnostic: None
                 },
                 Testcase {
                     description: "test.5.hurl".to_string(),
-                    success: true
+                    success: true,
+                    diagnostic: None
                 }
             ]
         );
     }
 
+    #[test]
+    fn test_parse_tap_report_with_diagnostic() {
+        let s = r#"TAP version 13
+1..2
+ok 1 - test.1.hurl
+  ---
+  asserts: 2
+  failures: 0
+  ...
+not ok 2 - test.2.hurl
+  ---
+  asserts: 3
+  failures: 1
+  message: 'Assert status code'
+  line: 6
+  ...
+"#;
+        assert_eq!(
+            parse_tap_report(s).unwrap(),
+            vec![
+                Testcase {
+                    description: "test.1.hurl".to_string(),
+                    success: true,
+                    diagnostic: Some(Diagnostic {
+                        asserts: 2,
+                        failures: 0,
+                        message: None,
+                        line: None,
+                    }),
+                },
+                Testcase {
+                    description: "test.2.hurl".to_string(),
+                    success: false,
+                    diagnostic: Some(Diagnostic {
+                        asserts: 3,
+                        failures: 1,
+                        message: Some("Assert status code".to_string()),
+                        line: Some(6),
+                    }),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_error() {
         let s = r#"Dummy header
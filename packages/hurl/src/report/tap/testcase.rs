@@ -15,15 +15,36 @@
  * limitations under the License.
  *
  */
+use hurl_core::error::DisplaySourceError;
 use hurl_core::input::Input;
 
 use crate::report::ReportError;
-use crate::runner::HurlResult;
+use crate::runner::{EntryResult, HurlResult, RunnerError};
+
+/// Granularity of the TAP testpoints: one testpoint per file, per entry or per assert.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Granularity {
+    #[default]
+    File,
+    Entry,
+    Assert,
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase {
     pub(crate) description: String,
     pub(crate) success: bool,
+    pub(crate) diagnostic: Option<Diagnostic>,
+}
+
+/// A TAP YAML diagnostic block of a testpoint, with the number of asserts run and failed, and the
+/// first failure message.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub(crate) asserts: usize,
+    pub(crate) failures: usize,
+    pub(crate) message: Option<String>,
+    pub(crate) line: Option<usize>,
 }
 
 impl Testcase {
@@ -31,10 +52,70 @@ impl Testcase {
     pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
         let description = filename.to_string();
         let success = hurl_result.errors().is_empty();
+        let diagnostic = Diagnostic::from_entries(last_runs(&hurl_result.entries));
         Testcase {
             description,
             success,
+            diagnostic: Some(diagnostic),
+        }
+    }
+
+    /// Creates a list of Tap &lt;testcase&gt; from an [`HurlResult`], given a testpoint `granularity`.
+    ///
+    /// With [`Granularity::Entry`] and [`Granularity::Assert`], only the last run of a retried
+    /// entry is used. With [`Granularity::Assert`], an entry in error (not due to asserts) produces
+    /// an additional failed testcase, so errors are always reported. Entries named with the `name`
+    /// option are identified by their name in the testcases description.
+    ///
+    /// If `diagnostic` is true, each testcase has a YAML diagnostic block with its asserts count
+    /// and first failure.
+    pub fn from_granularity(
+        hurl_result: &HurlResult,
+        filename: &Input,
+        granularity: Granularity,
+        diagnostic: bool,
+    ) -> Vec<Testcase> {
+        let mut testcases = match granularity {
+            Granularity::File => vec![Testcase::from(hurl_result, filename)],
+            Granularity::Entry => last_runs(&hurl_result.entries)
+                .map(|entry| Testcase {
+                    description: format!("{filename} ({})", entry_label(entry)),
+                    success: entry.errors.is_empty(),
+                    diagnostic: Some(Diagnostic::from_entries([entry].into_iter())),
+                })
+                .collect(),
+            Granularity::Assert => {
+                let mut testcases = vec![];
+                for entry in last_runs(&hurl_result.entries) {
+                    let label = entry_label(entry);
+                    for assert in entry.asserts.iter() {
+                        let error = assert.error();
+                        let diagnostic = Diagnostic {
+                            asserts: 1,
+                            failures: usize::from(error.is_some()),
+                            ..Diagnostic::from_error(error.as_ref())
+                        };
+                        testcases.push(Testcase {
+                            description: format!("{filename}:{} ({label})", assert.line()),
+                            success: error.is_none(),
+                            diagnostic: Some(diagnostic),
+                        });
+                    }
+                    if let Some(error) = entry.errors.iter().find(|e| !e.assert) {
+                        testcases.push(Testcase {
+                            description: format!("{filename} ({label})"),
+                            success: false,
+                            diagnostic: Some(Diagnostic::from_error(Some(error))),
+                        });
+                    }
+                }
+                testcases
+            }
+        };
+        if !diagnostic {
+            testcases.iter_mut().for_each(|t| t.diagnostic = None);
         }
+        testcases
     }
 
     /// Creates an Tap &lt;testcase&gt; from a TAP line
@@ -72,13 +153,336 @@ impl Testcase {
         Ok(Testcase {
             description,
             success,
+            diagnostic: None,
         })
     }
+
+    /// Serializes this testcase to a TAP line, with the testpoint `number`, followed by its
+    /// optional YAML diagnostic block.
+    pub fn to_tap(&self, number: usize) -> String {
+        let state = if self.success { "ok" } else { "not ok" };
+        let description = &self.description;
+        let mut s = format!("{state} {number} - {description}\n");
+        if let Some(diagnostic) = &self.diagnostic {
+            s.push_str(&diagnostic.to_yaml());
+        }
+        s
+    }
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic from a list of `entries`, counting all their asserts and keeping the
+    /// first error.
+    fn from_entries<'a>(entries: impl Iterator<Item = &'a EntryResult>) -> Diagnostic {
+        let mut asserts = 0;
+        let mut failures = 0;
+        let mut first_error = None;
+        for entry in entries {
+            asserts += entry.asserts.len();
+            failures += entry.asserts.iter().filter(|a| a.error().is_some()).count();
+            if first_error.is_none() {
+                first_error = entry.errors.first();
+            }
+        }
+        Diagnostic {
+            asserts,
+            failures,
+            ..Diagnostic::from_error(first_error)
+        }
+    }
+
+    /// Creates a diagnostic with the message and line of an optional `error`, without asserts.
+    fn from_error(error: Option<&RunnerError>) -> Diagnostic {
+        Diagnostic {
+            message: error.map(|e| e.description()),
+            line: error.map(|e| e.source_info.start.line),
+            ..Default::default()
+        }
+    }
+
+    /// Serializes this diagnostic to an indented TAP13 YAML block.
+    fn to_yaml(&self) -> String {
+        let mut s = "  ---\n".to_string();
+        s.push_str(&format!("  asserts: {}\n", self.asserts));
+        s.push_str(&format!("  failures: {}\n", self.failures));
+        if let Some(message) = &self.message {
+            let message = message.replace('\'', "''");
+            s.push_str(&format!("  message: '{message}'\n"));
+        }
+        if let Some(line) = self.line {
+            s.push_str(&format!("  line: {line}\n"));
+        }
+        s.push_str("  ...\n");
+        s
+    }
+
+    /// Parses a diagnostic from the `lines` of a YAML block (without the `---` and `...` markers).
+    pub fn parse(lines: &[&str]) -> Result<Diagnostic, ReportError> {
+        let mut diagnostic = Diagnostic::default();
+        for line in lines {
+            let Some((key, value)) = line.trim().split_once(':') else {
+                return Err(ReportError::from_string(&format!(
+                    "Invalid TAP diagnostic line <{line}>"
+                )));
+            };
+            let value = value.trim();
+            match key {
+                "asserts" => diagnostic.asserts = parse_count(line, value)?,
+                "failures" => diagnostic.failures = parse_count(line, value)?,
+                "line" => diagnostic.line = Some(parse_count(line, value)?),
+                "message" => {
+                    let message = value
+                        .strip_prefix('\'')
+                        .and_then(|v| v.strip_suffix('\''))
+                        .unwrap_or(value);
+                    diagnostic.message = Some(message.replace("''", "'"));
+                }
+                // Unknown keys are ignored, so diagnostics written by other tools can be kept.
+                _ => {}
+            }
+        }
+        Ok(diagnostic)
+    }
+}
+
+/// Parses a count `value` from a diagnostic `line`.
+fn parse_count(line: &str, value: &str) -> Result<usize, ReportError> {
+    value
+        .parse::<usize>()
+        .map_err(|_| ReportError::from_string(&format!("Invalid TAP diagnostic line <{line}>")))
+}
+
+/// Returns the label of an `entry` used in testpoints description, with the entry name if any.
+fn entry_label(entry: &EntryResult) -> String {
+    match &entry.name {
+        Some(name) => format!("entry {}: {name}", entry.entry_index),
+        None => format!("entry {}", entry.entry_index),
+    }
+}
+
+/// Returns the last run of each entry in `entries`, ignoring previous runs of retried entries.
+fn last_runs(entries: &[EntryResult]) -> impl Iterator<Item = &EntryResult> {
+    entries
+        .iter()
+        .enumerate()
+        .filter_map(|(i, entry)| match entries.get(i + 1) {
+            Some(next) if next.entry_index == entry.entry_index => None,
+            _ => Some(entry),
+        })
 }
 
 #[cfg(test)]
 mod tests {
+    use hurl_core::ast::SourceInfo;
+    use hurl_core::reader::Pos;
+
     use super::*;
+    use crate::http::HttpError;
+    use crate::runner::{AssertResult, RunnerError, RunnerErrorKind};
+
+    fn status_assert(line: usize, actual: u64, expected: u64) -> AssertResult {
+        AssertResult::Status {
+            actual,
+            expected,
+            source_info: SourceInfo::new(Pos::new(line, 6), Pos::new(line, 9)),
+        }
+    }
+
+    /// Returns a result with 3 entries: the first one is successful with 2 asserts, the second one
+    /// has been retried and has a failed assert, the third one is named and has a runtime error.
+    fn hurl_result() -> HurlResult {
+        let assert_error = RunnerError::new(
+            SourceInfo::new(Pos::new(6, 6), Pos::new(6, 9)),
+            RunnerErrorKind::AssertStatus {
+                actual: "404".to_string(),
+            },
+            true,
+        );
+        let http_error = RunnerError::new(
+            SourceInfo::new(Pos::new(8, 5), Pos::new(8, 27)),
+            RunnerErrorKind::Http(HttpError::Libcurl {
+                code: 6,
+                description: "Could not resolve host: unknown".to_string(),
+            }),
+            false,
+        );
+        HurlResult {
+            entries: vec![
+                EntryResult {
+                    entry_index: 1,
+                    asserts: vec![status_assert(2, 200, 200), status_assert(3, 200, 200)],
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 2,
+                    asserts: vec![status_assert(6, 500, 200)],
+                    errors: vec![assert_error.clone()],
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 2,
+                    asserts: vec![status_assert(6, 404, 200)],
+                    errors: vec![assert_error],
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 3,
+                    errors: vec![http_error],
+                    name: Some("Unknown host".to_string()),
+                    ..Default::default()
+                },
+            ],
+            success: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn create_testcases_with_granularity() {
+        let hurl_result = hurl_result();
+        let filename = Input::new("test.hurl");
+
+        let testcases =
+            Testcase::from_granularity(&hurl_result, &filename, Granularity::File, false);
+        assert_eq!(
+            testcases,
+            vec![Testcase {
+                description: "test.hurl".to_string(),
+                success: false,
+                diagnostic: None
+            }]
+        );
+
+        let testcases =
+            Testcase::from_granularity(&hurl_result, &filename, Granularity::Entry, false);
+        assert_eq!(
+            testcases,
+            vec![
+                Testcase {
+                    description: "test.hurl (entry 1)".to_string(),
+                    success: true,
+                    diagnostic: None
+                },
+                Testcase {
+                    description: "test.hurl (entry 2)".to_string(),
+                    success: false,
+                    diagnostic: None
+                },
+                Testcase {
+                    description: "test.hurl (entry 3: Unknown host)".to_string(),
+                    success: false,
+                    diagnostic: None
+                },
+            ]
+        );
+
+        let testcases =
+            Testcase::from_granularity(&hurl_result, &filename, Granularity::Assert, false);
+        assert_eq!(
+            testcases,
+            vec![
+                Testcase {
+                    description: "test.hurl:2 (entry 1)".to_string(),
+                    success: true,
+                    diagnostic: None
+                },
+                Testcase {
+                    description: "test.hurl:3 (entry 1)".to_string(),
+                    success: true,
+                    diagnostic: None
+                },
+                Testcase {
+                    description: "test.hurl:6 (entry 2)".to_string(),
+                    success: false,
+                    diagnostic: None
+                },
+                Testcase {
+                    description: "test.hurl (entry 3: Unknown host)".to_string(),
+                    success: false,
+                    diagnostic: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn create_testcases_with_diagnostic() {
+        let hurl_result = hurl_result();
+        let filename = Input::new("test.hurl");
+
+        let testcases =
+            Testcase::from_granularity(&hurl_result, &filename, Granularity::File, true);
+        assert_eq!(
+            testcases[0].diagnostic,
+            Some(Diagnostic {
+                asserts: 3,
+                failures: 1,
+                message: Some("Assert status code".to_string()),
+                line: Some(6),
+            })
+        );
+
+        let testcases =
+            Testcase::from_granularity(&hurl_result, &filename, Granularity::Entry, true);
+        let diagnostics = testcases
+            .into_iter()
+            .map(|t| t.diagnostic.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic {
+                    asserts: 2,
+                    failures: 0,
+                    message: None,
+                    line: None,
+                },
+                Diagnostic {
+                    asserts: 1,
+                    failures: 1,
+                    message: Some("Assert status code".to_string()),
+                    line: Some(6),
+                },
+                Diagnostic {
+                    asserts: 0,
+                    failures: 0,
+                    message: Some("HTTP connection".to_string()),
+                    line: Some(8),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn testcase_to_tap() {
+        let testcase = Testcase {
+            description: "test.hurl".to_string(),
+            success: false,
+            diagnostic: None,
+        };
+        assert_eq!(testcase.to_tap(2), "not ok 2 - test.hurl\n");
+
+        let testcase = Testcase {
+            diagnostic: Some(Diagnostic {
+                asserts: 3,
+                failures: 1,
+                message: Some("Assert 'status' code".to_string()),
+                line: Some(6),
+            }),
+            ..testcase
+        };
+        assert_eq!(
+            testcase.to_tap(2),
+            "not ok 2 - test.hurl
+  ---
+  asserts: 3
+  failures: 1
+  message: 'Assert ''status'' code'
+  line: 6
+  ...
+"
+        );
+    }
 
     #[test]
     fn parse_tap_test_line() {
@@ -91,7 +495,8 @@ mod tests {
             Testcase::parse("ok 1 - tests_ok/test.1.hurl").unwrap(),
             Testcase {
                 description: "tests_ok/test.1.hurl".to_string(),
-                success: true
+                success: true,
+                diagnostic: None
             }
         );
     }
@@ -16,19 +16,20 @@
  *
  */
 use std::cmp::min;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use hurl::parallel::job::{Job, JobResult};
 use hurl::parallel::runner::ParallelRunner;
+use hurl::report::json;
 use hurl::runner::{HurlResult, Output, VariableSet};
 use hurl::util::term::{Stdout, WriteMode};
 use hurl::{output, parallel, runner};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
-use hurl_core::input::Input;
+use hurl_core::input::{Input, InputKind};
 use hurl_core::typing::Count;
 
 use crate::cli::options::CliOptions;
-use crate::cli::CliError;
+use crate::cli::{BaseLogger, CliError};
 use crate::{cli, HurlRun};
 
 /// Runs Hurl `files` sequentially, given a current directory and command-line options (see
@@ -44,9 +45,13 @@ pub fn run_seq(
     let repeat = options.repeat.unwrap_or(Count::Finite(1));
     let queue = InputQueue::new(files, repeat);
 
-    // When dumped HTTP responses, we truncate existing output file on first save, then append
-    // it on subsequent write.
-    let mut append = false;
+    // When dumped HTTP responses, we truncate existing output file on first save (unless
+    // `--output-append` is used), then append it on subsequent write.
+    let mut append = options.output_append;
+
+    // When cookies are saved, the cookie storage is shared between files: each file starts with
+    // the cookies of the previous one.
+    let mut cookies = vec![];
 
     for filename in queue {
         let content = filename.read_to_string();
@@ -57,6 +62,21 @@ pub fn run_seq(
                 return Err(error);
             }
         };
+        // Curl commands are converted to Hurl before being run. As for Hurl parsing errors, the
+        // conversion error is displayed here and we return a parsing error.
+        let content = if options.from_curl {
+            match hurl_curl::parse(&content) {
+                Ok(c) => c,
+                Err(error) => {
+                    let verbose = options.verbose || options.very_verbose;
+                    let logger = BaseLogger::new(options.color, verbose);
+                    logger.error(&format!("Invalid curl commands in {filename}: {error}"));
+                    return Err(CliError::Parsing);
+                }
+            }
+        } else {
+            content
+        };
         let mut variables = VariableSet::from(&options.variables);
         // By runtime, construction, there is no two secrets having the same name so we can safely
         // insert all the secrets in the variable set.
@@ -65,7 +85,7 @@ pub fn run_seq(
                 .insert_secret(name.clone(), value.clone())
                 .unwrap();
         });
-        let runner_options = options.to_runner_options(&filename, current_dir);
+        let runner_options = options.to_runner_options(&filename, current_dir, &cookies);
         let logger_options = options.to_logger_options();
 
         // Run our Hurl file now, we can only fail if there is a parsing error.
@@ -97,12 +117,34 @@ pub fn run_seq(
         )?;
         append = true;
 
+        if let Some(dir) = &options.output_dir {
+            let dir = file_output_dir(dir, &filename, files);
+            write_bodies(&hurl_result, &content, &filename, &dir, options.color)?;
+        }
+
+        if let Some(file) = &options.json_report_ndjson {
+            let secrets = hurl_result.variables.secrets();
+            let secrets = secrets.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+            let testcase = json::Testcase::new(&hurl_result, &content, &filename);
+            json::append_ndjson_report(file, &testcase, &secrets)?;
+        }
+
+        if options.cookie_output_file.is_some() {
+            cookies = hurl_result.cookies.clone();
+        }
+
         let run = HurlRun {
             content,
             filename: filename.clone(),
             hurl_result,
         };
+        let success = run.hurl_result.success;
         runs.push(run);
+
+        // In fail fast mode, we don't run the remaining files after the first failure.
+        if options.fail_fast && !success {
+            break;
+        }
     }
 
     Ok(runs)
@@ -163,9 +205,72 @@ fn print_output(
             return Err(CliError::Runtime(message));
         }
     }
+    if let Some(file) = &options.dump_request {
+        write_last_request(hurl_result, content, filename, file, options.color)?;
+    }
     Ok(())
 }
 
+/// Returns the directory where the response bodies of the input file `filename` are written.
+///
+/// With a single input file, bodies are written directly in `dir`. With several input `files`,
+/// each file has its own subdirectory in `dir`, named after its index in `files` and its stem
+/// (`1-foo`, `2-bar` etc...), so bodies of different files don't overwrite each other.
+fn file_output_dir(dir: &Path, filename: &Input, files: &[Input]) -> PathBuf {
+    if files.len() <= 1 {
+        return dir.to_path_buf();
+    }
+    let index = files.iter().position(|f| f == filename).unwrap_or(0) + 1;
+    let stem = match filename.kind() {
+        InputKind::File(path) => path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        InputKind::Stdin(_) => "stdin".to_string(),
+    };
+    dir.join(format!("{index}-{stem}"))
+}
+
+/// Writes the response body of each entry of `hurl_result` to the directory `dir`.
+///
+/// `content` (the source string) and `filename` (the source file) are used to construct errors.
+fn write_bodies(
+    hurl_result: &HurlResult,
+    content: &str,
+    filename: &Input,
+    dir: &Path,
+    color: bool,
+) -> Result<(), CliError> {
+    output::write_bodies(hurl_result, dir).map_err(|e| {
+        CliError::Runtime(e.to_string(
+            &filename.to_string(),
+            content,
+            None,
+            OutputFormat::Terminal(color),
+        ))
+    })
+}
+
+/// Writes the last request of `hurl_result` to the file `file`.
+///
+/// `content` (the source string) and `filename` (the source file) are used to construct errors.
+fn write_last_request(
+    hurl_result: &HurlResult,
+    content: &str,
+    filename: &Input,
+    file: &Path,
+    color: bool,
+) -> Result<(), CliError> {
+    output::write_last_request(hurl_result, file).map_err(|e| {
+        CliError::Runtime(e.to_string(
+            &filename.to_string(),
+            content,
+            None,
+            OutputFormat::Terminal(color),
+        ))
+    })
+}
+
 /// Runs Hurl `files` in parallel, given a current directory and command-line options (see
 /// [`crate::cli::options::CliOptions`]). This function returns a list of [`HurlRun`] results or
 /// an error.
@@ -199,7 +304,7 @@ pub fn run_par(
         .iter()
         .enumerate()
         .map(|(seq, input)| {
-            let runner_options = options.to_runner_options(input, current_dir);
+            let runner_options = options.to_runner_options(input, current_dir, &[]);
             let logger_options = options.to_logger_options();
             Job::new(input, seq, &runner_options, &variables, &logger_options)
         })
@@ -211,11 +316,37 @@ pub fn run_par(
         options.repeat.unwrap_or(Count::Finite(1)),
         options.test,
         options.progress_bar,
+        options.progress_format.into(),
         options.color,
         max_width,
+        options.fail_fast,
+        options.json_report_ndjson.clone(),
+        options.output_append,
     );
     let results = runner.run(&jobs)?;
-    let results = results.into_iter().map(HurlRun::from).collect();
+    let results = results.into_iter().map(HurlRun::from).collect::<Vec<_>>();
+    if let Some(dir) = &options.output_dir {
+        for run in &results {
+            let dir = file_output_dir(dir, &run.filename, files);
+            write_bodies(
+                &run.hurl_result,
+                &run.content,
+                &run.filename,
+                &dir,
+                options.color,
+            )?;
+        }
+    }
+    // Results are ordered as the input files, so the last request is the one of the last file.
+    if let (Some(file), Some(run)) = (&options.dump_request, results.last()) {
+        write_last_request(
+            &run.hurl_result,
+            &run.content,
+            &run.filename,
+            file,
+            options.color,
+        )?;
+    }
     Ok(results)
 }
 
@@ -15,16 +15,17 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Assert, SourceInfo};
+use hurl_core::ast::{Assert, PredicateFuncValue, QueryValue, SourceInfo};
 use hurl_core::reader::Pos;
 
 use crate::http;
 use crate::runner::cache::BodyCache;
 use crate::runner::diff::diff;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
-use crate::runner::filter::eval_filters;
-use crate::runner::predicate::eval_predicate;
-use crate::runner::query::eval_query;
+use crate::runner::eval_context::EvalContext;
+use crate::runner::filter::{eval_filters, jsonpath_value};
+use crate::runner::predicate::{eval_jsonpath_predicate, eval_predicate};
+use crate::runner::query::{eval_query, eval_query_jsonpath_result};
 use crate::runner::result::AssertResult;
 use crate::runner::{Value, VariableSet};
 use crate::util::path::ContextDir;
@@ -149,14 +150,49 @@ fn use_diff(expected: &Value, actual: &Value) -> bool {
 ///
 /// The `cache` is used to store XML / JSON structured response data and avoid redundant parsing
 /// operation on the response.
+///
+/// If `ignore_trailing_newline` is `true`, a single trailing newline is ignored when comparing the
+/// response body.
 pub fn eval_explicit_assert(
     assert: &Assert,
     variables: &VariableSet,
     http_response: &http::Response,
     cache: &mut BodyCache,
     context_dir: &ContextDir,
+    ignore_trailing_newline: bool,
+    context: &EvalContext,
 ) -> AssertResult {
-    let query_result = eval_query(&assert.query, variables, http_response, cache);
+    // `exists` on a JSONPath query is evaluated against the shape of the JSONPath result, so a path
+    // selecting no node can be distinguished from a path selecting an empty array.
+    if let (QueryValue::Jsonpath { expr, .. }, PredicateFuncValue::Exist, true) = (
+        &assert.query.value,
+        &assert.predicate.predicate_func.value,
+        assert.filters.is_empty(),
+    ) {
+        let result = eval_query_jsonpath_result(
+            http_response,
+            cache,
+            expr,
+            variables,
+            assert.query.source_info,
+        );
+        let predicate_result = match &result {
+            Err(_) => None,
+            Ok(result) => Some(eval_jsonpath_predicate(
+                &assert.predicate,
+                variables,
+                result.as_ref(),
+                context_dir,
+            )),
+        };
+        return AssertResult::Explicit {
+            actual: result.map(|r| r.map(jsonpath_value)),
+            source_info: assert.predicate.predicate_func.source_info,
+            predicate_result,
+        };
+    }
+
+    let query_result = eval_query(&assert.query, variables, http_response, cache, context);
 
     let actual = if assert.filters.is_empty() {
         query_result
@@ -178,7 +214,7 @@ pub fn eval_explicit_assert(
                     .iter()
                     .map(|(_, f)| f.clone())
                     .collect::<Vec<_>>();
-                match eval_filters(&filters, &value, variables, true) {
+                match eval_filters(&filters, &value, variables, true, context) {
                     Ok(value) => Ok(value),
                     Err(e) => Err(e),
                 }
@@ -188,6 +224,9 @@ pub fn eval_explicit_assert(
         query_result
     };
 
+    // A trailing newline can only be ignored when comparing response bodies.
+    let ignore_trailing_newline =
+        ignore_trailing_newline && matches!(assert.query.value, QueryValue::Body);
     let source_info = assert.predicate.predicate_func.source_info;
     let predicate_result = match &actual {
         Err(_) => None,
@@ -196,6 +235,7 @@ pub fn eval_explicit_assert(
             variables,
             actual,
             context_dir,
+            ignore_trailing_newline,
         )),
     };
 
@@ -212,13 +252,13 @@ pub mod tests {
 
     use hurl_core::ast::{
         Filter, FilterValue, LineTerminator, Predicate, PredicateFunc, PredicateFuncValue,
-        PredicateValue, SourceInfo, Whitespace, I64,
+        PredicateValue, Query, QueryValue, SourceInfo, Template, TemplateElement, Whitespace, I64,
     };
     use hurl_core::reader::Pos;
 
     use super::super::query;
     use super::*;
-    use crate::http::xml_three_users_http_response;
+    use crate::http::{hello_http_response, xml_three_users_http_response};
     use crate::runner::Number;
 
     // `xpath "//user" count == 3`
@@ -262,6 +302,52 @@ pub mod tests {
         }
     }
 
+    // `header "{name}" not exists`
+    fn assert_header_not_exists(name: &str) -> Assert {
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        };
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8 + name.len())),
+            value: QueryValue::Header {
+                space0: whitespace.clone(),
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: name.to_string(),
+                        encoded: name.to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 8 + name.len())),
+                },
+            },
+        };
+        let predicate = Predicate {
+            not: true,
+            space0: whitespace.clone(),
+            predicate_func: PredicateFunc {
+                source_info: SourceInfo::new(
+                    Pos::new(1, 13 + name.len()),
+                    Pos::new(1, 19 + name.len()),
+                ),
+                value: PredicateFuncValue::Exist,
+            },
+        };
+        Assert {
+            line_terminators: vec![],
+            space0: whitespace.clone(),
+            query,
+            filters: vec![],
+            space1: whitespace.clone(),
+            predicate,
+            line_terminator0: LineTerminator {
+                space0: whitespace.clone(),
+                comment: None,
+                newline: whitespace,
+            },
+        }
+    }
+
     #[test]
     fn test_invalid_xpath() {}
 
@@ -278,7 +364,9 @@ pub mod tests {
                 &variables,
                 &xml_three_users_http_response(),
                 &mut cache,
-                &context_dir
+                &context_dir,
+                false,
+                &EvalContext::default()
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(3)))),
@@ -288,6 +376,46 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_eval_header_not_exists() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let mut cache = BodyCache::new();
+
+        // Header is absent: assert succeeds.
+        let assert_result = eval_explicit_assert(
+            &assert_header_not_exists("Server"),
+            &variables,
+            &hello_http_response(),
+            &mut cache,
+            &context_dir,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(assert_result.error(), None);
+
+        // Header is present: assert fails.
+        let assert_result = eval_explicit_assert(
+            &assert_header_not_exists("Content-Type"),
+            &variables,
+            &hello_http_response(),
+            &mut cache,
+            &context_dir,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            assert_result.error().unwrap().kind,
+            RunnerErrorKind::AssertFailure {
+                actual: "string <text/html; charset=utf-8>".to_string(),
+                expected: "not something".to_string(),
+                type_mismatch: false,
+            }
+        );
+    }
+
     #[test]
     pub fn test_use_diff() {
         assert!(!use_diff(&Value::Bool(true), &Value::Bool(false)));
@@ -17,7 +17,8 @@
  */
 use std::path::PathBuf;
 
-use hurl_core::ast::{Base64, Body, Bytes, File, Hex, Template};
+use encoding::EncoderTrap;
+use hurl_core::ast::{Base64, Body, Bytes, File, Hex, SourceInfo, Template};
 
 use crate::http;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
@@ -64,6 +65,60 @@ pub fn eval_bytes(
     }
 }
 
+/// Removes carriage returns and line feeds from a request `body`.
+///
+/// This mimics curl `--data` / `--data-ascii` behaviour when reading data from a file. Text and
+/// file bodies are stripped, while binary bodies (`base64,` and `hex,`) are sent unchanged. As
+/// the stripped bytes of a file body differ from the file content, a file body is converted to
+/// a binary body.
+pub fn strip_newlines(body: &http::Body) -> http::Body {
+    match body {
+        http::Body::Text(value) => {
+            let value = value.chars().filter(|c| *c != '\r' && *c != '\n').collect();
+            http::Body::Text(value)
+        }
+        http::Body::Binary(value) => http::Body::Binary(value.clone()),
+        http::Body::File(value, _) => {
+            let value = value
+                .iter()
+                .filter(|b| **b != b'\r' && **b != b'\n')
+                .copied()
+                .collect();
+            http::Body::Binary(value)
+        }
+    }
+}
+
+/// Encodes a text request `body` with a `charset`.
+///
+/// Only text bodies are encoded, binary and file bodies are sent unchanged. As the encoded bytes
+/// of a text body may not be valid UTF-8, a text body is converted to a binary body. `source_info`
+/// is the position of the body, used if the body can't be encoded.
+pub fn encode(
+    body: &http::Body,
+    charset: &str,
+    source_info: SourceInfo,
+) -> Result<http::Body, RunnerError> {
+    let http::Body::Text(value) = body else {
+        return Ok(body.clone());
+    };
+    let Some(encoding) = encoding::label::encoding_from_whatwg_label(charset) else {
+        let kind = RunnerErrorKind::InvalidCharset {
+            charset: charset.to_string(),
+        };
+        return Err(RunnerError::new(source_info, kind, false));
+    };
+    match encoding.encode(value, EncoderTrap::Strict) {
+        Ok(value) => Ok(http::Body::Binary(value)),
+        Err(_) => {
+            let kind = RunnerErrorKind::InvalidEncoding {
+                charset: charset.to_string(),
+            };
+            Err(RunnerError::new(source_info, kind, false))
+        }
+    }
+}
+
 pub fn eval_file(
     filename: &Template,
     variables: &VariableSet,
@@ -91,7 +146,7 @@ pub fn eval_file(
 mod tests {
     use std::path::Path;
 
-    use hurl_core::ast::{SourceInfo, TemplateElement, Whitespace};
+    use hurl_core::ast::{MultilineString, MultilineStringKind, TemplateElement, Text, Whitespace};
     use hurl_core::reader::Pos;
 
     use super::*;
@@ -165,4 +220,91 @@ mod tests {
             SourceInfo::new(Pos::new(1, 7), Pos::new(1, 15))
         );
     }
+
+    #[test]
+    fn test_strip_newlines() {
+        let body = http::Body::Text("{\n  \"foo\": \"bar\"\r\n}\n".to_string());
+        assert_eq!(
+            strip_newlines(&body),
+            http::Body::Text("{  \"foo\": \"bar\"}".to_string())
+        );
+
+        let body = http::Body::File(b"line1\nline2\r\n".to_vec(), "data.txt".to_string());
+        assert_eq!(
+            strip_newlines(&body),
+            http::Body::Binary(b"line1line2".to_vec())
+        );
+
+        let body = http::Body::Binary(b"\x00\n\r\x01".to_vec());
+        assert_eq!(
+            strip_newlines(&body),
+            http::Body::Binary(b"\x00\n\r\x01".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_preserve_newlines() {
+        // By default, newlines of inline and file bodies are sent as is.
+        let bytes = Bytes::MultilineString(MultilineString {
+            kind: MultilineStringKind::Text(Text {
+                space: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(1, 4)),
+                },
+                newline: Whitespace {
+                    value: "\n".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 4), Pos::new(2, 1)),
+                },
+                value: Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "line1\nline2\n".to_string(),
+                        encoded: "line1\nline2\n".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(2, 1), Pos::new(4, 1)),
+                },
+            }),
+            attributes: vec![],
+        });
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::default();
+        assert_eq!(
+            eval_bytes(&bytes, &variables, &context_dir).unwrap(),
+            http::Body::Text("line1\nline2\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        let source_info = SourceInfo::new(Pos::new(2, 1), Pos::new(2, 1));
+
+        // 日本 in Shift_JIS
+        let body = http::Body::Text("日本".to_string());
+        assert_eq!(
+            encode(&body, "Shift_JIS", source_info).unwrap(),
+            http::Body::Binary(vec![0x93, 0xfa, 0x96, 0x7b])
+        );
+
+        // Binary bodies are not encoded
+        let body = http::Body::Binary(vec![0xff]);
+        assert_eq!(encode(&body, "Shift_JIS", source_info).unwrap(), body);
+
+        let body = http::Body::Text("日本".to_string());
+        let error = encode(&body, "ISO-8859-1", source_info).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::InvalidEncoding {
+                charset: "ISO-8859-1".to_string()
+            }
+        );
+        assert_eq!(error.source_info, source_info);
+
+        let error = encode(&body, "xxx", source_info).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::InvalidCharset {
+                charset: "xxx".to_string()
+            }
+        );
+    }
 }
@@ -20,6 +20,7 @@ use hurl_core::ast::Capture;
 use crate::http;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::eval_context::EvalContext;
 use crate::runner::filter::eval_filters;
 use crate::runner::query::eval_query;
 use crate::runner::result::CaptureResult;
@@ -30,15 +31,17 @@ use crate::runner::VariableSet;
 /// [`CaptureResult`] on success or an [`RunnerError`].
 ///
 /// The `cache` is used to store XML / JSON structured response data and avoid redundant parsing
-/// operation on the response.
+/// operation on the response. The `context` holds the settings used to evaluate the query and
+/// filters of the capture.
 pub fn eval_capture(
     capture: &Capture,
     variables: &VariableSet,
     http_response: &http::Response,
     cache: &mut BodyCache,
+    context: &EvalContext,
 ) -> Result<CaptureResult, RunnerError> {
     let name = eval_template(&capture.name, variables)?;
-    let value = eval_query(&capture.query, variables, http_response, cache)?;
+    let value = eval_query(&capture.query, variables, http_response, cache, context)?;
     let value = match value {
         None => {
             return Err(RunnerError::new(
@@ -53,7 +56,7 @@ pub fn eval_capture(
                 .iter()
                 .map(|(_, f)| f.clone())
                 .collect::<Vec<_>>();
-            match eval_filters(&filters, &value, variables, false)? {
+            match eval_filters(&filters, &value, variables, false, context)? {
                 None => {
                     return Err(RunnerError::new(
                         capture.query.source_info,
@@ -187,6 +190,7 @@ pub mod tests {
             &variables,
             &http::xml_three_users_http_response(),
             &mut cache,
+            &EvalContext::default(),
         )
         .err()
         .unwrap();
@@ -252,6 +256,7 @@ pub mod tests {
                 &variables,
                 &http::xml_three_users_http_response(),
                 &mut cache,
+                &EvalContext::default(),
             )
             .unwrap(),
             CaptureResult {
@@ -265,7 +270,8 @@ pub mod tests {
                 &duration_capture(),
                 &variables,
                 &http::json_http_response(),
-                &mut cache
+                &mut cache,
+                &EvalContext::default()
             )
             .unwrap(),
             CaptureResult {
@@ -0,0 +1,102 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Computes the delays before each entry, randomized with a jitter (see `--delay-jitter`).
+///
+/// Random values are produced by a [SplitMix64](https://prng.di.unimi.it/splitmix64.c)
+/// generator: it's not suitable for cryptographic usage but it's fast, and a given seed always
+/// produces the same sequence of delays.
+pub struct Delay {
+    jitter: Duration,
+    state: u64,
+}
+
+impl Delay {
+    /// Creates a new delay generator with a `jitter` and an optional `seed`. If there is no `seed`,
+    /// the generator is seeded with the current time.
+    pub fn new(jitter: Duration, seed: Option<u64>) -> Self {
+        let state = seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+        Delay { jitter, state }
+    }
+
+    /// Returns the effective duration to pause for a given `delay`: a random duration between
+    /// `delay` and `delay + jitter`. There is no jitter when `delay` is zero.
+    pub fn next(&mut self, delay: Duration) -> Duration {
+        if delay.is_zero() || self.jitter.is_zero() {
+            return delay;
+        }
+        let jitter_ms = self.jitter.as_millis() as u64;
+        delay + Duration::from_millis(self.next_u64() % (jitter_ms + 1))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_without_jitter() {
+        let mut delay = Delay::new(Duration::ZERO, Some(42));
+        assert_eq!(
+            delay.next(Duration::from_millis(100)),
+            Duration::from_millis(100)
+        );
+        assert_eq!(delay.next(Duration::ZERO), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_delay_with_jitter() {
+        let jitter = Duration::from_millis(50);
+        let mut delay = Delay::new(jitter, Some(42));
+        for _ in 0..1000 {
+            let d = delay.next(Duration::from_millis(100));
+            assert!(d >= Duration::from_millis(100) && d <= Duration::from_millis(150));
+        }
+        // No jitter is added to a zero delay.
+        assert_eq!(delay.next(Duration::ZERO), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_delay_seed_is_reproducible() {
+        let jitter = Duration::from_millis(1000);
+        let mut delay1 = Delay::new(jitter, Some(1234));
+        let mut delay2 = Delay::new(jitter, Some(1234));
+        let delays1 = (0..10)
+            .map(|_| delay1.next(Duration::from_millis(10)))
+            .collect::<Vec<_>>();
+        let delays2 = (0..10)
+            .map(|_| delay2.next(Duration::from_millis(10)))
+            .collect::<Vec<_>>();
+        assert_eq!(delays1, delays2);
+        assert!(delays1.iter().any(|d| *d != delays1[0]));
+    }
+}
@@ -15,15 +15,16 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Entry, SourceInfo};
+use hurl_core::ast::{Assert, Entry, Filter, FilterValue, SourceInfo, Whitespace};
 
 use crate::http;
 use crate::http::{ClientOptions, CurlCmd};
 use crate::runner::cache::BodyCache;
 use crate::runner::error::RunnerError;
+use crate::runner::eval_context::EvalContext;
 use crate::runner::result::{AssertResult, EntryResult};
 use crate::runner::runner_options::RunnerOptions;
-use crate::runner::{request, response, CaptureResult, RunnerErrorKind, VariableSet};
+use crate::runner::{body, request, response, CaptureResult, RunnerErrorKind, VariableSet};
 use crate::util::logger::{Logger, Verbosity};
 use crate::util::term::WriteMode;
 
@@ -62,6 +63,7 @@ pub fn run(
                     source_info,
                     errors: vec![error],
                     compressed,
+                    name: runner_options.name.clone(),
                     ..Default::default()
                 };
             }
@@ -69,7 +71,7 @@ pub fn run(
     }
 
     // Evaluates our source requests given our set of variables
-    let http_request = match request::eval_request(&entry.request, variables, context_dir) {
+    let mut http_request = match request::eval_request(&entry.request, variables, context_dir) {
         Ok(r) => r,
         Err(error) => {
             return EntryResult {
@@ -77,11 +79,36 @@ pub fn run(
                 source_info,
                 errors: vec![error],
                 compressed,
+                name: runner_options.name.clone(),
                 ..Default::default()
             };
         }
     };
 
+    if runner_options.strip_newlines {
+        http_request.body = body::strip_newlines(&http_request.body);
+    }
+    if let (Some(charset), Some(body)) = (&runner_options.request_charset, &entry.request.body) {
+        let body_pos = body.space0.source_info.end;
+        let body_source_info = SourceInfo::new(body_pos, body_pos);
+        http_request.body = match body::encode(&http_request.body, charset, body_source_info) {
+            Ok(body) => body,
+            Err(error) => {
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    errors: vec![error],
+                    compressed,
+                    name: runner_options.name.clone(),
+                    ..Default::default()
+                };
+            }
+        };
+    }
+    if let Some(compression) = runner_options.compress_request {
+        http_request.compress_body(compression);
+    }
+
     let client_options = ClientOptions::from(runner_options, logger.verbosity);
 
     // Experimental features with cookie storage
@@ -105,10 +132,17 @@ pub fn run(
         logger,
     );
 
-    log_request(http_client, &curl_cmd, &http_request, logger);
+    log_request(
+        http_client,
+        &curl_cmd,
+        &http_request,
+        &client_options,
+        logger,
+    );
 
     // Run the HTTP requests (optionally follow redirection)
-    let calls = match http_client.execute_with_redirect(&http_request, &client_options, logger) {
+    let mut calls = match http_client.execute_with_redirect(&http_request, &client_options, logger)
+    {
         Ok(calls) => calls,
         Err(http_error) => {
             let start = entry.request.url.source_info.start;
@@ -122,23 +156,50 @@ pub fn run(
                 errors: vec![error],
                 compressed,
                 curl_cmd,
+                name: runner_options.name.clone(),
                 ..Default::default()
             };
         }
     };
 
+    // Responses bodies are decoded with the charset given by the user, if any.
+    if let Some(charset) = &runner_options.response_charset {
+        for call in calls.iter_mut() {
+            call.response.charset = Some(charset.clone());
+        }
+    }
+
     // Now, we can compute capture and asserts on the last HTTP request/response chains.
     let call = calls.last().unwrap();
     let http_response = &call.response;
+    // The redirection chain is made of the intermediate calls, excluding the final one.
+    let redirects = calls[..calls.len() - 1]
+        .iter()
+        .map(|call| call.request.url.clone())
+        .collect::<Vec<_>>();
 
     // `transfer_duration` represent the network time of calls, not including assert processing.
     let transfer_duration = calls.iter().map(|call| call.timings.total).sum();
 
+    // A request is insecure if its SSL certificates are not verified, whether by `--insecure`,
+    // `--insecure-host` or an `insecure` entry option.
+    let insecure_requests = calls
+        .iter()
+        .filter(|call| client_options.is_insecure(&call.request.url))
+        .count();
+
     // We proceed asserts and captures in this order:
     // 1. first, check implicit assert on status and version. If KO, test is failed
     // 2. then, we compute captures, we might need them in asserts
     // 3. finally, run the remaining asserts
     let mut cache = BodyCache::new();
+    let context = EvalContext {
+        content_type: http_response.headers.content_type(),
+        redirects: &redirects,
+        transfer_duration,
+        timings: Some(&call.timings),
+        ..runner_options.eval_context()
+    };
     let mut asserts = vec![];
 
     if !runner_options.ignore_asserts {
@@ -158,16 +219,36 @@ pub fn run(
                     errors,
                     transfer_duration,
                     compressed,
+                    insecure_requests,
                     curl_cmd,
+                    output: runner_options.output.clone(),
+                    name: runner_options.name.clone(),
                 };
             }
         }
     };
 
+    // A `decode` filter without encoding uses the charset of the response Content-Type header, and
+    // falls back to UTF-8.
+    if has_decode_auto_filter(entry, &runner_options.asserts)
+        && context.content_type.and_then(http::charset).is_none()
+    {
+        logger.warning(
+            "No charset found in response Content-Type header, `decode` filter uses UTF-8",
+        );
+    }
+
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => {
-            match response::eval_captures(response_spec, http_response, &mut cache, variables) {
+            match response::eval_captures(
+                response_spec,
+                &call.request,
+                http_response,
+                &mut cache,
+                variables,
+                &context,
+            ) {
                 Ok(captures) => captures,
                 Err(e) => {
                     return EntryResult {
@@ -179,7 +260,10 @@ pub fn run(
                         errors: vec![e],
                         transfer_duration,
                         compressed,
+                        insecure_requests,
                         curl_cmd,
+                        output: runner_options.output.clone(),
+                        name: runner_options.name.clone(),
                     };
                 }
             }
@@ -199,12 +283,26 @@ pub fn run(
             let mut other_asserts = response::eval_asserts(
                 response_spec,
                 variables,
+                &call.request,
                 http_response,
                 &mut cache,
                 context_dir,
+                runner_options.ignore_trailing_newline,
+                &context,
             );
             asserts.append(&mut other_asserts);
         }
+        let mut injected_asserts = response::eval_injected_asserts(
+            &runner_options.asserts,
+            source_info,
+            variables,
+            http_response,
+            &mut cache,
+            context_dir,
+            runner_options.ignore_trailing_newline,
+            &context,
+        );
+        asserts.append(&mut injected_asserts);
     };
 
     let errors = asserts_to_errors(&asserts);
@@ -218,7 +316,10 @@ pub fn run(
         errors,
         transfer_duration,
         compressed,
+        insecure_requests,
         curl_cmd,
+        output: runner_options.output.clone(),
+        name: runner_options.name.clone(),
     }
 }
 
@@ -248,9 +349,11 @@ impl ClientOptions {
             connect_timeout: runner_options.connect_timeout,
             connects_to: runner_options.connects_to.clone(),
             cookie_input_file: runner_options.cookie_input_file.clone(),
+            dns_servers: runner_options.dns_servers.clone(),
             follow_location: runner_options.follow_location,
             follow_location_trusted: runner_options.follow_location_trusted,
             headers: runner_options.headers.clone(),
+            host_headers: runner_options.host_headers.clone(),
             http_version: runner_options.http_version,
             ip_resolve: runner_options.ip_resolve,
             max_filesize: runner_options.max_filesize,
@@ -264,6 +367,8 @@ impl ClientOptions {
             proxy: runner_options.proxy.clone(),
             no_proxy: runner_options.no_proxy.clone(),
             insecure: runner_options.insecure,
+            insecure_hosts: runner_options.insecure_hosts.clone(),
+            require_http_version: runner_options.require_http_version,
             resolves: runner_options.resolves.clone(),
             ssl_no_revoke: runner_options.ssl_no_revoke,
             timeout: runner_options.timeout,
@@ -284,6 +389,7 @@ fn log_request(
     http_client: &mut http::Client,
     curl_cmd: &CurlCmd,
     request: &http::RequestSpec,
+    options: &ClientOptions,
     logger: &mut Logger,
 ) {
     logger.debug("");
@@ -298,6 +404,15 @@ fn log_request(
     for header in &request.headers {
         logger.debug(&header.to_string());
     }
+    let options_headers = options.headers(&request.url);
+    let headers = request.headers.aggregate_raw_headers(&options_headers);
+    if let Some(implicit) = &request.implicit_content_type {
+        if let Some(explicit) = headers.content_type_override(implicit) {
+            logger.debug(&format!(
+                "Explicit Content-Type <{explicit}> overrides implicit content type <{implicit}>"
+            ));
+        }
+    }
     if !request.querystring.is_empty() {
         logger.debug("[QueryStringParams]");
         for param in &request.querystring {
@@ -328,6 +443,25 @@ fn log_request(
     logger.debug("");
 }
 
+/// Returns `true` if a capture or an assert of this `entry` (or an `injected_asserts`) uses a
+/// `decode` filter without explicit encoding.
+fn has_decode_auto_filter(entry: &Entry, injected_asserts: &[Assert]) -> bool {
+    let is_decode_auto = |filters: &[(Whitespace, Filter)]| {
+        filters
+            .iter()
+            .any(|(_, f)| matches!(f.value, FilterValue::DecodeAuto))
+    };
+    let (captures, asserts) = match &entry.response {
+        Some(response) => (response.captures(), response.asserts()),
+        None => (&[][..], &[][..]),
+    };
+    captures.iter().any(|c| is_decode_auto(&c.filters))
+        || asserts
+            .iter()
+            .chain(injected_asserts)
+            .any(|a| is_decode_auto(&a.filters))
+}
+
 /// Logs the `captures` from the entry HTTP response.
 fn log_captures(captures: &[CaptureResult], logger: &mut Logger) {
     if captures.is_empty() {
@@ -71,6 +71,8 @@ pub enum RunnerErrorKind {
     AssertVersion {
         actual: String,
     },
+    /// A query on the response body has been attempted on a `HEAD` request.
+    BodyOnHeadRequest,
     ExpressionInvalidType {
         value: String,
         expecting: String,
@@ -84,12 +86,26 @@ pub enum RunnerErrorKind {
         path: PathBuf,
         error: String,
     },
+    FilterCustom {
+        name: String,
+        message: String,
+    },
     FilterDecode(String),
+    FilterDecompress,
     FilterInvalidEncoding(String),
     FilterInvalidInput(String),
     FilterInvalidFormatSpecifier(String),
     FilterMissingInput,
+    FilterUnknown(String),
     Http(HttpError),
+    /// The charset of a request body is not valid.
+    InvalidCharset {
+        charset: String,
+    },
+    /// A request body can not be encoded with a charset.
+    InvalidEncoding {
+        charset: String,
+    },
     InvalidJson {
         value: String,
     },
@@ -100,13 +116,30 @@ pub enum RunnerErrorKind {
     },
     NoQueryResult,
     PossibleLoggedSecret,
+    QueryCertificateNotFound,
+    /// The custom query `name` has failed with an error `message`.
+    QueryCustom {
+        name: String,
+        message: String,
+    },
     QueryHeaderNotFound,
     QueryInvalidJsonpathExpression {
         value: String,
     },
+    QueryInvalidJsonpointerExpression {
+        value: String,
+    },
     QueryInvalidXpathEval,
     QueryInvalidXml,
     QueryInvalidJson,
+    /// A metric `name` has not been found in the `Server-Timing` response headers.
+    QueryServerTimingMetricNotFound {
+        name: String,
+    },
+    /// The query `name` is neither built in Hurl nor registered as a custom query.
+    QueryUnknown {
+        name: String,
+    },
     ReadOnlySecret {
         name: String,
     },
@@ -117,6 +150,7 @@ pub enum RunnerErrorKind {
     UnauthorizedFileAccess {
         path: PathBuf,
     },
+    UnsupportedRequestCompression(String),
     /// Only string secrets are supported.
     UnsupportedSecretType(String),
     UnrenderableExpression {
@@ -138,33 +172,51 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::AssertHeaderValueError { .. } => "Assert header value".to_string(),
             RunnerErrorKind::AssertStatus { .. } => "Assert status code".to_string(),
             RunnerErrorKind::AssertVersion { .. } => "Assert HTTP version".to_string(),
+            RunnerErrorKind::BodyOnHeadRequest => "Body on HEAD request".to_string(),
             RunnerErrorKind::ExpressionInvalidType { .. } => "Invalid expression type".to_string(),
             RunnerErrorKind::FileReadAccess { .. } => "File read access".to_string(),
             RunnerErrorKind::FileWriteAccess { .. } => "File write access".to_string(),
+            RunnerErrorKind::FilterCustom { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterDecode { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterDecompress => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidEncoding { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidInput { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidFormatSpecifier { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterMissingInput => "Filter error".to_string(),
+            RunnerErrorKind::FilterUnknown { .. } => "Filter error".to_string(),
             RunnerErrorKind::Http(http_error) => http_error.description(),
+            RunnerErrorKind::InvalidCharset { .. } => "Invalid charset".to_string(),
+            RunnerErrorKind::InvalidEncoding { .. } => "Invalid encoding".to_string(),
             RunnerErrorKind::InvalidJson { .. } => "Invalid JSON".to_string(),
             RunnerErrorKind::InvalidUrl { .. } => "Invalid URL".to_string(),
             RunnerErrorKind::InvalidRegex => "Invalid regex".to_string(),
             RunnerErrorKind::NoQueryResult => "No query result".to_string(),
             RunnerErrorKind::PossibleLoggedSecret => "Invalid redacted secret".to_string(),
+            RunnerErrorKind::QueryCertificateNotFound => "Certificate not found".to_string(),
+            RunnerErrorKind::QueryCustom { .. } => "Query error".to_string(),
             RunnerErrorKind::QueryHeaderNotFound => "Header not found".to_string(),
             RunnerErrorKind::QueryInvalidJson => "Invalid JSON".to_string(),
             RunnerErrorKind::QueryInvalidJsonpathExpression { .. } => {
                 "Invalid JSONPath".to_string()
             }
+            RunnerErrorKind::QueryInvalidJsonpointerExpression { .. } => {
+                "Invalid JSON Pointer".to_string()
+            }
             RunnerErrorKind::QueryInvalidXml => "Invalid XML".to_string(),
             RunnerErrorKind::QueryInvalidXpathEval => "Invalid XPath expression".to_string(),
+            RunnerErrorKind::QueryServerTimingMetricNotFound { .. } => {
+                "Server-Timing metric not found".to_string()
+            }
+            RunnerErrorKind::QueryUnknown { .. } => "Unknown query".to_string(),
             RunnerErrorKind::ReadOnlySecret { .. } => "Readonly secret".to_string(),
             RunnerErrorKind::TemplateVariableNotDefined { .. } => "Undefined variable".to_string(),
             RunnerErrorKind::UnauthorizedFileAccess { .. } => {
                 "Unauthorized file access".to_string()
             }
             RunnerErrorKind::UnrenderableExpression { .. } => "Unrenderable expression".to_string(),
+            RunnerErrorKind::UnsupportedRequestCompression(_) => {
+                "Unsupported request compression".to_string()
+            }
             RunnerErrorKind::UnsupportedSecretType(_) => "Invalid secret type".to_string(),
         }
     }
@@ -214,6 +266,12 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::BodyOnHeadRequest => {
+                let message =
+                    "responses to HEAD requests have no body, this query can not be evaluated";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::ExpressionInvalidType {
                 value, expecting, ..
             } => {
@@ -231,11 +289,21 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterCustom { name, message } => {
+                let message = &format!("{name} filter failed: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterDecode(encoding) => {
                 let message = &format!("value can not be decoded with <{encoding}> encoding");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterDecompress => {
+                let message = "value can not be decompressed (not a gzip, zlib or brotli stream)";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterInvalidEncoding(encoding) => {
                 let message = &format!("<{encoding}> encoding is not supported");
                 let message = error::add_carets(message, self.source_info, content);
@@ -256,11 +324,27 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterUnknown(name) => {
+                let message = &format!("filter <@{name}> is not registered");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::Http(http_error) => {
                 let message = http_error.message();
                 let message = error::add_carets(&message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::InvalidCharset { charset } => {
+                let message = &format!("the charset '{charset}' is not valid");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::InvalidEncoding { charset } => {
+                let message =
+                    &format!("the request body can not be encoded with charset '{charset}'");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::InvalidJson { value } => {
                 let message = &format!("actual value is <{value}>");
                 let message = error::add_carets(message, self.source_info, content);
@@ -286,6 +370,16 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::QueryCertificateNotFound => {
+                let message = "the HTTP response has no SSL certificate (plain HTTP connection)";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::QueryCustom { name, message } => {
+                let message = &format!("{name} query failed: {message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::QueryHeaderNotFound => {
                 let message = "this header has not been found in the response";
                 let message = error::add_carets(message, self.source_info, content);
@@ -301,6 +395,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::QueryInvalidJsonpointerExpression { value } => {
+                let message = &format!("the JSON Pointer '{value}' is not valid");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::QueryInvalidXml => {
                 let message = "the HTTP response is not a valid XML";
                 let message = error::add_carets(message, self.source_info, content);
@@ -311,6 +410,17 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::QueryServerTimingMetricNotFound { name } => {
+                let message =
+                    &format!("the metric '{name}' has not been found in the Server-Timing header");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::QueryUnknown { name } => {
+                let message = &format!("query <@{name}> is not registered");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::ReadOnlySecret { name } => {
                 let message = &format!("secret '{name}' can't be reassigned");
                 let message = error::add_carets(message, self.source_info, content);
@@ -334,6 +444,13 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::UnsupportedRequestCompression(value) => {
+                let message = &format!(
+                    "compression '{value}' is not supported, valid values are gzip and deflate"
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::UnsupportedSecretType(kind) => {
                 let message = &format!("secret must be string, actual value is <{kind}>");
                 let message = error::add_carets(message, self.source_info, content);
@@ -0,0 +1,44 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::time::Duration;
+
+use crate::http;
+use crate::runner::{CustomFilterFn, CustomQueryFn};
+
+/// The settings and response data used to evaluate the queries and filters of an entry.
+///
+/// Contrary to the `BodyCache` that holds data parsed from a given response, this context is
+/// computed from the entry options and its HTTP calls, and borrows from them.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EvalContext<'a> {
+    /// Namespaces bindings `(prefix, URI)` registered in XML documents, in addition to the
+    /// namespaces declared in the documents.
+    pub xpath_namespaces: &'a [(String, String)],
+    /// Custom filters functions, registered by name.
+    pub custom_filters: &'a [(String, CustomFilterFn)],
+    /// Custom queries functions, registered by name.
+    pub custom_queries: &'a [(String, CustomQueryFn)],
+    /// Content-Type header of the response, used by the `decode` filter without encoding.
+    pub content_type: Option<&'a str>,
+    /// Redirected URLs preceding the final response of the entry.
+    pub redirects: &'a [http::Url],
+    /// Network time of all the calls of the entry (including redirections).
+    pub transfer_duration: Duration,
+    /// Timings of the last call of the entry.
+    pub timings: Option<&'a http::Timings>,
+}
@@ -0,0 +1,212 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Decode base 32 encoded string (or UTF-8 bytes) 'value' into bytes.
+/// Padding with `=` is optional.
+pub fn eval_base32_decode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let value = match value {
+        Value::String(value) => Some(value.as_str()),
+        // Bytes are interpreted as an UTF-8 base 32 string.
+        Value::Bytes(value) => std::str::from_utf8(value).ok(),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    match value.and_then(decode) {
+        Some(decoded) => Ok(Some(Value::Bytes(decoded))),
+        None => {
+            let kind = RunnerErrorKind::FilterInvalidInput("Invalid base32 string".to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Decodes a base 32 `value` (RFC 4648 alphabet), returns `None` if `value` is not a valid
+/// base 32 string.
+fn decode(value: &str) -> Option<Vec<u8>> {
+    let data = value.trim_end_matches('=');
+    // If present, padding must complete the last block of 8 characters.
+    if data.len() != value.len() && value.len() % 8 != 0 {
+        return None;
+    }
+    // A last block of 1, 3 or 6 characters can't be produced by any number of bytes.
+    if matches!(data.len() % 8, 1 | 3 | 6) {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(data.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in data.bytes() {
+        let index = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | u32::from(index);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    fn test_decode() {
+        // Test vectors from <https://datatracker.ietf.org/doc/html/rfc4648#section-10>
+        assert_eq!(decode("").unwrap(), b"");
+        assert_eq!(decode("MY======").unwrap(), b"f");
+        assert_eq!(decode("MZXQ====").unwrap(), b"fo");
+        assert_eq!(decode("MZXW6===").unwrap(), b"foo");
+        assert_eq!(decode("MZXW6YQ=").unwrap(), b"foob");
+        assert_eq!(decode("MZXW6YTB").unwrap(), b"fooba");
+        assert_eq!(decode("MZXW6YTBOI======").unwrap(), b"foobar");
+
+        // Without padding
+        assert_eq!(decode("MY").unwrap(), b"f");
+        assert_eq!(decode("MZXQ").unwrap(), b"fo");
+        assert_eq!(decode("MZXW6").unwrap(), b"foo");
+        assert_eq!(decode("MZXW6YQ").unwrap(), b"foob");
+        assert_eq!(decode("MZXW6YTBOI").unwrap(), b"foobar");
+    }
+
+    #[test]
+    fn test_decode_error() {
+        // Invalid characters
+        assert!(decode("mzxw6===").is_none());
+        assert!(decode("MZXW1===").is_none());
+        assert!(decode("MZ=XW6==").is_none());
+        // Invalid length
+        assert!(decode("M").is_none());
+        assert!(decode("MZX").is_none());
+        assert!(decode("MZXW6Y").is_none());
+        // Incomplete padding
+        assert!(decode("MY=").is_none());
+        assert!(decode("MZXW6YQ==").is_none());
+    }
+
+    #[test]
+    fn eval_filter_base32_decode_ok() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base32Decode,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("JBSWY3DPEE======".to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Bytes(b"Hello!".to_vec()));
+    }
+
+    #[test]
+    fn eval_filter_base32_decode_bytes() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base32Decode,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(b"JBSWY3DPEE======".to_vec()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Bytes(b"Hello!".to_vec()));
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes([0xc4, 0xe3, 0xba].to_vec()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("Invalid base32 string".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_base32_decode_ko_invalid_characters() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base32Decode,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("!@#".to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("Invalid base32 string".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_base32_decode_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base32Decode,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
@@ -0,0 +1,140 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// RFC 4648 base 32 alphabet.
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Encode bytes or string 'value' into base 32 encoded string (with padding).
+pub fn eval_base32_encode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(value) => Ok(Some(Value::String(encode(value)))),
+        Value::String(value) => Ok(Some(Value::String(encode(value.as_bytes())))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Encodes `bytes` in base 32, each block of 5 bytes giving 8 characters.
+/// The last block is padded with `=` to a multiple of 8 characters.
+fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    for chunk in bytes.chunks(5) {
+        let mut block = [0u8; 5];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let bits = block.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+        // Number of significant characters for a chunk of 1, 2, 3, 4 or 5 bytes.
+        let count = (chunk.len() * 8).div_ceil(5);
+        for i in 0..8 {
+            if i < count {
+                let index = (bits >> (35 - i * 5)) & 0x1f;
+                encoded.push(ALPHABET[index as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, VariableSet};
+
+    #[test]
+    fn test_encode() {
+        // Test vectors from <https://datatracker.ietf.org/doc/html/rfc4648#section-10>
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "MY======");
+        assert_eq!(encode(b"fo"), "MZXQ====");
+        assert_eq!(encode(b"foo"), "MZXW6===");
+        assert_eq!(encode(b"foob"), "MZXW6YQ=");
+        assert_eq!(encode(b"fooba"), "MZXW6YTB");
+        assert_eq!(encode(b"foobar"), "MZXW6YTBOI======");
+        assert_eq!(encode(&[0xff, 0xff, 0xff, 0xff, 0xff]), "77777777");
+        assert_eq!(encode(&[0x00]), "AA======");
+    }
+
+    #[test]
+    fn eval_filter_base32_encode_ok() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base32Encode,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(b"Hello!".to_vec()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String("JBSWY3DPEE======".to_string())
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("Hello!".to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String("JBSWY3DPEE======".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_base32_encode_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base32Encode,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(42)),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("integer".to_string())
+        );
+    }
+}
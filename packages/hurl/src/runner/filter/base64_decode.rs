@@ -47,6 +47,7 @@ mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -66,6 +67,7 @@ mod tests {
             &Value::String("5L2g5aW95LiW55WM".to_string()),
             &variables,
             false,
+            &EvalContext::default(),
         );
         assert_eq!(ret.unwrap().unwrap(), Value::Bytes(bytes));
     }
@@ -82,6 +84,7 @@ mod tests {
             &Value::String("!@#".to_string()),
             &variables,
             false,
+            &EvalContext::default(),
         );
         assert_eq!(
             ret.unwrap_err().kind,
@@ -102,6 +105,7 @@ mod tests {
             &Value::Bytes([0xc4, 0xe3, 0xba].to_vec()),
             &variables,
             false,
+            &EvalContext::default(),
         );
         assert_eq!(
             ret.unwrap_err().kind,
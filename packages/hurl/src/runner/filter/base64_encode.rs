@@ -41,6 +41,7 @@ mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -55,7 +56,13 @@ mod tests {
             0xe4, 0xbd, 0xa0, 0xe5, 0xa5, 0xbd, 0xe4, 0xb8, 0x96, 0xe7, 0x95, 0x8c,
         ];
 
-        let ret = eval_filter(&filter, &Value::Bytes(bytes), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
         assert_eq!(
             ret.unwrap().unwrap(),
             Value::String("5L2g5aW95LiW55WM".to_string())
@@ -75,6 +82,7 @@ mod tests {
             &Value::String("你好世界".to_string()),
             &variables,
             false,
+            &EvalContext::default(),
         );
         assert_eq!(
             ret.unwrap_err().kind,
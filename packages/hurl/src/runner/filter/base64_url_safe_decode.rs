@@ -0,0 +1,154 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use base64::alphabet::URL_SAFE;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Base 64 URL-safe engine, accepting encoded strings with or without padding.
+const BASE64_URL_SAFE_INDIFFERENT: GeneralPurpose = GeneralPurpose::new(
+    &URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Decode base 64 URL-safe encoded string 'value' into bytes. Padding is optional.
+pub fn eval_base64_url_safe_decode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => match BASE64_URL_SAFE_INDIFFERENT.decode(value) {
+            Ok(decoded) => Ok(Some(Value::Bytes(decoded))),
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterInvalidInput(
+                    "Invalid base64 URL-safe string".to_string(),
+                );
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    fn eval_filter_base64_url_safe_decode_ok() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base64UrlSafeDecode,
+        };
+        let bytes = vec![0xfb, 0xff, 0xbf, 0x68, 0x65, 0x6c, 0x6c, 0x6f];
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("-_-_aGVsbG8".to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Bytes(bytes.clone()));
+
+        // Padding is accepted
+        let ret = eval_filter(
+            &filter,
+            &Value::String("-_-_aGVsbG8=".to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::Bytes(bytes));
+    }
+
+    #[test]
+    fn eval_filter_base64_url_safe_decode_jwt_payload() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base64UrlSafeDecode,
+        };
+        let ret = eval_filter(
+            &filter,
+            &Value::String("eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIn0".to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::Bytes(b"{\"sub\":\"1234567890\",\"name\":\"John Doe\"}".to_vec())
+        );
+    }
+
+    #[test]
+    fn eval_filter_base64_url_safe_decode_ko_invalid_characters() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base64UrlSafeDecode,
+        };
+        for value in ["+_-_aGVsbG8", "-/-_aGVsbG8", "!@#"] {
+            let ret = eval_filter(
+                &filter,
+                &Value::String(value.to_string()),
+                &variables,
+                false,
+                &EvalContext::default(),
+            );
+            assert_eq!(
+                ret.unwrap_err().kind,
+                RunnerErrorKind::FilterInvalidInput("Invalid base64 URL-safe string".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_base64_url_safe_decode_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Base64UrlSafeDecode,
+        };
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes([0xc4, 0xe3, 0xba].to_vec()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("bytes".to_string())
+        );
+    }
+}
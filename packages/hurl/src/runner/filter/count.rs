@@ -42,6 +42,7 @@ mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -63,15 +64,22 @@ mod tests {
                 ]),
                 &variables,
                 false,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
             Value::Number(Number::Integer(3))
         );
 
-        let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
-            .err()
-            .unwrap();
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info,
             SourceInfo::new(Pos::new(1, 1), Pos::new(1, 6))
@@ -0,0 +1,205 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template, Whitespace};
+
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Error returned by a custom filter function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvalError {
+    pub message: String,
+}
+
+impl EvalError {
+    /// Creates a new custom filter error with a `message`.
+    pub fn new(message: &str) -> Self {
+        EvalError {
+            message: message.to_string(),
+        }
+    }
+}
+
+/// A custom filter function, registered by name in [`crate::runner::RunnerOptions`].
+///
+/// The function receives the input value and the evaluated filter arguments.
+pub type CustomFilterFn = fn(Value, &[Value]) -> Result<Value, EvalError>;
+
+/// Evaluates the custom filter `name` with its `args` on an input `value`.
+///
+/// The filter function is looked up by name in the `custom_filters` registry.
+pub fn eval_custom(
+    value: &Value,
+    name: &str,
+    args: &[(Whitespace, Template)],
+    variables: &VariableSet,
+    custom_filters: &[(String, CustomFilterFn)],
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Some((_, filter)) = custom_filters.iter().find(|(n, _)| n == name) else {
+        let kind = RunnerErrorKind::FilterUnknown(name.to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let args = args
+        .iter()
+        .map(|(_, arg)| eval_template(arg, variables).map(Value::String))
+        .collect::<Result<Vec<_>, _>>()?;
+    match filter(value.clone(), &args) {
+        Ok(value) => Ok(Some(value)),
+        Err(error) => {
+            let kind = RunnerErrorKind::FilterCustom {
+                name: name.to_string(),
+                message: error.message,
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use super::{CustomFilterFn, EvalError};
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    fn reverse_string(value: Value, _: &[Value]) -> Result<Value, EvalError> {
+        match value {
+            Value::String(s) => Ok(Value::String(s.chars().rev().collect())),
+            v => Err(EvalError::new(&format!("{} is not a string", v.repr()))),
+        }
+    }
+
+    fn repeat(value: Value, args: &[Value]) -> Result<Value, EvalError> {
+        match (value, args) {
+            (Value::String(s), [Value::String(sep)]) => Ok(Value::String(format!("{s}{sep}{s}"))),
+            _ => Err(EvalError::new("invalid arguments")),
+        }
+    }
+
+    fn custom_filters() -> Vec<(String, CustomFilterFn)> {
+        vec![
+            ("reverseString".to_string(), reverse_string),
+            ("repeat".to_string(), repeat),
+        ]
+    }
+
+    fn custom_filter(name: &str, args: &[&str]) -> Filter {
+        let args = args
+            .iter()
+            .map(|arg| {
+                (
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: arg.to_string(),
+                            encoded: arg.to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                )
+            })
+            .collect();
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Custom {
+                name: name.to_string(),
+                args,
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_custom() {
+        let variables = VariableSet::new();
+        let custom_filters = custom_filters();
+        let context = EvalContext {
+            custom_filters: &custom_filters,
+            ..Default::default()
+        };
+        let filter = custom_filter("reverseString", &[]);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("Hello".to_string()),
+                &variables,
+                false,
+                &context
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("olleH".to_string())
+        );
+
+        let filter = custom_filter("repeat", &["-"]);
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("Hello".to_string()),
+                &variables,
+                false,
+                &context
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Hello-Hello".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_custom_error() {
+        let variables = VariableSet::new();
+        let custom_filters = custom_filters();
+        let context = EvalContext {
+            custom_filters: &custom_filters,
+            ..Default::default()
+        };
+        let filter = custom_filter("reverseString", &[]);
+        let error =
+            eval_filter(&filter, &Value::Bool(true), &variables, false, &context).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterCustom {
+                name: "reverseString".to_string(),
+                message: "boolean <true> is not a string".to_string(),
+            }
+        );
+
+        let filter = custom_filter("toUpper", &[]);
+        let error = eval_filter(
+            &filter,
+            &Value::String("Hello".to_string()),
+            &variables,
+            false,
+            &context,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterUnknown("toUpper".to_string())
+        );
+    }
+}
@@ -46,6 +46,7 @@ mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -63,6 +64,7 @@ mod tests {
                 &Value::Date(now),
                 &variables,
                 false,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -79,6 +81,7 @@ mod tests {
                 &Value::Date(now_plus_30hours),
                 &variables,
                 false,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -93,6 +96,7 @@ mod tests {
                 &Value::Date(now_plus_30hours),
                 &variables,
                 false,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
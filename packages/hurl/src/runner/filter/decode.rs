@@ -18,6 +18,7 @@
 use encoding::DecoderTrap;
 use hurl_core::ast::{SourceInfo, Template};
 
+use crate::http;
 use crate::runner::template::eval_template;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
@@ -30,6 +31,31 @@ pub fn eval_decode(
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     let encoding = eval_template(encoding, variables)?;
+    decode(value, encoding, source_info, assert)
+}
+
+/// Decode bytes `value` to string using the charset of the response `content_type`.
+///
+/// If there is no charset in the response `content_type`, UTF-8 is used. In this case, a warning
+/// is logged once per entry before evaluating its captures and asserts (see `runner::entry::run`).
+pub fn eval_decode_auto(
+    value: &Value,
+    content_type: Option<&str>,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let encoding = content_type
+        .and_then(http::charset)
+        .unwrap_or_else(|| "utf-8".to_string());
+    decode(value, encoding, source_info, assert)
+}
+
+fn decode(
+    value: &Value,
+    encoding: String,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
     match value {
         Value::Bytes(value) => {
             match encoding::label::encoding_from_whatwg_label(encoding.as_str()) {
@@ -59,6 +85,7 @@ mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -92,15 +119,76 @@ mod tests {
         let bytes = vec![
             0xe4, 0xbd, 0xa0, 0xe5, 0xa5, 0xbd, 0xe4, 0xb8, 0x96, 0xe7, 0x95, 0x8c,
         ];
-        let ret = eval_filter(&filter, &Value::Bytes(bytes), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
         assert_eq!(ret.unwrap().unwrap(), Value::String("你好世界".to_string()));
 
         let filter = new_decode_filter("gb2312");
         let bytes = vec![0xc4, 0xe3, 0xba, 0xc3, 0xca, 0xc0, 0xbd, 0xe7];
-        let ret = eval_filter(&filter, &Value::Bytes(bytes), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
         assert_eq!(ret.unwrap().unwrap(), Value::String("你好世界".to_string()));
     }
 
+    #[test]
+    fn eval_filter_decode_auto() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+            value: FilterValue::DecodeAuto,
+        };
+
+        // "café" encoded in ISO-8859-1
+        let bytes = vec![0x63, 0x61, 0x66, 0xe9];
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes.clone()),
+            &variables,
+            false,
+            &EvalContext {
+                content_type: Some("text/html; charset=ISO-8859-1"),
+                ..Default::default()
+            },
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::String("café".to_string()));
+
+        // Without charset, UTF-8 is used.
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes),
+            &variables,
+            false,
+            &EvalContext {
+                content_type: Some("text/html"),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterDecode("utf-8".to_string()),
+        );
+
+        let bytes = vec![0x63, 0x61, 0x66, 0xc3, 0xa9];
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::String("café".to_string()));
+    }
+
     #[test]
     fn eval_filter_decode_ko_unknown_encoding() {
         let variables = VariableSet::new();
@@ -108,7 +196,13 @@ mod tests {
         let filter = new_decode_filter("xxx");
         let bytes = vec![];
 
-        let ret = eval_filter(&filter, &Value::Bytes(bytes), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
 
         assert_eq!(
             ret.unwrap_err().kind,
@@ -122,7 +216,13 @@ mod tests {
 
         let filter = new_decode_filter("gb2312");
         let bytes = vec![0xc4, 0x00];
-        let ret = eval_filter(&filter, &Value::Bytes(bytes), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
         assert_eq!(
             ret.unwrap_err().kind,
             RunnerErrorKind::FilterDecode("gb2312".to_string()),
@@ -139,6 +239,7 @@ mod tests {
             &Value::String("café".to_string()),
             &variables,
             false,
+            &EvalContext::default(),
         );
         assert_eq!(
             ret.unwrap_err().kind,
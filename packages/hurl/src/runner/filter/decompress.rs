@@ -0,0 +1,144 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::http::ContentEncoding;
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Decompresses bytes `value`, detecting gzip and zlib streams by their magic bytes and falling
+/// back to brotli (which has no magic bytes).
+pub fn eval_decompress(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(value) => {
+            let encoding = detect_encoding(value);
+            match encoding.decode(value) {
+                Ok(decompressed) => Ok(Some(Value::Bytes(decompressed))),
+                Err(_) => {
+                    let kind = RunnerErrorKind::FilterDecompress;
+                    Err(RunnerError::new(source_info, kind, assert))
+                }
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the compression format of `data`.
+fn detect_encoding(data: &[u8]) -> ContentEncoding {
+    match data {
+        [0x1f, 0x8b, ..] => ContentEncoding::Gzip,
+        // zlib header (RFC 1950): deflate compression method with a valid header checksum.
+        [cmf, flg, ..] if cmf & 0x0f == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0 => {
+            ContentEncoding::Deflate
+        }
+        _ => ContentEncoding::Brotli,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    fn eval_filter_decompress_ok() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Decompress,
+        };
+        let gzip = vec![
+            0x1f, 0x8b, 0x08, 0x08, 0xa7, 0x52, 0x85, 0x5f, 0x00, 0x03, 0x64, 0x61, 0x74, 0x61,
+            0x2e, 0x74, 0x78, 0x74, 0x00, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0x08, 0xcf, 0x2f,
+            0xca, 0x49, 0x51, 0x04, 0x00, 0xa3, 0x1c, 0x29, 0x1c, 0x0c, 0x00, 0x00, 0x00,
+        ];
+        let zlib = vec![
+            0x78, 0x9c, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0x08, 0xcf, 0x2f, 0xca, 0x49, 0x51,
+            0x04, 0x00, 0x1c, 0x49, 0x04, 0x3e,
+        ];
+        let brotli = vec![
+            0x21, 0x2c, 0x00, 0x04, 0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x57, 0x6f, 0x72, 0x6c,
+            0x64, 0x21, 0x03,
+        ];
+        for data in [gzip, zlib, brotli] {
+            let ret = eval_filter(
+                &filter,
+                &Value::Bytes(data),
+                &variables,
+                false,
+                &EvalContext::default(),
+            );
+            assert_eq!(
+                ret.unwrap().unwrap(),
+                Value::Bytes(b"Hello World!".to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_decompress_ko_invalid_stream() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Decompress,
+        };
+        // Truncated gzip and uncompressed data
+        for data in [vec![0x1f, 0x8b, 0x08], b"Hello World!".to_vec()] {
+            let ret = eval_filter(
+                &filter,
+                &Value::Bytes(data),
+                &variables,
+                false,
+                &EvalContext::default(),
+            );
+            assert_eq!(ret.unwrap_err().kind, RunnerErrorKind::FilterDecompress);
+        }
+    }
+
+    #[test]
+    fn eval_filter_decompress_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Decompress,
+        };
+        let ret = eval_filter(
+            &filter,
+            &Value::String("Hello World!".to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
@@ -17,40 +17,63 @@
  */
 use hurl_core::ast::{Filter, FilterValue};
 
+use crate::runner::eval_context::EvalContext;
+use crate::runner::filter::base32_decode::eval_base32_decode;
+use crate::runner::filter::base32_encode::eval_base32_encode;
 use crate::runner::filter::base64_decode::eval_base64_decode;
 use crate::runner::filter::base64_encode::eval_base64_encode;
+use crate::runner::filter::base64_url_safe_decode::eval_base64_url_safe_decode;
+use crate::runner::filter::base64_url_safe_encode::eval_base64_url_safe_encode;
 use crate::runner::filter::count::eval_count;
+use crate::runner::filter::custom::eval_custom;
 use crate::runner::filter::days_after_now::eval_days_after_now;
 use crate::runner::filter::days_before_now::eval_days_before_now;
-use crate::runner::filter::decode::eval_decode;
+use crate::runner::filter::decode::{eval_decode, eval_decode_auto};
+use crate::runner::filter::decompress::eval_decompress;
 use crate::runner::filter::format::eval_format;
+use crate::runner::filter::hex_decode::eval_hex_decode;
+use crate::runner::filter::hex_encode::eval_hex_encode;
 use crate::runner::filter::html_escape::eval_html_escape;
 use crate::runner::filter::html_unescape::eval_html_unescape;
+use crate::runner::filter::json_escape::eval_json_escape;
+use crate::runner::filter::json_unescape::eval_json_unescape;
+use crate::runner::filter::jsonl::eval_jsonl;
 use crate::runner::filter::jsonpath::eval_jsonpath;
+use crate::runner::filter::jsonpointer::eval_jsonpointer;
+use crate::runner::filter::md5::eval_md5;
 use crate::runner::filter::nth::eval_nth;
+use crate::runner::filter::path_encode::eval_path_encode;
 use crate::runner::filter::regex::eval_regex;
 use crate::runner::filter::replace::eval_replace;
+use crate::runner::filter::sha1::eval_sha1;
+use crate::runner::filter::sha256::eval_sha256;
 use crate::runner::filter::split::eval_split;
 use crate::runner::filter::to_date::eval_to_date;
 use crate::runner::filter::to_float::eval_to_float;
 use crate::runner::filter::to_int::eval_to_int;
+use crate::runner::filter::to_string::eval_to_string;
 use crate::runner::filter::url_decode::eval_url_decode;
 use crate::runner::filter::url_encode::eval_url_encode;
+use crate::runner::filter::xml_escape::eval_xml_escape;
 use crate::runner::filter::xpath::eval_xpath;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Apply successive `filter` to an input `value`.
 /// Specify whether they are executed  `in_assert` or not.
+///
+/// Filters that depend on the response (like `decode` without explicit encoding) and filters that
+/// are not built in Hurl use the evaluation `context`.
 pub fn eval_filters(
     filters: &[Filter],
     value: &Value,
     variables: &VariableSet,
     in_assert: bool,
+    context: &EvalContext,
 ) -> Result<Option<Value>, RunnerError> {
     let mut value = Some(value.clone());
     for filter in filters {
         value = if let Some(value) = value {
-            eval_filter(filter, &value, variables, in_assert)?
+            eval_filter(filter, &value, variables, in_assert, context)?
         } else {
             return Err(RunnerError::new(
                 filter.source_info,
@@ -62,34 +85,66 @@ pub fn eval_filters(
     Ok(value)
 }
 
-/// Evaluates a `filter` with an input `value`, given a set of `variables`.
+/// Evaluates a `filter` with an input `value`, given a set of `variables` and an evaluation `context`.
 pub fn eval_filter(
     filter: &Filter,
     value: &Value,
     variables: &VariableSet,
     in_assert: bool,
+    context: &EvalContext,
 ) -> Result<Option<Value>, RunnerError> {
     match &filter.value {
+        FilterValue::Base32Decode => eval_base32_decode(value, filter.source_info, in_assert),
+        FilterValue::Base32Encode => eval_base32_encode(value, filter.source_info, in_assert),
         FilterValue::Base64Decode => eval_base64_decode(value, filter.source_info, in_assert),
         FilterValue::Base64Encode => eval_base64_encode(value, filter.source_info, in_assert),
+        FilterValue::Base64UrlSafeDecode => {
+            eval_base64_url_safe_decode(value, filter.source_info, in_assert)
+        }
+        FilterValue::Base64UrlSafeEncode => {
+            eval_base64_url_safe_encode(value, filter.source_info, in_assert)
+        }
         FilterValue::Count => eval_count(value, filter.source_info, in_assert),
+        FilterValue::Custom { name, args } => eval_custom(
+            value,
+            name,
+            args,
+            variables,
+            context.custom_filters,
+            filter.source_info,
+            in_assert,
+        ),
         FilterValue::DaysAfterNow => eval_days_after_now(value, filter.source_info, in_assert),
         FilterValue::DaysBeforeNow => eval_days_before_now(value, filter.source_info, in_assert),
         FilterValue::Decode { encoding, .. } => {
             eval_decode(value, encoding, variables, filter.source_info, in_assert)
         }
+        FilterValue::DecodeAuto => {
+            eval_decode_auto(value, context.content_type, filter.source_info, in_assert)
+        }
+        FilterValue::Decompress => eval_decompress(value, filter.source_info, in_assert),
         FilterValue::Format { fmt, .. } => {
             eval_format(value, fmt, variables, filter.source_info, in_assert)
         }
+        FilterValue::HexDecode => eval_hex_decode(value, filter.source_info, in_assert),
+        FilterValue::HexEncode => eval_hex_encode(value, filter.source_info, in_assert),
         FilterValue::HtmlEscape => eval_html_escape(value, filter.source_info, in_assert),
         FilterValue::HtmlUnescape => eval_html_unescape(value, filter.source_info, in_assert),
+        FilterValue::JsonEscape => eval_json_escape(value, filter.source_info, in_assert),
+        FilterValue::Jsonl => eval_jsonl(value, filter.source_info, in_assert),
         FilterValue::JsonPath { expr, .. } => {
             eval_jsonpath(value, expr, variables, filter.source_info, in_assert)
         }
+        FilterValue::JsonPointer { expr, .. } => {
+            eval_jsonpointer(value, expr, variables, filter.source_info, in_assert)
+        }
+        FilterValue::JsonUnescape => eval_json_unescape(value, filter.source_info, in_assert),
+        FilterValue::Md5 => eval_md5(value, filter.source_info, in_assert),
+        FilterValue::Nth { n, .. } => eval_nth(value, filter.source_info, in_assert, n.as_u64()),
+        FilterValue::PathEncode => eval_path_encode(value, filter.source_info, in_assert),
         FilterValue::Regex {
             value: regex_value, ..
         } => eval_regex(value, regex_value, variables, filter.source_info, in_assert),
-        FilterValue::Nth { n, .. } => eval_nth(value, filter.source_info, in_assert, n.as_u64()),
         FilterValue::Replace {
             old_value,
             new_value,
@@ -102,6 +157,8 @@ pub fn eval_filter(
             old_value,
             new_value,
         ),
+        FilterValue::Sha1 => eval_sha1(value, filter.source_info, in_assert),
+        FilterValue::Sha256 => eval_sha256(value, filter.source_info, in_assert),
         FilterValue::Split { sep, .. } => {
             eval_split(value, variables, filter.source_info, in_assert, sep)
         }
@@ -110,11 +167,18 @@ pub fn eval_filter(
         }
         FilterValue::ToFloat => eval_to_float(value, filter.source_info, in_assert),
         FilterValue::ToInt => eval_to_int(value, filter.source_info, in_assert),
+        FilterValue::ToString => eval_to_string(value, filter.source_info, in_assert),
         FilterValue::UrlDecode => eval_url_decode(value, filter.source_info, in_assert),
         FilterValue::UrlEncode => eval_url_encode(value, filter.source_info, in_assert),
-        FilterValue::XPath { expr, .. } => {
-            eval_xpath(value, expr, variables, filter.source_info, in_assert)
-        }
+        FilterValue::XmlEscape => eval_xml_escape(value, filter.source_info, in_assert),
+        FilterValue::XPath { expr, .. } => eval_xpath(
+            value,
+            expr,
+            variables,
+            context.xpath_namespaces,
+            filter.source_info,
+            in_assert,
+        ),
     }
 }
 
@@ -123,6 +187,7 @@ mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filters;
     use crate::runner::{Number, Value, VariableSet};
 
@@ -143,6 +208,7 @@ mod tests {
                 ]),
                 &variables,
                 false,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
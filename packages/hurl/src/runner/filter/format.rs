@@ -58,6 +58,7 @@ mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -89,7 +90,13 @@ mod tests {
 
         let date = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
         let filter = new_format_filter("%m/%d/%Y");
-        let ret = eval_filter(&filter, &Value::Date(date), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::Date(date),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
         assert_eq!(
             ret.unwrap().unwrap(),
             Value::String("01/01/2025".to_string())
@@ -106,6 +113,7 @@ mod tests {
             &Value::String("01/01/2025".to_string()),
             &variables,
             false,
+            &EvalContext::default(),
         );
         assert_eq!(
             ret.unwrap_err().kind,
@@ -119,7 +127,13 @@ mod tests {
 
         let date = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
         let filter = new_format_filter("%%%");
-        let ret = eval_filter(&filter, &Value::Date(date), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::Date(date),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
         assert_eq!(
             ret.unwrap_err().kind,
             RunnerErrorKind::FilterInvalidFormatSpecifier("%%%".to_string())
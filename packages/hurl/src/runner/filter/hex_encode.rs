@@ -0,0 +1,132 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Encode bytes 'value' into a lowercase hexadecimal string.
+pub fn eval_hex_encode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(value) => Ok(Some(Value::String(hex::encode(value)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    fn eval_filter_hex_encode_ok() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::HexEncode,
+        };
+        let bytes = vec![0x00, 0x01, 0x7f, 0x80, 0xde, 0xad, 0xbe, 0xef, 0xff];
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(bytes),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String("00017f80deadbeefff".to_string())
+        );
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(vec![]),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(ret.unwrap().unwrap(), Value::String(String::new()));
+    }
+
+    #[test]
+    fn eval_filter_hex_encode_decode_round_trip() {
+        let variables = VariableSet::new();
+        let encode = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::HexEncode,
+        };
+        let decode = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::HexDecode,
+        };
+        let bytes = (0..=255).collect::<Vec<u8>>();
+
+        let encoded = eval_filter(
+            &encode,
+            &Value::Bytes(bytes.clone()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .unwrap()
+        .unwrap();
+        let decoded = eval_filter(
+            &decode,
+            &encoded,
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(decoded, Value::Bytes(bytes));
+    }
+
+    #[test]
+    fn eval_filter_hex_encode_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::HexEncode,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::String("deadbeef".to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
@@ -43,6 +43,7 @@ pub mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
 
@@ -69,7 +70,8 @@ pub mod tests {
                     &filter,
                     &Value::String(input.to_string()),
                     &variables,
-                    false
+                    false,
+                    &EvalContext::default()
                 )
                 .unwrap()
                 .unwrap(),
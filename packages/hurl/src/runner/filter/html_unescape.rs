@@ -44,6 +44,7 @@ mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
 
@@ -63,6 +64,19 @@ mod tests {
                 "string with double quote: &quot;baz&quot;",
                 "string with double quote: \"baz\"",
             ),
+            ("&#123;&#125;", "{}"),
+            ("&#x7B;&#X7d;", "{}"),
+            ("&#0000065;&#x000041;", "AA"),
+            ("&#65&#x41", "AA"),
+            ("&#x1F600;", "\u{1F600}"),
+            ("&#xD800;", "\u{FFFD}"),
+            ("&#x110000;", "\u{FFFD}"),
+            ("&#128;", "\u{20AC}"),
+            ("&apos;&nbsp;&copy;&euro;&hellip;", "'\u{A0}©€…"),
+            ("&Alpha;&beta;&rarr;&hearts;", "Αβ→♥"),
+            ("&NotNestedGreaterGreater;", "\u{2AA2}\u{338}"),
+            ("&copy 2024", "© 2024"),
+            ("&unknown; &amp", "&unknown; &"),
         ];
         for (input, output) in tests.iter() {
             assert_eq!(
@@ -70,7 +84,8 @@ mod tests {
                     &filter,
                     &Value::String(input.to_string()),
                     &variables,
-                    false
+                    false,
+                    &EvalContext::default()
                 )
                 .unwrap()
                 .unwrap(),
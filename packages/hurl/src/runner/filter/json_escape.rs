@@ -0,0 +1,107 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Escapes the string `value` so it can be embedded in a JSON string: quotes, backslashes and
+/// control characters are escaped. The escaped string is not surrounded by quotes.
+pub fn eval_json_escape(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            // Serializing a string can't fail, and always gives a quoted JSON string.
+            let escaped = serde_json::to_string(value).unwrap();
+            let escaped = &escaped[1..escaped.len() - 1];
+            Ok(Some(Value::String(escaped.to_string())))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    fn eval_filter_json_escape() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonEscape,
+        };
+
+        let tests = [
+            ("foo", "foo"),
+            ("", ""),
+            (r#"say "hello""#, r#"say \"hello\""#),
+            (r"C:\Users\bob", r"C:\\Users\\bob"),
+            ("line1\nline2\r\n\ttab", r"line1\nline2\r\n\ttab"),
+            ("\u{0}\u{1}\u{1f}", r"\u0000\u0001\u001f"),
+            ("\u{8}\u{c}", r"\b\f"),
+            ("café / 🦀", "café / 🦀"),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    false,
+                    &EvalContext::default()
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_json_escape_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonEscape,
+        };
+
+        let ret = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
@@ -0,0 +1,133 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Unescapes the JSON escaped string `value` (the content of a JSON string, without surrounding
+/// quotes): escape sequences like `\"`, `\\`, `\n` or `\u00e9` are converted to their characters.
+pub fn eval_json_unescape(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            let quoted = format!("\"{value}\"");
+            match serde_json::from_str::<String>(&quoted) {
+                Ok(unescaped) => Ok(Some(Value::String(unescaped))),
+                Err(_) => {
+                    let kind = RunnerErrorKind::FilterInvalidInput(
+                        "Invalid JSON escaped string".to_string(),
+                    );
+                    Err(RunnerError::new(source_info, kind, assert))
+                }
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    fn eval_filter_json_unescape() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonUnescape,
+        };
+        let tests = [
+            ("foo", "foo"),
+            ("", ""),
+            (r#"say \"hello\""#, r#"say "hello""#),
+            (r"C:\\Users\\bob", r"C:\Users\bob"),
+            (r"line1\nline2\r\n\ttab", "line1\nline2\r\n\ttab"),
+            (r"\u0000\u0001\u001f", "\u{0}\u{1}\u{1f}"),
+            (r"\b\f\/", "\u{8}\u{c}/"),
+            (r"caf\u00e9 \ud83e\udd80", "café 🦀"),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    false,
+                    &EvalContext::default()
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_json_unescape_ko_invalid_string() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonUnescape,
+        };
+        for value in [r#"say "hello""#, r"\x", r"\u00", "trailing\\", "new\nline"] {
+            let ret = eval_filter(
+                &filter,
+                &Value::String(value.to_string()),
+                &variables,
+                false,
+                &EvalContext::default(),
+            );
+            assert_eq!(
+                ret.unwrap_err().kind,
+                RunnerErrorKind::FilterInvalidInput("Invalid JSON escaped string".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_json_unescape_ko_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonUnescape,
+        };
+        let ret = eval_filter(
+            &filter,
+            &Value::Bytes(vec![0x01]),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
+        assert_eq!(
+            ret.unwrap_err().kind,
+            RunnerErrorKind::FilterInvalidInput("bytes".to_string())
+        );
+    }
+}
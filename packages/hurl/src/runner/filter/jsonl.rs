@@ -0,0 +1,128 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Parses the newline-delimited JSON string `value` (see <https://jsonlines.org>) to a list of
+/// JSON values, one per line. Empty lines (like a trailing newline) are ignored.
+pub fn eval_jsonl(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(s) => {
+            let mut values = vec![];
+            for line in s.lines().filter(|line| !line.trim().is_empty()) {
+                let Ok(json) = serde_json::from_str::<serde_json::Value>(line) else {
+                    let kind = RunnerErrorKind::QueryInvalidJson;
+                    return Err(RunnerError::new(source_info, kind, false));
+                };
+                values.push(Value::from_json(&json));
+            }
+            Ok(Some(Value::List(values)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.repr());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn jsonl_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 6)),
+            value: FilterValue::Jsonl,
+        }
+    }
+
+    #[test]
+    fn eval_filter_jsonl() {
+        let variables = VariableSet::new();
+        let value = Value::String("{\"id\":\"a\"}\n\n{\"id\":\"b\"}\r\n42\n".to_string());
+        assert_eq!(
+            eval_filter(
+                &jsonl_filter(),
+                &value,
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::Object(vec![("id".to_string(), Value::String("a".to_string()))]),
+                Value::Object(vec![("id".to_string(), Value::String("b".to_string()))]),
+                Value::Number(Number::Integer(42)),
+            ])
+        );
+
+        let value = Value::String(String::new());
+        assert_eq!(
+            eval_filter(
+                &jsonl_filter(),
+                &value,
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![])
+        );
+    }
+
+    #[test]
+    fn eval_filter_jsonl_error() {
+        let variables = VariableSet::new();
+        let value = Value::String("{\"id\":\"a\"}\n{\"id\":\n".to_string());
+        let error = eval_filter(
+            &jsonl_filter(),
+            &value,
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
+
+        let value = Value::Bool(true);
+        let error = eval_filter(
+            &jsonl_filter(),
+            &value,
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean <true>".to_string())
+        );
+    }
+}
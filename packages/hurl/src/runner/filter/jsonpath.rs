@@ -18,6 +18,7 @@
 use hurl_core::ast::{SourceInfo, Template};
 
 use crate::jsonpath;
+use crate::jsonpath::JsonpathResult;
 use crate::runner::template::eval_template;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
@@ -43,6 +44,10 @@ pub fn eval_jsonpath(
             };
             eval_jsonpath_json(&json, expr, variables)
         }
+        // Values from a previous filter (like `jsonl`) can be queried directly.
+        Value::List(_) | Value::Object(_) => {
+            eval_jsonpath_json(&value.to_json(&[]), expr, variables)
+        }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
             Err(RunnerError::new(source_info, kind, assert))
@@ -55,6 +60,17 @@ pub fn eval_jsonpath_json(
     expr: &Template,
     variables: &VariableSet,
 ) -> Result<Option<Value>, RunnerError> {
+    let results = eval_jsonpath_result(json, expr, variables)?;
+    Ok(results.map(jsonpath_value))
+}
+
+/// Evaluates a JSONPath expression `expr` against a `json` value, keeping the shape of the
+/// JSONPath result (a single entry for a definite path, a collection for an indefinite path).
+pub fn eval_jsonpath_result(
+    json: &serde_json::Value,
+    expr: &Template,
+    variables: &VariableSet,
+) -> Result<Option<JsonpathResult>, RunnerError> {
     let expr_str = eval_template(expr, variables)?;
     let expr_source_info = expr.source_info;
     let jsonpath_query = match jsonpath::parse(&expr_str) {
@@ -65,13 +81,14 @@ pub fn eval_jsonpath_json(
         }
     };
 
-    let results = jsonpath_query.eval(json);
-    match results {
-        None => Ok(None),
-        Some(jsonpath::JsonpathResult::SingleEntry(value)) => Ok(Some(Value::from_json(&value))),
-        Some(jsonpath::JsonpathResult::Collection(values)) => {
-            Ok(Some(Value::from_json(&serde_json::Value::Array(values))))
-        }
+    Ok(jsonpath_query.eval(json))
+}
+
+/// Converts a JSONPath `result` to a [`Value`], a collection being converted to a list.
+pub fn jsonpath_value(result: JsonpathResult) -> Value {
+    match result {
+        JsonpathResult::SingleEntry(value) => Value::from_json(&value),
+        JsonpathResult::Collection(values) => Value::from_json(&serde_json::Value::Array(values)),
     }
 }
 
@@ -80,8 +97,10 @@ mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
 
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
-    use crate::runner::{Value, VariableSet};
+    use crate::runner::{Number, Value, VariableSet};
 
     #[test]
     fn eval_filter_jsonpath() {
@@ -109,11 +128,105 @@ mod tests {
                 &filter,
                 &Value::String(r#"{"message":"Hello"}"#.to_string()),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
             Value::String("Hello".to_string())
         );
     }
+
+    #[test]
+    fn eval_jsonpath_json_collection() {
+        let variables = VariableSet::new();
+        let expr = Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: "$.items[*].id".to_string(),
+                encoded: "$.items[*].id".to_string(),
+            }],
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+
+        let json = serde_json::json!({ "items": [{ "id": 3 }, { "id": 1 }, { "id": 2 }] });
+        assert_eq!(
+            eval_jsonpath_json(&json, &expr, &variables).unwrap(),
+            Some(Value::List(vec![
+                Value::Number(Number::Integer(3)),
+                Value::Number(Number::Integer(1)),
+                Value::Number(Number::Integer(2)),
+            ]))
+        );
+
+        // A single-element collection is not collapsed to a scalar.
+        let json = serde_json::json!({ "items": [{ "id": 1 }] });
+        assert_eq!(
+            eval_jsonpath_json(&json, &expr, &variables).unwrap(),
+            Some(Value::List(vec![Value::Number(Number::Integer(1))]))
+        );
+
+        // An empty collection is an empty list, not a missing value.
+        let json = serde_json::json!({ "items": [] });
+        assert_eq!(
+            eval_jsonpath_json(&json, &expr, &variables).unwrap(),
+            Some(Value::List(vec![]))
+        );
+    }
+
+    #[test]
+    fn eval_filter_jsonpath_on_object() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPath {
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.id".to_string(),
+                        encoded: "$.id".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let value = Value::Object(vec![("id".to_string(), Value::String("abc".to_string()))]);
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false, &EvalContext::default())
+                .unwrap()
+                .unwrap(),
+            Value::String("abc".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_count_on_collection() {
+        let variables = VariableSet::new();
+        let json = serde_json::json!({ "items": [{ "id": 1 }] });
+        let expr = Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: "$.items[*].id".to_string(),
+                encoded: "$.items[*].id".to_string(),
+            }],
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let value = eval_jsonpath_json(&json, &expr, &variables)
+            .unwrap()
+            .unwrap();
+        let count = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Count,
+        };
+        assert_eq!(
+            eval_filter(&count, &value, &variables, false, &EvalContext::default())
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(1))
+        );
+    }
 }
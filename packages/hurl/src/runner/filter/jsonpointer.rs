@@ -0,0 +1,158 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::jsonpointer;
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Evaluates a JSON Pointer expression `expr` against a `value`.
+pub fn eval_jsonpointer(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(text) => {
+            let json = match serde_json::from_str(text) {
+                Err(_) => {
+                    return Err(RunnerError::new(
+                        source_info,
+                        RunnerErrorKind::QueryInvalidJson,
+                        false,
+                    ));
+                }
+                Ok(v) => v,
+            };
+            eval_jsonpointer_json(&json, expr, variables)
+        }
+        Value::List(_) | Value::Object(_) => {
+            eval_jsonpointer_json(&value.to_json(&[]), expr, variables)
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Evaluates a JSON Pointer expression `expr` against a `json` document.
+pub fn eval_jsonpointer_json(
+    json: &serde_json::Value,
+    expr: &Template,
+    variables: &VariableSet,
+) -> Result<Option<Value>, RunnerError> {
+    let expr_str = eval_template(expr, variables)?;
+    let Some(pointer) = jsonpointer::parse(&expr_str) else {
+        let kind = RunnerErrorKind::QueryInvalidJsonpointerExpression { value: expr_str };
+        return Err(RunnerError::new(expr.source_info, kind, false));
+    };
+    Ok(pointer.eval(json).map(Value::from_json))
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    fn jsonpointer_filter(expr: &str) -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPointer {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: expr.to_string(),
+                        encoded: expr.to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 13 + expr.len())),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn eval_filter_jsonpointer() {
+        let variables = VariableSet::new();
+        let value = Value::String(r#"{"a/b": [1, {"m~n": "foo"}], "c": null}"#.to_string());
+
+        let filter = jsonpointer_filter("/a~1b/0");
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false, &EvalContext::default()).unwrap(),
+            Some(Value::Number(Number::Integer(1)))
+        );
+
+        let filter = jsonpointer_filter("/a~1b/1/m~0n");
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false, &EvalContext::default()).unwrap(),
+            Some(Value::String("foo".to_string()))
+        );
+
+        let filter = jsonpointer_filter("/c");
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false, &EvalContext::default()).unwrap(),
+            Some(Value::Null)
+        );
+
+        let filter = jsonpointer_filter("/a~1b/2");
+        assert_eq!(
+            eval_filter(&filter, &value, &variables, false, &EvalContext::default()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn eval_filter_jsonpointer_error() {
+        let variables = VariableSet::new();
+        let value = Value::String(r#"{"a": 1}"#.to_string());
+
+        let filter = jsonpointer_filter("a");
+        let error =
+            eval_filter(&filter, &value, &variables, false, &EvalContext::default()).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidJsonpointerExpression {
+                value: "a".to_string()
+            }
+        );
+
+        let filter = jsonpointer_filter("/a");
+        let value = Value::String("{".to_string());
+        let error =
+            eval_filter(&filter, &value, &variables, false, &EvalContext::default()).unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
+
+        let value = Value::Bool(true);
+        let error =
+            eval_filter(&filter, &value, &variables, false, &EvalContext::default()).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
@@ -16,28 +16,49 @@
  *
  */
 
+pub use custom::{CustomFilterFn, EvalError};
 pub use eval::eval_filters;
-pub use jsonpath::eval_jsonpath_json;
+pub use jsonpath::{eval_jsonpath_result, jsonpath_value};
+pub use jsonpointer::eval_jsonpointer_json;
+pub use to_date::parse_date;
 pub use xpath::eval_xpath_doc;
 
+mod base32_decode;
+mod base32_encode;
 mod base64_decode;
 mod base64_encode;
+mod base64_url_safe_decode;
+mod base64_url_safe_encode;
 mod count;
+mod custom;
 mod days_after_now;
 mod days_before_now;
 mod decode;
+mod decompress;
 mod eval;
 mod format;
+mod hex_decode;
+mod hex_encode;
 mod html_escape;
 mod html_unescape;
+mod json_escape;
+mod json_unescape;
+mod jsonl;
 mod jsonpath;
+mod jsonpointer;
+mod md5;
 mod nth;
+mod path_encode;
 mod regex;
 mod replace;
+mod sha1;
+mod sha256;
 mod split;
 mod to_date;
 mod to_float;
 mod to_int;
+mod to_string;
 mod url_decode;
 mod url_encode;
+mod xml_escape;
 mod xpath;
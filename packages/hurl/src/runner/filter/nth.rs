@@ -49,6 +49,7 @@ mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace, U64};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
 
@@ -76,7 +77,8 @@ mod tests {
                     Value::Number(Number::Integer(3))
                 ]),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -90,7 +92,8 @@ mod tests {
                     Value::Number(Number::Integer(1))
                 ]),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .err()
             .unwrap(),
@@ -0,0 +1,147 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use percent_encoding::AsciiSet;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Characters percent-encoded in a URL path: all characters except unreserved chars, sub-delims,
+/// `:`, `@` (see `pchar` in [RFC3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.3)) and the
+/// segment separator `/`.
+const PATH: &AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b'!')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')')
+    .remove(b'*')
+    .remove(b'+')
+    .remove(b',')
+    .remove(b';')
+    .remove(b'=')
+    .remove(b':')
+    .remove(b'@')
+    .remove(b'/');
+
+/// Percent-encodes all the characters in `value` that are not allowed in a URL path (like space,
+/// `?` or `#`). Contrary to `urlEncode`, characters allowed in a path segment (like `@`, `+` or `=`)
+/// are kept as is.
+pub fn eval_path_encode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            let encoded = percent_encoding::percent_encode(value.as_bytes(), PATH).to_string();
+            Ok(Some(Value::String(encoded)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_path_encode() {
+        let variables = VariableSet::new();
+        let path_encode = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::PathEncode,
+        };
+        let url_encode = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::UrlEncode,
+        };
+        let value = Value::String("users/john doe@example.com/a+b=c?x#y".to_string());
+        assert_eq!(
+            eval_filter(
+                &path_encode,
+                &value,
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("users/john%20doe@example.com/a+b=c%3Fx%23y".to_string())
+        );
+        // `urlEncode` also keeps `/` but encodes sub-delims, `:` and `@`.
+        assert_eq!(
+            eval_filter(
+                &url_encode,
+                &value,
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("users/john%20doe%40example.com/a%2Bb%3Dc%3Fx%23y".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &path_encode,
+                &Value::String("café/50%".to_string()),
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("caf%C3%A9/50%25".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_path_encode_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::PathEncode,
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(42)),
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("integer".to_string())
+        );
+    }
+}
@@ -52,6 +52,7 @@ mod tests {
     };
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{RunnerErrorKind, Value, VariableSet};
 
@@ -83,15 +84,22 @@ mod tests {
                 &Value::String("Hello Bob!".to_string()),
                 &variables,
                 false,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
             Value::String("Bob".to_string())
         );
 
-        let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
-            .err()
-            .unwrap();
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info,
             SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20))
@@ -128,6 +136,7 @@ mod tests {
             &Value::String("Hello Bob!".to_string()),
             &variables,
             false,
+            &EvalContext::default(),
         )
         .err()
         .unwrap();
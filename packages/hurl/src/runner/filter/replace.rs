@@ -51,6 +51,7 @@ mod tests {
     };
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
 
@@ -92,7 +93,8 @@ mod tests {
                 &filter,
                 &Value::String("1 2\t3  4".to_string()),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -49,6 +49,7 @@ mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
 
@@ -78,7 +79,8 @@ mod tests {
                 &filter,
                 &Value::String("1,2,3".to_string()),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
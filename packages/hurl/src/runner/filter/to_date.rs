@@ -15,7 +15,7 @@
  * limitations under the License.
  *
  */
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use hurl_core::ast::{SourceInfo, Template};
 
 use crate::runner::template::eval_template;
@@ -33,11 +33,9 @@ pub fn eval_to_date(
     let format = eval_template(format, variables)?;
 
     match value {
-        Value::String(v) => match NaiveDateTime::parse_from_str(v, format.as_str()) {
-            Ok(v) => Ok(Some(Value::Date(
-                v.and_local_timezone(chrono::Utc).unwrap(),
-            ))),
-            Err(_) => {
+        Value::String(v) => match parse_date(v, &format) {
+            Some(v) => Ok(Some(Value::Date(v))),
+            None => {
                 let kind = RunnerErrorKind::FilterInvalidInput(value.repr());
                 Err(RunnerError::new(source_info, kind, assert))
             }
@@ -49,12 +47,27 @@ pub fn eval_to_date(
     }
 }
 
+/// Parses a string `value` to a UTC date given a specification `format`.
+///
+/// If `format` has no time specifiers (like `%Y-%m-%d`), the date is set at midnight.
+pub fn parse_date(value: &str, format: &str) -> Option<DateTime<Utc>> {
+    let date = match NaiveDateTime::parse_from_str(value, format) {
+        Ok(date) => date,
+        Err(_) => NaiveDate::parse_from_str(value, format)
+            .ok()?
+            .and_hms_opt(0, 0, 0)?,
+    };
+    Some(date.and_local_timezone(Utc).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{DateTime, NaiveDate, Utc};
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
 
+    use super::parse_date;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
 
@@ -90,7 +103,8 @@ mod tests {
                 &filter,
                 &Value::String("1983 Apr 13 12:09:14.274 +0000".to_string()),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -125,11 +139,23 @@ mod tests {
                 &filter,
                 &Value::String("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
             Value::Date(datetime_utc)
         );
     }
+    #[test]
+    fn parse_date_without_time() {
+        let naive_datetime_utc = NaiveDate::from_ymd_opt(2024, 2, 29)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(naive_datetime_utc, Utc);
+        assert_eq!(parse_date("2024-02-29", "%Y-%m-%d"), Some(datetime_utc));
+        assert_eq!(parse_date("2023-02-29", "%Y-%m-%d"), None);
+        assert_eq!(parse_date("2024-02-29", "%d/%m/%Y"), None);
+    }
 }
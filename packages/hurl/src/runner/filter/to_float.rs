@@ -47,6 +47,7 @@ mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
@@ -62,7 +63,8 @@ mod tests {
                 &filter,
                 &Value::String("3.1415".to_string()),
                 &variable,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -73,7 +75,8 @@ mod tests {
                 &filter,
                 &Value::Number(Number::Float(3.1415)),
                 &variable,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -84,7 +87,8 @@ mod tests {
                 &filter,
                 &Value::Number(Number::Float(3.0)),
                 &variable,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -95,7 +99,8 @@ mod tests {
                 &filter,
                 &Value::Number(Number::Integer(3)),
                 &variable,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -115,6 +120,7 @@ mod tests {
             &Value::String("3x.1415".to_string()),
             &variables,
             false,
+            &EvalContext::default(),
         )
         .err()
         .unwrap();
@@ -122,9 +128,15 @@ mod tests {
             err.kind,
             RunnerErrorKind::FilterInvalidInput("string <3x.1415>".to_string())
         );
-        let err = eval_filter(&filter, &Value::Bool(true), &variables, false)
-            .err()
-            .unwrap();
+        let err = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             err.kind,
             RunnerErrorKind::FilterInvalidInput("boolean <true>".to_string())
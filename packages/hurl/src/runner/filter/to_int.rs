@@ -47,6 +47,7 @@ mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
@@ -62,7 +63,8 @@ mod tests {
                 &filter,
                 &Value::String("123".to_string()),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -73,7 +75,8 @@ mod tests {
                 &filter,
                 &Value::Number(Number::Integer(123)),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -84,7 +87,8 @@ mod tests {
                 &filter,
                 &Value::Number(Number::Float(1.6)),
                 &variables,
-                false
+                false,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -104,6 +108,7 @@ mod tests {
             &Value::String("123x".to_string()),
             &variables,
             false,
+            &EvalContext::default(),
         )
         .err()
         .unwrap();
@@ -111,9 +116,15 @@ mod tests {
             err.kind,
             RunnerErrorKind::FilterInvalidInput("string <123x>".to_string())
         );
-        let err = eval_filter(&filter, &Value::Bool(true), &variables, false)
-            .err()
-            .unwrap();
+        let err = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             err.kind,
             RunnerErrorKind::FilterInvalidInput("boolean <true>".to_string())
@@ -0,0 +1,197 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts `value` to a string.
+///
+/// Numbers, booleans and dates are rendered in their canonical form: integers are rendered
+/// without decimal part and dates use ISO 8601 format.
+pub fn eval_to_string(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let s = match value {
+        Value::Bool(_) | Value::Date(_) | Value::Number(_) | Value::String(_) => value.render(),
+        _ => None,
+    };
+    match s {
+        Some(s) => Ok(Some(Value::String(s))),
+        None => {
+            let kind = RunnerErrorKind::FilterInvalidInput(value.kind().to_string());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::{eval_filter, eval_filters};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_to_string() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToString,
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(123)),
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("123".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Float(1.5)),
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("1.5".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Bool(true),
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("true".to_string())
+        );
+        let date = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Date(date),
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("2025-01-01T00:00:00.000000Z".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("Hello".to_string()),
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Hello".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_to_string_to_int() {
+        let variables = VariableSet::new();
+        let filters = [
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                value: FilterValue::ToString,
+            },
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                value: FilterValue::ToInt,
+            },
+        ];
+        for i in [0, 42, -7, i64::MAX] {
+            assert_eq!(
+                eval_filters(
+                    &filters,
+                    &Value::Number(Number::Integer(i)),
+                    &variables,
+                    false,
+                    &EvalContext::default()
+                )
+                .unwrap()
+                .unwrap(),
+                Value::Number(Number::Integer(i))
+            );
+        }
+
+        let filters = [
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                value: FilterValue::ToInt,
+            },
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                value: FilterValue::ToString,
+            },
+        ];
+        assert_eq!(
+            eval_filters(
+                &filters,
+                &Value::String("123".to_string()),
+                &variables,
+                false,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("123".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_to_string_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ToString,
+        };
+        let err = eval_filter(
+            &filter,
+            &Value::List(vec![Value::Number(Number::Integer(1))]),
+            &variables,
+            false,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("list".to_string())
+        );
+    }
+}
@@ -46,6 +46,7 @@ pub fn eval_url_decode(
 
 #[cfg(test)]
 mod tests {
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
@@ -64,6 +65,7 @@ mod tests {
                 &Value::String("https://mozilla.org/?x=%D1%88%D0%B5%D0%BB%D0%BB%D1%8B".to_string()),
                 &variables,
                 false,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
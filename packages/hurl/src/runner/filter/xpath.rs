@@ -22,24 +22,43 @@ use crate::runner::xpath::{Document, Format, XPathError};
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Evaluates a XPath expression `expr` against a `value`.
+///
+/// The namespaces bindings `xpath_namespaces` are registered in the parsed document.
 pub fn eval_xpath(
     value: &Value,
     expr: &Template,
     variables: &VariableSet,
+    xpath_namespaces: &[(String, String)],
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match value {
         Value::String(xml) => {
-            // The filter will use the HTML parser that should also work with XML input
-            let Ok(doc) = Document::parse(xml, Format::Html) else {
+            let expr_str = eval_template(expr, variables)?;
+            // The filter will use the HTML parser that should also work with XML input. As
+            // namespaces are only supported by the XML parser, the input is parsed as XML only
+            // when the expression uses one of the bound namespace prefixes.
+            let format = if xpath_namespaces
+                .iter()
+                .any(|(prefix, _)| uses_prefix(&expr_str, prefix))
+            {
+                Format::Xml
+            } else {
+                Format::Html
+            };
+            let Ok(mut doc) = Document::parse(xml, format) else {
                 return Err(RunnerError::new(
                     source_info,
                     RunnerErrorKind::QueryInvalidXml,
                     false,
                 ));
             };
-            eval_xpath_doc(&doc, expr, variables)
+            if format == Format::Xml {
+                for (prefix, uri) in xpath_namespaces {
+                    doc.register_namespace(prefix, uri);
+                }
+            }
+            eval_xpath_str(&doc, &expr_str, expr)
         }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
@@ -54,7 +73,16 @@ pub fn eval_xpath_doc(
     variables: &VariableSet,
 ) -> Result<Option<Value>, RunnerError> {
     let expr_str = eval_template(expr, variables)?;
-    let result = doc.eval_xpath(&expr_str);
+    eval_xpath_str(doc, &expr_str, expr)
+}
+
+/// Evaluates the rendered XPath expression `expr_str` of the template `expr` against `doc`.
+fn eval_xpath_str(
+    doc: &Document,
+    expr_str: &str,
+    expr: &Template,
+) -> Result<Option<Value>, RunnerError> {
+    let result = doc.eval_xpath(expr_str);
     match result {
         Ok(value) => Ok(Some(value)),
         Err(XPathError::Eval) => Err(RunnerError::new(
@@ -68,12 +96,23 @@ pub fn eval_xpath_doc(
     }
 }
 
+/// Returns true if the XPath expression `expr` uses the namespace `prefix` (as in `prefix:name`).
+fn uses_prefix(expr: &str, prefix: &str) -> bool {
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-' || c == '.';
+    expr.match_indices(prefix).any(|(index, _)| {
+        let before = expr[..index].chars().next_back();
+        let mut after = expr[index + prefix.len()..].chars();
+        !before.is_some_and(is_name_char) && after.next() == Some(':') && after.next() != Some(':')
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -105,18 +144,102 @@ mod tests {
 
         let html = "<html><body>你好世界</body></html>";
         let filter = new_xpath_filter("string(//body/text())");
-        let ret = eval_filter(&filter, &Value::String(html.to_string()), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::String(html.to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
 
         assert_eq!(ret.unwrap().unwrap(), Value::String("你好世界".to_string()));
     }
 
+    #[test]
+    fn eval_filter_xpath_namespaces() {
+        let variables = VariableSet::new();
+
+        let xml = r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope/">
+    <soap:Body>
+        <GetPriceResponse xmlns="https://www.example.org/prices">
+            <Price>1.90</Price>
+        </GetPriceResponse>
+    </soap:Body>
+</soap:Envelope>"#;
+        let filter = new_xpath_filter("string(//soap:Body/m:GetPriceResponse/m:Price)");
+        let namespaces = vec![(
+            "m".to_string(),
+            "https://www.example.org/prices".to_string(),
+        )];
+        let context = EvalContext {
+            xpath_namespaces: &namespaces,
+            ..Default::default()
+        };
+        let ret = eval_filter(
+            &filter,
+            &Value::String(xml.to_string()),
+            &variables,
+            false,
+            &context,
+        );
+
+        assert_eq!(ret.unwrap().unwrap(), Value::String("1.90".to_string()));
+    }
+
+    #[test]
+    fn eval_filter_xpath_html_with_unused_namespaces() {
+        let variables = VariableSet::new();
+
+        // This HTML is not valid XML: it is parsed with the HTML parser as the expression doesn't
+        // use any bound namespace prefix.
+        let html = "<html><body><p>Hello<br>World</p></body></html>";
+        let filter = new_xpath_filter("string(//p)");
+        let namespaces = vec![(
+            "m".to_string(),
+            "https://www.example.org/prices".to_string(),
+        )];
+        let context = EvalContext {
+            xpath_namespaces: &namespaces,
+            ..Default::default()
+        };
+        let ret = eval_filter(
+            &filter,
+            &Value::String(html.to_string()),
+            &variables,
+            false,
+            &context,
+        );
+
+        assert_eq!(
+            ret.unwrap().unwrap(),
+            Value::String("HelloWorld".to_string())
+        );
+    }
+
+    #[test]
+    fn xpath_uses_prefix() {
+        assert!(uses_prefix("//m:Price", "m"));
+        assert!(uses_prefix("string(//soap:Body/m:Price)", "m"));
+        assert!(!uses_prefix("//Price", "m"));
+        assert!(!uses_prefix("//soap:Body", "p"));
+        assert!(!uses_prefix("//m::Price", "m"));
+        assert!(!uses_prefix("//html:Price", "m"));
+    }
+
     #[test]
     fn eval_filter_xpath_doc_ko_invalid_xpath() {
         let variables = VariableSet::new();
 
         let html = "<html><body>你好世界</body></html>";
         let filter = new_xpath_filter("str(//body/text())");
-        let ret = eval_filter(&filter, &Value::String(html.to_string()), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::String(html.to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
 
         assert_eq!(
             ret.unwrap_err().kind,
@@ -130,7 +253,13 @@ mod tests {
 
         let html = "";
         let filter = new_xpath_filter("string(//body/text())");
-        let ret = eval_filter(&filter, &Value::String(html.to_string()), &variables, false);
+        let ret = eval_filter(
+            &filter,
+            &Value::String(html.to_string()),
+            &variables,
+            false,
+            &EvalContext::default(),
+        );
 
         assert_eq!(ret.unwrap_err().kind, RunnerErrorKind::QueryInvalidXml);
     }
@@ -145,6 +274,7 @@ mod tests {
             &Value::Bytes(vec![0xc4, 0xe3, 0xba, 0xc3, 0xca, 0xc0, 0xbd, 0xe7]),
             &variables,
             false,
+            &EvalContext::default(),
         );
 
         assert_eq!(
@@ -16,21 +16,23 @@
  *
  */
 use std::thread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
 use hurl_core::ast::VersionValue::VersionAnyLegacy;
-use hurl_core::ast::{Entry, OptionKind, SourceInfo};
+use hurl_core::ast::{Entry, OptionKind, SourceInfo, Template, TemplateElement};
 use hurl_core::error::DisplaySourceError;
 use hurl_core::input::Input;
 use hurl_core::parser;
 use hurl_core::typing::Count;
 
-use crate::http::{Call, Client};
+use crate::http::{Call, Client, Cookie, Url};
+use crate::runner::delay::Delay;
 use crate::runner::event::EventListener;
+use crate::runner::retry;
 use crate::runner::runner_options::RunnerOptions;
 use crate::runner::{entry, options, EntryResult, HurlResult, VariableSet};
-use crate::util::logger::{ErrorFormat, Logger, LoggerOptions};
+use crate::util::logger::{ErrorFormat, Logger, LoggerOptions, Verbosity};
 use crate::util::term::{Stderr, Stdout, WriteMode};
 
 /// Runs a Hurl `content` and returns a [`HurlResult`] upon completion.
@@ -146,17 +148,69 @@ pub fn run_entries(
     logger: &mut Logger,
 ) -> HurlResult {
     let mut http_client = Client::new();
+    for cookie in &runner_options.cookies {
+        http_client.add_cookie(cookie, logger);
+    }
     let mut entries_result = vec![];
     let mut variables = variables.clone();
     let mut entry_index = runner_options.from_entry.unwrap_or(1);
     let mut repeat_count = 0;
+    // Target URL of the `Link` response header to follow, and number of links followed.
+    let mut link_url: Option<Url> = None;
+    let mut link_count = 0;
     let n = runner_options.to_entry.unwrap_or(entries.len());
     let default_verbosity = logger.verbosity;
     let start = Instant::now();
     let timestamp = Utc::now().timestamp();
+    let mut warmup_duration = Duration::ZERO;
+    let mut delay = Delay::new(runner_options.delay_jitter, runner_options.delay_seed);
 
     log_run_info(entries, runner_options, &variables, logger);
 
+    if let Some(max_entries) = runner_options.max_entries {
+        if entries.len() > max_entries {
+            let filename = filename.map_or(String::new(), |f| f.to_string());
+            let count = entries.len();
+            logger.error(&format!(
+                "File {filename} has {count} entries, more than the maximum of {max_entries} entries"
+            ));
+            return HurlResult {
+                success: false,
+                timestamp,
+                ..Default::default()
+            };
+        }
+    }
+
+    if entries.is_empty() && runner_options.fail_if_empty {
+        let filename = filename.map_or(String::new(), |f| f.to_string());
+        logger.error(&format!("No entry found in file {filename}"));
+        return HurlResult {
+            success: false,
+            timestamp,
+            ..Default::default()
+        };
+    }
+
+    if runner_options.concurrency_per_file > 1 {
+        let range = entries.iter().take(n).skip(entry_index - 1);
+        if can_run_concurrently(range, runner_options) {
+            return run_entries_concurrently(
+                entries,
+                content,
+                filename,
+                runner_options,
+                &variables,
+                stdout,
+                listener,
+                logger,
+            );
+        }
+        logger.warning(
+            "Entries are not independent (captures or variables definitions), running them sequentially",
+        );
+    }
+
     // Main loop processing each entry.
     // The `entry_index` is not always incremented of each loop tick: an entry can be retried upon
     // errors for instance. Each entry is executed with options that are computed from the global
@@ -167,6 +221,15 @@ pub fn run_entries(
             break;
         }
         let entry = &entries[entry_index - 1];
+        // When following a link, the entry is run again with the link target URL.
+        let link_entry;
+        let entry = match &link_url {
+            Some(url) => {
+                link_entry = with_url(entry, url);
+                &link_entry
+            }
+            None => entry,
+        };
 
         if let Some(pre_entry) = runner_options.pre_entry {
             let exit = pre_entry(entry);
@@ -175,89 +238,57 @@ pub fn run_entries(
             }
         }
 
-        // We compute the new logger verbosity for this entry, before entering into the `run`
-        // function because entry options can modify the logger verbosity and we want the preamble
-        // "Executing entry..." to be displayed based on the entry level verbosity.
-        logger.verbosity = default_verbosity;
-        let entry_verbosity = options::get_entry_verbosity(entry, default_verbosity, &variables);
-        if let Ok(entry_verbosity) = entry_verbosity {
-            logger.verbosity = entry_verbosity;
-        }
-
-        log_run_entry(entry_index, logger);
-
-        warn_deprecated(entry, filename, logger);
-
         // We can report the progression of the run for --test mode.
         if let Some(listener) = listener {
             listener.on_running(entry_index - 1, n);
         }
 
-        // The real execution of the entry happens here, first: we compute the overridden request
-        // options.
-        let options = options::get_entry_options(entry, runner_options, &mut variables, logger);
-        if let Err(error) = &options {
-            // If we have error evaluating request options, we consider it as a non retryable error
-            // and either break the runner or go to the next entries.
-            let entry_result = EntryResult {
-                entry_index,
-                source_info: entry.source_info(),
-                errors: vec![error.clone()],
-                ..Default::default()
-            };
-            log_errors(&entry_result, content, filename, false, logger);
-            entries_result.push(entry_result);
-            if runner_options.continue_on_error {
-                entry_index += 1;
-                continue;
-            } else {
-                break;
-            }
-        }
-
-        let options = options.unwrap();
-
-        // Should we skip?
-        if options.skip {
-            logger.debug("");
-            logger.debug_important(&format!("Entry {entry_index} has been skipped"));
-            entry_index += 1;
-            continue;
-        }
-
-        // Repeat 0 is equivalent to skip.
-        if options.repeat == Some(Count::Finite(0)) {
-            logger.debug("");
-            logger.debug_important(&format!("Entry {entry_index} is skipped (repeat 0 times)"));
-            entry_index += 1;
-            continue;
-        }
-
-        // Should we delay?
-        let delay = options.delay;
-        let delay_ms = delay.as_millis();
-        if delay_ms > 0 {
-            logger.debug("");
-            logger.debug_important(&format!("Delay entry {entry_index} (pause {delay_ms} ms)"));
-            thread::sleep(delay);
-        };
-
-        // Loop for executing HTTP run requests, with optional retry. Only "HTTP" errors in options
-        // are taken into account for retry (errors while computing entry options and output error
-        // are not retried).
-        let results = run_request(
+        // Warmup requests are sent only once per entry, not on each repetition or followed link.
+        let warmup = repeat_count == 0 && link_url.is_none();
+        let (options, results) = match run_entry(
             entry,
             entry_index,
             content,
             filename,
             &mut http_client,
-            &options,
+            runner_options,
             &mut variables,
+            &mut delay,
+            warmup,
+            default_verbosity,
             stdout,
             logger,
-        );
+        ) {
+            EntryRun::OptionsError(entry_result) => {
+                // If we have error evaluating request options, we consider it as a non retryable
+                // error and either break the runner or go to the next entries.
+                entries_result.push(entry_result);
+                if runner_options.continue_on_error {
+                    entry_index += 1;
+                    continue;
+                } else {
+                    break;
+                }
+            }
+            EntryRun::Skipped => {
+                entry_index += 1;
+                continue;
+            }
+            EntryRun::Done {
+                options,
+                results,
+                warmup_duration: entry_warmup_duration,
+            } => {
+                warmup_duration += entry_warmup_duration;
+                (options, results)
+            }
+        };
 
         let has_error = results.last().is_some_and(|r| !r.errors.is_empty());
+        let next_link_url = match (&options.follow_link, results.last()) {
+            (Some(rel), Some(result)) => get_link_url(result, rel),
+            _ => None,
+        };
 
         entries_result.extend(results);
 
@@ -271,30 +302,36 @@ pub fn run_entries(
             break;
         }
 
+        // We run the entry again while there is a link to follow.
+        if let Some(url) = next_link_url {
+            let can_follow = match options.follow_link_max {
+                Count::Finite(max) => link_count < max,
+                Count::Infinite => true,
+            };
+            if can_follow {
+                link_count += 1;
+                logger.debug_important(&format!(
+                    "Follow link of entry {entry_index} to <{url}> (x{link_count})"
+                ));
+                link_url = Some(url);
+                continue;
+            }
+            logger.debug_important(&format!(
+                "Maximum number of links followed for entry {entry_index} ({link_count})"
+            ));
+        }
+        link_url = None;
+        link_count = 0;
+
         // We pass to the next entry if the repeat count is reached.
         repeat_count += 1;
-        match options.repeat {
-            None => {
-                repeat_count = 0;
-                entry_index += 1;
-            }
-            Some(Count::Finite(n)) => {
-                if repeat_count >= n {
-                    repeat_count = 0;
-                    entry_index += 1;
-                } else {
-                    logger.debug_important(&format!(
-                        "Repeat entry {entry_index} (x{repeat_count}/{n})"
-                    ));
-                }
-            }
-            Some(Count::Infinite) => {
-                logger.debug_important(&format!("Repeat entry {entry_index} (x{repeat_count})"));
-            }
+        if !is_repeated(options.repeat, repeat_count, entry_index, logger) {
+            repeat_count = 0;
+            entry_index += 1;
         }
     }
 
-    let duration = start.elapsed();
+    let duration = start.elapsed() - warmup_duration;
     let cookies = http_client.cookie_storage(logger);
     let success = is_success(&entries_result);
     HurlResult {
@@ -307,6 +344,427 @@ pub fn run_entries(
     }
 }
 
+/// Returns `true` if `entries` can be run concurrently.
+///
+/// Entries can be run concurrently if they're independent: no entry has captures or defines
+/// variables in its `[Options]` section. Entries hooks (used in interactive mode) also require
+/// a sequential run.
+fn can_run_concurrently<'a>(
+    mut entries: impl Iterator<Item = &'a Entry>,
+    runner_options: &RunnerOptions,
+) -> bool {
+    if runner_options.pre_entry.is_some() || runner_options.post_entry.is_some() {
+        return false;
+    }
+    entries.all(|entry| {
+        let has_captures = entry
+            .response
+            .as_ref()
+            .is_some_and(|response| !response.captures().is_empty());
+        let has_variables_or_links = entry.request.options().iter().any(|option| {
+            matches!(
+                option.kind,
+                OptionKind::Variable(_) | OptionKind::FollowLink(_)
+            )
+        });
+        !has_captures && !has_variables_or_links
+    })
+}
+
+/// Returns the target URL of the link with relation type `rel` in the last response of this
+/// `entry_result`, or `None` if there is no link to follow.
+///
+/// Relative targets are resolved against the request URL.
+fn get_link_url(entry_result: &EntryResult, rel: &str) -> Option<Url> {
+    if !entry_result.errors.is_empty() {
+        return None;
+    }
+    let call = entry_result.calls.last()?;
+    let link = call
+        .response
+        .headers
+        .links()
+        .into_iter()
+        .find(|link| link.has_rel(rel))?;
+    call.request.url.join(&link.target).ok()
+}
+
+/// Returns a copy of this `entry` requesting `url`.
+fn with_url(entry: &Entry, url: &Url) -> Entry {
+    let mut entry = entry.clone();
+    let value = url.to_string();
+    entry.request.url = Template {
+        delimiter: None,
+        elements: vec![TemplateElement::String {
+            value: value.clone(),
+            encoded: value,
+        }],
+        source_info: entry.request.url.source_info,
+    };
+    entry
+}
+
+/// The result of an entry run in its own thread: entry results, buffered standard output and
+/// error, and cookies of the HTTP client.
+struct IsolatedRun {
+    results: Vec<EntryResult>,
+    stdout: Stdout,
+    logger: Logger,
+    cookies: Vec<Cookie>,
+    warmup_duration: Duration,
+}
+
+/// Runs a list of independent `entries` concurrently and returns a [`HurlResult`] upon completion.
+///
+/// Entries are run by batches of `runner_options.concurrency_per_file` entries. Each entry is
+/// run in its own thread, with its own HTTP client, a copy of `variables` and buffered standard
+/// output and error. Once a batch is completed, outputs are written in the entries order.
+#[allow(clippy::too_many_arguments)]
+fn run_entries_concurrently(
+    entries: &[Entry],
+    content: &str,
+    filename: Option<&Input>,
+    runner_options: &RunnerOptions,
+    variables: &VariableSet,
+    stdout: &mut Stdout,
+    listener: Option<&dyn EventListener>,
+    logger: &mut Logger,
+) -> HurlResult {
+    let start = Instant::now();
+    let timestamp = Utc::now().timestamp();
+    let from = runner_options.from_entry.unwrap_or(1);
+    let n = runner_options.to_entry.unwrap_or(entries.len());
+    let indexes = (from..=n).collect::<Vec<_>>();
+    let mut entries_result = vec![];
+    let mut cookie_stores = vec![];
+    let mut warmup_duration = Duration::ZERO;
+
+    for batch in indexes.chunks(runner_options.concurrency_per_file) {
+        let runs = thread::scope(|scope| {
+            let handles = batch
+                .iter()
+                .map(|&entry_index| {
+                    if let Some(listener) = listener {
+                        listener.on_running(entry_index - 1, n);
+                    }
+                    let entry = &entries[entry_index - 1];
+                    let entry_logger = logger.with_stderr(Stderr::new(WriteMode::Buffered));
+                    scope.spawn(move || {
+                        run_isolated_entry(
+                            entry,
+                            entry_index,
+                            content,
+                            filename,
+                            runner_options,
+                            variables,
+                            entry_logger,
+                        )
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        let mut has_error = false;
+        // Entries of a batch are run concurrently, so the batch warmup lasts as long as the
+        // longest entry warmup.
+        warmup_duration += runs
+            .iter()
+            .map(|run| run.warmup_duration)
+            .max()
+            .unwrap_or_default();
+        for run in runs {
+            logger.stderr.eprint(run.logger.stderr.buffer());
+            if let Err(e) = stdout.write_all(run.stdout.buffer()) {
+                logger.warning(&format!("Unable to write to standard output: {e}"));
+            }
+            has_error |= run.results.last().is_some_and(|r| !r.errors.is_empty());
+            entries_result.extend(run.results);
+            cookie_stores.push(run.cookies);
+        }
+        if !runner_options.continue_on_error && has_error {
+            break;
+        }
+    }
+
+    // Each entry has its own HTTP client, seeded with the same initial cookies: a cookie set by
+    // several entries is kept once, with the value of the last entry.
+    let cookies = Cookie::merge(cookie_stores.iter().map(Vec::as_slice));
+    let duration = start.elapsed() - warmup_duration;
+    let success = is_success(&entries_result);
+    HurlResult {
+        entries: entries_result,
+        duration,
+        success,
+        cookies,
+        timestamp,
+        variables: variables.clone(),
+    }
+}
+
+/// Runs an `entry` with its own HTTP client, standard output and `logger`.
+///
+/// The entry is run with a copy of `variables`, honoring the entry options (skip, repeat, delay,
+/// retry etc...) like in a sequential run.
+fn run_isolated_entry(
+    entry: &Entry,
+    entry_index: usize,
+    content: &str,
+    filename: Option<&Input>,
+    runner_options: &RunnerOptions,
+    variables: &VariableSet,
+    mut logger: Logger,
+) -> IsolatedRun {
+    let mut http_client = Client::new();
+    for cookie in &runner_options.cookies {
+        http_client.add_cookie(cookie, &mut logger);
+    }
+    let mut stdout = Stdout::new(WriteMode::Buffered);
+    let mut variables = variables.clone();
+    let mut results = vec![];
+    let mut warmup_duration = Duration::ZERO;
+    let default_verbosity = logger.verbosity;
+    // Each entry has its own generator, derived from the seed and the entry index, so concurrent
+    // runs are also reproducible.
+    let seed = runner_options
+        .delay_seed
+        .map(|seed| seed.wrapping_add(entry_index as u64));
+    let mut delay = Delay::new(runner_options.delay_jitter, seed);
+    let mut repeat_count = 0;
+
+    loop {
+        match run_entry(
+            entry,
+            entry_index,
+            content,
+            filename,
+            &mut http_client,
+            runner_options,
+            &mut variables,
+            &mut delay,
+            repeat_count == 0,
+            default_verbosity,
+            &mut stdout,
+            &mut logger,
+        ) {
+            EntryRun::OptionsError(entry_result) => {
+                results.push(entry_result);
+                break;
+            }
+            EntryRun::Skipped => break,
+            EntryRun::Done {
+                options,
+                results: entry_results,
+                warmup_duration: entry_warmup_duration,
+            } => {
+                warmup_duration += entry_warmup_duration;
+                let has_error = entry_results.last().is_some_and(|r| !r.errors.is_empty());
+                results.extend(entry_results);
+                if !runner_options.continue_on_error && has_error {
+                    break;
+                }
+                repeat_count += 1;
+                if !is_repeated(options.repeat, repeat_count, entry_index, &mut logger) {
+                    break;
+                }
+            }
+        }
+    }
+
+    let cookies = http_client.cookie_storage(&mut logger);
+    IsolatedRun {
+        results,
+        stdout,
+        logger,
+        cookies,
+        warmup_duration,
+    }
+}
+
+/// The outcome of one run of an entry, see [`run_entry`].
+enum EntryRun {
+    /// The entry options can't be evaluated: the entry is not run.
+    OptionsError(EntryResult),
+    /// The entry is skipped given its options.
+    Skipped,
+    /// The entry has been run with `options`.
+    Done {
+        options: Box<RunnerOptions>,
+        results: Vec<EntryResult>,
+        warmup_duration: Duration,
+    },
+}
+
+/// Runs once the `entry` at `entry_index`, in sequential or concurrent runs.
+///
+/// The entry options are computed from the `runner_options` and the entry `[Options]` section.
+/// Then, the entry can be skipped, paused with the next delay of `delay`, warmed up (if `warmup` is
+/// true) before its HTTP requests are run, with optional retry. The logger verbosity is
+/// reset to `default_verbosity` before applying the entry verbosity.
+#[allow(clippy::too_many_arguments)]
+fn run_entry(
+    entry: &Entry,
+    entry_index: usize,
+    content: &str,
+    filename: Option<&Input>,
+    http_client: &mut Client,
+    runner_options: &RunnerOptions,
+    variables: &mut VariableSet,
+    delay: &mut Delay,
+    warmup: bool,
+    default_verbosity: Option<Verbosity>,
+    stdout: &mut Stdout,
+    logger: &mut Logger,
+) -> EntryRun {
+    // We compute the new logger verbosity for this entry, before entering into the `run`
+    // function because entry options can modify the logger verbosity and we want the preamble
+    // "Executing entry..." to be displayed based on the entry level verbosity.
+    logger.verbosity = default_verbosity;
+    let entry_verbosity = options::get_entry_verbosity(entry, default_verbosity, variables);
+    if let Ok(entry_verbosity) = entry_verbosity {
+        logger.verbosity = entry_verbosity;
+    }
+
+    log_run_entry(entry_index, logger);
+
+    warn_deprecated(entry, filename, logger);
+
+    // The real execution of the entry happens here, first: we compute the overridden request
+    // options.
+    let options = match options::get_entry_options(entry, runner_options, variables, logger) {
+        Ok(options) => options,
+        Err(error) => {
+            let entry_result = EntryResult {
+                entry_index,
+                source_info: entry.source_info(),
+                errors: vec![error],
+                ..Default::default()
+            };
+            log_errors(&entry_result, content, filename, false, logger);
+            return EntryRun::OptionsError(entry_result);
+        }
+    };
+
+    // Should we skip?
+    if let Some(reason) = skip_reason(&options) {
+        logger.debug("");
+        logger.debug_important(&format!("Entry {entry_index} {reason}"));
+        return EntryRun::Skipped;
+    }
+
+    // Should we delay?
+    pause(delay.next(options.delay), entry_index, logger);
+
+    let warmup_duration = if warmup {
+        run_warmup(entry, entry_index, http_client, &options, variables, logger)
+    } else {
+        Duration::ZERO
+    };
+
+    // Loop for executing HTTP run requests, with optional retry. Only "HTTP" errors in options
+    // are taken into account for retry (errors while computing entry options and output error
+    // are not retried).
+    let results = run_request(
+        entry,
+        entry_index,
+        content,
+        filename,
+        http_client,
+        &options,
+        variables,
+        stdout,
+        logger,
+    );
+    EntryRun::Done {
+        options: Box::new(options),
+        results,
+        warmup_duration,
+    }
+}
+
+/// Returns the reason why an entry is skipped given its `options`, or `None` if it's not skipped.
+///
+/// An entry is skipped with the `skip` option, or if it's repeated 0 times.
+fn skip_reason(options: &RunnerOptions) -> Option<&'static str> {
+    if options.skip {
+        Some("has been skipped")
+    } else if options.repeat == Some(Count::Finite(0)) {
+        Some("is skipped (repeat 0 times)")
+    } else {
+        None
+    }
+}
+
+/// Pauses the current thread for `delay` before running the entry at `entry_index`.
+fn pause(delay: Duration, entry_index: usize, logger: &mut Logger) {
+    let delay_ms = delay.as_millis();
+    if delay_ms > 0 {
+        logger.debug("");
+        logger.debug_important(&format!("Delay entry {entry_index} (pause {delay_ms} ms)"));
+        thread::sleep(delay);
+    }
+}
+
+/// Returns `true` if the entry at `entry_index`, already run `repeat_count` times, has to be run
+/// again given its `repeat` option.
+fn is_repeated(
+    repeat: Option<Count>,
+    repeat_count: usize,
+    entry_index: usize,
+    logger: &mut Logger,
+) -> bool {
+    match repeat {
+        None => false,
+        Some(Count::Finite(n)) if repeat_count >= n => false,
+        Some(Count::Finite(n)) => {
+            logger.debug_important(&format!("Repeat entry {entry_index} (x{repeat_count}/{n})"));
+            true
+        }
+        Some(Count::Infinite) => {
+            logger.debug_important(&format!("Repeat entry {entry_index} (x{repeat_count})"));
+            true
+        }
+    }
+}
+
+/// Sends an `entry` `options.warmup` times, without recording results, and returns the time spent.
+///
+/// Warmup requests are run with a copy of `variables`, so captures of warmup requests are not
+/// visible to the measured run.
+fn run_warmup(
+    entry: &Entry,
+    entry_index: usize,
+    http_client: &mut Client,
+    options: &RunnerOptions,
+    variables: &VariableSet,
+    logger: &mut Logger,
+) -> Duration {
+    let start = Instant::now();
+    for i in 1..=options.warmup {
+        logger.debug("");
+        logger.debug_important(&format!(
+            "Warmup entry {entry_index} (x{i}/{})",
+            options.warmup
+        ));
+        let mut variables = variables.clone();
+        let result = entry::run(
+            entry,
+            entry_index,
+            http_client,
+            &mut variables,
+            options,
+            logger,
+        );
+        result.errors.iter().for_each(|error| {
+            logger.debug(&format!("Warmup error: {}", error.description()));
+        });
+    }
+    start.elapsed()
+}
+
 /// Runs an HTTP request and optional retry it until there are no HTTP errors. Returns a list of
 /// [`EntryResult`].
 #[allow(clippy::too_many_arguments)]
@@ -347,7 +805,10 @@ fn run_request(
 
         // We log eventual errors, only if we're not retrying the current entry...
         // The retry does not take into account a possible output Error
-        let retry = options.retry.is_some() && !retry_max_reached && has_error;
+        let retry = options.retry.is_some()
+            && !retry_max_reached
+            && has_error
+            && is_retryable_status(&result, &options.retry_on_status);
 
         // When --output is overridden on a request level, we output the HTTP response only if the
         // call has succeeded. Output errors are not taken into account for retrying requests.
@@ -373,7 +834,8 @@ fn run_request(
             break;
         }
 
-        let delay = options.retry_interval.as_millis();
+        let interval = get_retry_interval(results.last(), options, retry_count);
+        let delay = interval.as_millis();
         logger.debug("");
         logger.debug_important(&format!(
             "Retry entry {entry_index} (x{retry_count} pause {delay} ms)"
@@ -382,7 +844,7 @@ fn run_request(
         // If we retry the entry, we do not want to display a 'blank' progress bar during the
         // sleep delay. During the pause, we artificially show the previously erased progress
         // line.
-        thread::sleep(options.retry_interval);
+        thread::sleep(interval);
 
         // TODO: We keep this log because we don't want to change stderr with the changes
         // introduced by <https://github.com/Orange-OpenSource/hurl/issues/1973>
@@ -392,6 +854,48 @@ fn run_request(
     results
 }
 
+/// Returns the interval to pause before the retry number `retry_count` (starting at 1) of an entry.
+///
+/// The interval is computed with the retry backoff strategy, but a `Retry-After` header in the last
+/// response of the entry `result` takes precedence. In both cases, the interval is capped by the
+/// optional maximum retry interval.
+fn get_retry_interval(
+    result: Option<&EntryResult>,
+    options: &RunnerOptions,
+    retry_count: usize,
+) -> Duration {
+    let retry_after = result
+        .and_then(|r| r.calls.last())
+        .and_then(|c| c.response.headers.get("Retry-After"))
+        .and_then(|h| retry::parse_retry_after(&h.value, Utc::now()));
+    match (retry_after, options.retry_max_interval) {
+        (Some(retry_after), Some(max_interval)) => retry_after.min(max_interval),
+        (Some(retry_after), None) => retry_after,
+        (None, _) => options.retry_backoff.interval(
+            options.retry_interval,
+            options.retry_max_interval,
+            retry_count,
+        ),
+    }
+}
+
+/// Returns `true` if the last response status of this entry `result` allows a retry.
+///
+/// If `retry_on_status` is empty, any status can be retried, otherwise the status of the last
+/// response must be one of the `retry_on_status` codes. An entry without any response (a runtime
+/// error for instance) can't be retried when `retry_on_status` is set.
+fn is_retryable_status(result: &EntryResult, retry_on_status: &[u16]) -> bool {
+    if retry_on_status.is_empty() {
+        return true;
+    }
+    match result.calls.last() {
+        Some(call) => retry_on_status
+            .iter()
+            .any(|status| u32::from(*status) == call.response.status),
+        None => false,
+    }
+}
+
 /// Use source_info from output option if this option has been defined
 fn get_output_source_info(entry: &Entry) -> SourceInfo {
     let mut source_info = entry.source_info();
@@ -464,14 +968,53 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         non_default_options.push(("delay", format!("{}ms", options.delay.as_millis() as u64)));
     }
 
+    if options.delay_jitter != default_options.delay_jitter {
+        let jitter = options.delay_jitter.as_millis() as u64;
+        non_default_options.push(("delay jitter", format!("{jitter}ms")));
+    }
+
+    if let Some(seed) = options.delay_seed {
+        non_default_options.push(("delay seed", seed.to_string()));
+    }
+
+    if options.dns_servers != default_options.dns_servers {
+        if let Some(dns_servers) = &options.dns_servers {
+            non_default_options.push(("dns servers", dns_servers.to_string()));
+        }
+    }
+
+    if options.fail_if_empty {
+        non_default_options.push(("fail if empty", "true".to_string()));
+    }
+
+    if let Some(follow_link) = &options.follow_link {
+        non_default_options.push(("follow link", follow_link.to_string()));
+    }
+
+    if options.follow_link_max != default_options.follow_link_max {
+        non_default_options.push(("follow link max", options.follow_link_max.to_string()));
+    }
+
     if options.follow_location != default_options.follow_location {
         non_default_options.push(("follow redirect", options.follow_location.to_string()));
     }
 
+    if options.ignore_trailing_newline {
+        non_default_options.push(("ignore trailing newline", "true".to_string()));
+    }
+
     if options.insecure != default_options.insecure {
         non_default_options.push(("insecure", options.insecure.to_string()));
     }
 
+    if options.insecure_hosts != default_options.insecure_hosts {
+        non_default_options.push(("insecure hosts", options.insecure_hosts.join(",")));
+    }
+
+    if let Some(max_entries) = options.max_entries {
+        non_default_options.push(("max entries", max_entries.to_string()));
+    }
+
     if options.max_redirect != default_options.max_redirect {
         non_default_options.push(("max redirect", options.max_redirect.to_string()));
     }
@@ -482,6 +1025,25 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         }
     }
 
+    if options.request_charset != default_options.request_charset {
+        if let Some(request_charset) = &options.request_charset {
+            non_default_options.push(("request charset", request_charset.to_string()));
+        }
+    }
+
+    if options.require_http_version != default_options.require_http_version {
+        non_default_options.push((
+            "require HTTP version",
+            options.require_http_version.to_string(),
+        ));
+    }
+
+    if options.response_charset != default_options.response_charset {
+        if let Some(response_charset) = &options.response_charset {
+            non_default_options.push(("response charset", response_charset.to_string()));
+        }
+    }
+
     if options.retry != default_options.retry {
         let value = match options.retry {
             Some(retry) => retry.to_string(),
@@ -490,6 +1052,25 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         non_default_options.push(("retry", value));
     }
 
+    if options.retry_backoff != default_options.retry_backoff {
+        non_default_options.push(("retry backoff", options.retry_backoff.to_string()));
+    }
+
+    if let Some(max_interval) = options.retry_max_interval {
+        let max_interval = max_interval.as_millis() as u64;
+        non_default_options.push(("retry max interval", format!("{max_interval}ms")));
+    }
+
+    if options.retry_on_status != default_options.retry_on_status {
+        let value = options
+            .retry_on_status
+            .iter()
+            .map(|status| status.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        non_default_options.push(("retry on status", value));
+    }
+
     if options.unix_socket != default_options.unix_socket {
         if let Some(unix_socket) = &options.unix_socket {
             non_default_options.push(("unix socket", unix_socket.to_string()));
@@ -590,4 +1171,53 @@ mod test {
         assert_eq!(first_non_default.0, "delay");
         assert_eq!(first_non_default.1, "500ms");
     }
+
+    #[test]
+    fn can_run_concurrently_independent_entries() {
+        let content = r#"
+GET http://localhost:8000/a
+HTTP 200
+[Asserts]
+body == "a"
+
+GET http://localhost:8000/b
+[Options]
+retry: 2
+HTTP 200
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        let options = RunnerOptions::default();
+        assert!(can_run_concurrently(hurl_file.entries.iter(), &options));
+    }
+
+    #[test]
+    fn can_not_run_concurrently_dependent_entries() {
+        let options = RunnerOptions::default();
+
+        let content = r#"
+GET http://localhost:8000/a
+HTTP 200
+[Captures]
+a: body
+
+GET http://localhost:8000/b?a={{a}}
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        assert!(!can_run_concurrently(hurl_file.entries.iter(), &options));
+        // Entries after the capture are independent.
+        assert!(can_run_concurrently(
+            hurl_file.entries.iter().skip(1),
+            &options
+        ));
+
+        let content = r#"
+GET http://localhost:8000/a
+[Options]
+variable: a=1
+
+GET http://localhost:8000/b
+"#;
+        let hurl_file = parser::parse_hurl_file(content).unwrap();
+        assert!(!can_run_concurrently(hurl_file.entries.iter(), &options));
+    }
 }
@@ -21,13 +21,17 @@
 pub use self::error::{RunnerError, RunnerErrorKind};
 #[doc(hidden)]
 pub use self::event::EventListener;
+pub use self::filter::{CustomFilterFn, EvalError};
 pub use self::hurl_file::run;
 #[doc(hidden)]
 pub use self::hurl_file::run_entries;
 pub use self::number::Number;
 pub use self::output::Output;
+pub use self::query::CustomQueryFn;
 pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult};
+pub use self::retry::RetryBackoff;
 pub use self::runner_options::{RunnerOptions, RunnerOptionsBuilder};
+pub use self::summary::RunSummary;
 pub use self::value::Value;
 pub use self::variable::{Variable, VariableSet, Visibility};
 
@@ -35,9 +39,11 @@ mod assert;
 mod body;
 mod cache;
 mod capture;
+mod delay;
 mod diff;
 mod entry;
 mod error;
+mod eval_context;
 mod event;
 mod expr;
 mod filter;
@@ -56,7 +62,9 @@ mod regex;
 mod request;
 mod response;
 mod result;
+mod retry;
 mod runner_options;
+mod summary;
 mod template;
 mod value;
 mod variable;
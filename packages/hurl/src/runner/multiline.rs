@@ -15,7 +15,7 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{MultilineString, MultilineStringKind, Text};
+use hurl_core::ast::{MultilineString, MultilineStringAttribute, MultilineStringKind, Text};
 use serde_json::json;
 
 use crate::runner::json::eval_json_value;
@@ -23,6 +23,8 @@ use crate::runner::template::eval_template;
 use crate::runner::{RunnerError, VariableSet};
 
 /// Renders to string a multiline body, given a set of variables.
+///
+/// With the `nonewline` attribute, the final newline of a text, JSON or XML multiline is removed.
 pub fn eval_multiline(
     multiline: &MultilineString,
     variables: &VariableSet,
@@ -41,6 +43,14 @@ pub fn eval_multiline(
             ..
         } => {
             let s = eval_template(value, variables)?;
+            if multiline
+                .attributes
+                .contains(&MultilineStringAttribute::NoNewline)
+            {
+                let s = s.strip_suffix('\n').unwrap_or(&s);
+                let s = s.strip_suffix('\r').unwrap_or(s);
+                return Ok(s.to_string());
+            }
             Ok(s)
         }
         MultilineString {
@@ -65,7 +75,8 @@ pub fn eval_multiline(
 mod tests {
     use hurl_core::ast::{
         GraphQl, GraphQlVariables, JsonObjectElement, JsonValue, MultilineString,
-        MultilineStringKind, SourceInfo, Template, TemplateElement, Whitespace,
+        MultilineStringAttribute, MultilineStringKind, SourceInfo, Template, TemplateElement, Text,
+        Whitespace,
     };
     use hurl_core::reader::Pos;
 
@@ -90,6 +101,55 @@ mod tests {
         SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0))
     }
 
+    fn text_multiline(value: &str, attributes: Vec<MultilineStringAttribute>) -> MultilineString {
+        MultilineString {
+            kind: MultilineStringKind::Text(Text {
+                space: whitespace(),
+                newline: newline(),
+                value: Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: value.to_string(),
+                        encoded: value.to_string(),
+                    }],
+                    source_info: empty_source_info(),
+                },
+            }),
+            attributes,
+        }
+    }
+
+    #[test]
+    fn eval_text_multiline() {
+        let variables = VariableSet::new();
+        let multiline = text_multiline("line1\nline2\n", vec![]);
+        let body = eval_multiline(&multiline, &variables).unwrap();
+        assert_eq!(body, "line1\nline2\n");
+    }
+
+    #[test]
+    fn eval_text_multiline_nonewline() {
+        let variables = VariableSet::new();
+        let attributes = vec![MultilineStringAttribute::NoNewline];
+
+        let multiline = text_multiline("line1\nline2\n", attributes.clone());
+        let body = eval_multiline(&multiline, &variables).unwrap();
+        assert_eq!(body, "line1\nline2");
+
+        let multiline = text_multiline("line1\r\nline2\r\n", attributes.clone());
+        let body = eval_multiline(&multiline, &variables).unwrap();
+        assert_eq!(body, "line1\r\nline2");
+
+        // Only the final newline is removed.
+        let multiline = text_multiline("line1\n\n", attributes.clone());
+        let body = eval_multiline(&multiline, &variables).unwrap();
+        assert_eq!(body, "line1\n");
+
+        let multiline = text_multiline("", attributes);
+        let body = eval_multiline(&multiline, &variables).unwrap();
+        assert_eq!(body, "");
+    }
+
     #[test]
     fn eval_graphql_multiline_simple() {
         let query = r#"{
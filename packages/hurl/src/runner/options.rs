@@ -21,7 +21,7 @@ use hurl_core::ast::{
 };
 use hurl_core::typing::{BytesPerSec, Count, DurationUnit};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{IpResolve, RequestCompression, RequestedHttpVersion};
 use crate::runner::template::eval_template;
 use crate::runner::{
     expr, Number, Output, RunnerError, RunnerErrorKind, RunnerOptions, Value, VariableSet,
@@ -72,6 +72,14 @@ pub fn get_entry_options(
                         let value = eval_template(filename, variables)?;
                         entry_options.client_key_file = Some(value);
                     }
+                    OptionKind::CompressRequest(template) => {
+                        let value = eval_template(template, variables)?;
+                        let Some(compression) = RequestCompression::parse(&value) else {
+                            let kind = RunnerErrorKind::UnsupportedRequestCompression(value);
+                            return Err(RunnerError::new(template.source_info, kind, false));
+                        };
+                        entry_options.compress_request = Some(compression);
+                    }
                     OptionKind::Compressed(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.compressed = value;
@@ -148,6 +156,14 @@ pub fn get_entry_options(
                             entry_options.http_version = RequestedHttpVersion::Http2;
                         }
                     }
+                    OptionKind::FollowLink(value) => {
+                        let value = eval_template(value, variables)?;
+                        entry_options.follow_link = Some(value);
+                    }
+                    OptionKind::FollowLinkMax(value) => {
+                        let value = eval_count_option(value, variables)?;
+                        entry_options.follow_link_max = value;
+                    }
                     OptionKind::FollowLocation(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.follow_location = value;
@@ -188,6 +204,15 @@ pub fn get_entry_options(
                         let value = eval_count_option(value, variables)?;
                         entry_options.max_redirect = value;
                     }
+                    OptionKind::MaxTime(value) => {
+                        let value =
+                            eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
+                        entry_options.timeout = value;
+                    }
+                    OptionKind::Name(value) => {
+                        let value = eval_template(value, variables)?;
+                        entry_options.name = Some(value);
+                    }
                     OptionKind::NetRc(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.netrc = value;
@@ -200,6 +225,10 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.netrc_optional = value;
                     }
+                    OptionKind::NoProxy(value) => {
+                        let value = eval_template(value, variables)?;
+                        entry_options.no_proxy = Some(value);
+                    }
                     OptionKind::Output(output) => {
                         let filename = eval_template(output, variables)?;
                         let output = Output::new(&filename);
@@ -217,10 +246,22 @@ pub fn get_entry_options(
                         let value = eval_count_option(value, variables)?;
                         entry_options.repeat = Some(value);
                     }
+                    OptionKind::RequestCharset(template) => {
+                        let value = eval_template(template, variables)?;
+                        if encoding::label::encoding_from_whatwg_label(&value).is_none() {
+                            let kind = RunnerErrorKind::InvalidCharset { charset: value };
+                            return Err(RunnerError::new(template.source_info, kind, false));
+                        }
+                        entry_options.request_charset = Some(value);
+                    }
                     OptionKind::Resolve(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.resolves.push(value);
                     }
+                    OptionKind::ResponseCharset(value) => {
+                        let value = eval_template(value, variables)?;
+                        entry_options.response_charset = Some(value);
+                    }
                     OptionKind::Retry(value) => {
                         let value = eval_count_option(value, variables)?;
                         entry_options.retry = Some(value);
@@ -234,6 +275,10 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.skip = value;
                     }
+                    OptionKind::StripNewlines(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.strip_newlines = value;
+                    }
                     OptionKind::UnixSocket(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.unix_socket = Some(value);
@@ -262,6 +307,12 @@ pub fn get_entry_options(
                     OptionKind::VeryVerbose(value) => {
                         eval_boolean_option(value, variables)?;
                     }
+                    OptionKind::XPathNamespace(VariableDefinition { name, value, .. }) => {
+                        let value = eval_variable_value(value, variables)?;
+                        entry_options
+                            .xpath_namespaces
+                            .push((name.clone(), value.to_string()));
+                    }
                 }
                 log_option(option, logger);
             }
@@ -20,9 +20,10 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::{fmt, io};
 
-use hurl_core::ast::SourceInfo;
+use hurl_core::ast::{Placeholder, SourceInfo, TemplateElement};
+use hurl_core::parser;
 
-use crate::runner::{RunnerError, RunnerErrorKind};
+use crate::runner::{expr, RunnerError, RunnerErrorKind, VariableSet};
 use crate::util::path::ContextDir;
 use crate::util::term::Stdout;
 
@@ -55,6 +56,45 @@ impl Output {
         }
     }
 
+    /// Renders this output, evaluating the placeholders of the filename (like `{{slug}}`) with
+    /// `variables`.
+    ///
+    /// This is used to resolve the `--output` filename after a run, so it can reference variables
+    /// captured during the run. As captured values come from the HTTP responses, a rendered
+    /// placeholder can't contain a path separator or `..`: the output file is always written in the
+    /// directory given by the filename. If the filename can't be rendered, an error message is
+    /// returned.
+    pub fn render(&self, variables: &VariableSet) -> Result<Output, String> {
+        let Output::File(filename) = self else {
+            return Ok(Output::Stdout);
+        };
+        let filename = filename.to_string_lossy();
+        let Ok(template) = parser::parse_template(&filename) else {
+            return Err(format!("{filename} is not a valid template"));
+        };
+        let mut rendered = String::new();
+        for element in &template.elements {
+            match element {
+                TemplateElement::String { value, .. } => rendered.push_str(value),
+                TemplateElement::Placeholder(Placeholder { expr, .. }) => {
+                    let value = expr::render(expr, variables).map_err(|e| match e.kind {
+                        RunnerErrorKind::TemplateVariableNotDefined { name } => {
+                            format!("you must set the variable {name} to render the output file")
+                        }
+                        _ => format!("{{{{{expr}}}}} can not be rendered in the output file"),
+                    })?;
+                    if value.contains(std::path::is_separator) || value.contains("..") {
+                        return Err(format!(
+                            "value <{value}> of {{{{{expr}}}}} can not be used in the output file"
+                        ));
+                    }
+                    rendered.push_str(&value);
+                }
+            }
+        }
+        Ok(Output::File(PathBuf::from(rendered)))
+    }
+
     /// Writes these `bytes` to the output.
     ///
     /// If output is a standard output variant, `stdout` is used to write the bytes. If `append`
@@ -148,3 +188,83 @@ impl RunnerError {
         RunnerError::new(source_info, kind, false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Value;
+
+    #[test]
+    fn render_output() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("slug".to_string(), Value::String("hello-world".to_string()))
+            .unwrap();
+
+        let output = Output::new("build/{{slug}}.json");
+        assert_eq!(
+            output.render(&variables).unwrap(),
+            Output::File(PathBuf::from("build/hello-world.json"))
+        );
+        let output = Output::new("build/{{ slug }}/{{slug}}.json");
+        assert_eq!(
+            output.render(&variables).unwrap(),
+            Output::File(PathBuf::from("build/hello-world/hello-world.json"))
+        );
+        let output = Output::new("build/output.json");
+        assert_eq!(
+            output.render(&variables).unwrap(),
+            Output::File(PathBuf::from("build/output.json"))
+        );
+        let output = Output::new("-");
+        assert_eq!(output.render(&variables).unwrap(), Output::Stdout);
+
+        let output = Output::new("build/{{id}}.json");
+        assert_eq!(
+            output.render(&variables).unwrap_err(),
+            "you must set the variable id to render the output file".to_string()
+        );
+        let output = Output::new("build/{{slug.json");
+        assert_eq!(
+            output.render(&variables).unwrap_err(),
+            "build/{{slug.json is not a valid template".to_string()
+        );
+    }
+
+    #[test]
+    fn render_output_with_function() {
+        let variables = VariableSet::new();
+        let output = Output::new("build/{{newUuid}}.json");
+        let Output::File(filename) = output.render(&variables).unwrap() else {
+            panic!("output should be a file");
+        };
+        assert_eq!(filename.to_string_lossy().len(), "build/.json".len() + 36);
+    }
+
+    #[test]
+    fn render_output_rejects_unsafe_values() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("slug".to_string(), Value::String("../../x".to_string()))
+            .unwrap();
+        variables
+            .insert("dir".to_string(), Value::String("a/b".to_string()))
+            .unwrap();
+        variables
+            .insert("up".to_string(), Value::String("..".to_string()))
+            .unwrap();
+
+        for (filename, name) in [
+            ("build/{{slug}}.json", "slug"),
+            ("build/{{dir}}.json", "dir"),
+            ("build/{{up}}/out.json", "up"),
+        ] {
+            let output = Output::new(filename);
+            let value = variables.get(name).unwrap().value().render().unwrap();
+            assert_eq!(
+                output.render(&variables).unwrap_err(),
+                format!("value <{value}> of {{{{{name}}}}} can not be used in the output file")
+            );
+        }
+    }
+}
@@ -19,7 +19,7 @@ use hurl_core::ast::{Number, Placeholder, PredicateValue};
 
 use crate::runner::body::eval_file; // TODO move function out of body module
 use crate::runner::error::RunnerError;
-use crate::runner::expr::eval;
+use crate::runner::expr::{eval, render};
 use crate::runner::multiline::eval_multiline;
 use crate::runner::template::eval_template;
 use crate::runner::{Number as ValueNumber, Value, VariableSet};
@@ -63,8 +63,9 @@ pub fn eval_predicate_value_template(
     match predicate_value {
         PredicateValue::String(template) => eval_template(template, variables),
         PredicateValue::Regex(regex) => Ok(regex.inner.to_string()),
+        PredicateValue::Placeholder(Placeholder { expr, .. }) => render(expr, variables),
         // All others value should have failed in parsing:
-        _ => panic!("expect a string or a regex predicate value"),
+        _ => panic!("expect a string, a regex or a placeholder predicate value"),
     }
 }
 
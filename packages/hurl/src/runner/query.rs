@@ -15,59 +15,131 @@
  * limitations under the License.
  *
  */
+use std::time::Duration;
+
 use hurl_core::ast::{
     CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, Query, QueryValue,
-    RegexValue, SourceInfo, Template,
+    RegexValue, SourceInfo, Template, TimingsAttributeName, Whitespace,
 };
 use regex::Regex;
 use sha2::Digest;
 
 use crate::http;
+use crate::jsonpath::JsonpathResult;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::eval_context::EvalContext;
 use crate::runner::template::eval_template;
 use crate::runner::xpath::{Document, Format};
-use crate::runner::{filter, Number, Value, VariableSet};
+use crate::runner::{filter, EvalError, Number, Value, VariableSet};
 
 pub type QueryResult = Result<Option<Value>, RunnerError>;
 
+/// A custom query function, registered by name in [`crate::runner::RunnerOptions`].
+///
+/// The function receives the HTTP response and the evaluated query arguments, and returns `None`
+/// if the query has no result. This can be used to evaluate custom response formats (like a
+/// protobuf body) in asserts and captures.
+pub type CustomQueryFn = fn(&http::Response, &[Value]) -> Result<Option<Value>, EvalError>;
+
 /// Evaluates this `query` and returns a [`QueryResult`], using the HTTP `response` and `variables`.
+///
+/// The `context` holds the per-entry data used to evaluate the query (like XML namespaces bindings
+/// or the redirection chain).
 pub fn eval_query(
     query: &Query,
     variables: &VariableSet,
     response: &http::Response,
     cache: &mut BodyCache,
+    context: &EvalContext,
 ) -> QueryResult {
     match &query.value {
         QueryValue::Status => eval_query_status(response),
         QueryValue::Url => eval_query_url(response),
+        QueryValue::Redirects => eval_query_redirects(context.redirects),
         QueryValue::Header { name, .. } => eval_query_header(response, name, variables),
+        QueryValue::Trailer { name, .. } => eval_query_trailer(response, name, variables),
+        QueryValue::ServerTiming { name, .. } => {
+            eval_query_server_timing(response, name, variables, query.source_info)
+        }
+        QueryValue::Link { rel, .. } => eval_query_link(response, rel, variables),
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
             ..
         } => eval_query_cookie(response, name, attribute, variables),
         QueryValue::Body => eval_query_body(response, query.source_info),
-        QueryValue::Xpath { expr, .. } => {
-            eval_query_xpath(response, cache, expr, variables, query.source_info)
-        }
+        QueryValue::Xpath { expr, .. } => eval_query_xpath(
+            response,
+            cache,
+            expr,
+            variables,
+            context.xpath_namespaces,
+            query.source_info,
+        ),
         QueryValue::Jsonpath { expr, .. } => {
             eval_query_jsonpath(response, cache, expr, variables, query.source_info)
         }
+        QueryValue::Jsonpointer { expr, .. } => {
+            eval_query_jsonpointer(response, cache, expr, variables, query.source_info)
+        }
         QueryValue::Regex { value, .. } => {
             eval_query_regex(response, value, variables, query.source_info)
         }
         QueryValue::Variable { name, .. } => eval_query_variable(name, variables),
-        QueryValue::Duration => eval_query_duration(response),
+        QueryValue::Duration => eval_query_duration(context.transfer_duration),
         QueryValue::Bytes => eval_query_bytes(response, query.source_info),
+        QueryValue::RawBytes => eval_query_raw_bytes(response),
         QueryValue::Sha256 => eval_query_sha256(response, query.source_info),
         QueryValue::Md5 => eval_query_md5(response, query.source_info),
         QueryValue::Certificate {
             attribute_name: field,
             ..
-        } => eval_query_certificate(response, *field),
+        } => eval_query_certificate(response, *field, query.source_info),
+        QueryValue::Timings {
+            attribute_name: field,
+            ..
+        } => eval_query_timings(context.timings, *field),
+        QueryValue::Custom { name, args } => eval_query_custom(
+            response,
+            name,
+            args,
+            variables,
+            context.custom_queries,
+            query.source_info,
+        ),
     }
 }
 
+/// Evaluates the custom query `name` with its `args`, using the HTTP `response`.
+///
+/// The query function is looked up by name in the `custom_queries` registry.
+fn eval_query_custom(
+    response: &http::Response,
+    name: &str,
+    args: &[(Whitespace, Template)],
+    variables: &VariableSet,
+    custom_queries: &[(String, CustomQueryFn)],
+    source_info: SourceInfo,
+) -> QueryResult {
+    let Some((_, query)) = custom_queries.iter().find(|(n, _)| n == name) else {
+        let kind = RunnerErrorKind::QueryUnknown {
+            name: name.to_string(),
+        };
+        return Err(RunnerError::new(source_info, kind, false));
+    };
+    let args = args
+        .iter()
+        .map(|(_, arg)| eval_template(arg, variables).map(Value::String))
+        .collect::<Result<Vec<_>, _>>()?;
+    query(response, &args).map_err(|error| {
+        let kind = RunnerErrorKind::QueryCustom {
+            name: name.to_string(),
+            message: error.message,
+        };
+        RunnerError::new(source_info, kind, false)
+    })
+}
+
 /// Evaluates the response status code using the HTTP `response`.
 fn eval_query_status(response: &http::Response) -> QueryResult {
     Ok(Some(Value::Number(Number::Integer(i64::from(
@@ -80,6 +152,15 @@ fn eval_query_url(response: &http::Response) -> QueryResult {
     Ok(Some(Value::String(response.url.to_string())))
 }
 
+/// Evaluates the list of redirected URLs, preceding the final response.
+fn eval_query_redirects(redirects: &[http::Url]) -> QueryResult {
+    let values = redirects
+        .iter()
+        .map(|url| Value::String(url.to_string()))
+        .collect();
+    Ok(Some(Value::List(values)))
+}
+
 /// Evaluates a response query header `name`, on the HTTP `response` given a set of `variables`.
 fn eval_query_header(
     response: &http::Response,
@@ -88,17 +169,73 @@ fn eval_query_header(
 ) -> QueryResult {
     let name = eval_template(name, variables)?;
     let values = response.headers.values(&name);
+    Ok(header_values(&values))
+}
+
+/// Evaluates a response query trailer `name`, on the HTTP `response` given a set of `variables`.
+fn eval_query_trailer(
+    response: &http::Response,
+    name: &Template,
+    variables: &VariableSet,
+) -> QueryResult {
+    let name = eval_template(name, variables)?;
+    let values = response.trailers.values(&name);
+    Ok(header_values(&values))
+}
+
+/// Evaluates the duration of the `Server-Timing` metric `name`, on the HTTP `response` given a set
+/// of `variables`.
+///
+/// Returns a null value if the metric has no duration, and an error if the metric is not found.
+fn eval_query_server_timing(
+    response: &http::Response,
+    name: &Template,
+    variables: &VariableSet,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let name = eval_template(name, variables)?;
+    let metrics = response.headers.server_timing();
+    let Some(metric) = metrics.iter().find(|m| m.name == name) else {
+        let kind = RunnerErrorKind::QueryServerTimingMetricNotFound { name };
+        return Err(RunnerError::new(query_source_info, kind, false));
+    };
+    match metric.duration {
+        Some(duration) => Ok(Some(Value::Number(Number::Float(duration)))),
+        None => Ok(Some(Value::Null)),
+    }
+}
+
+/// Evaluates the target URL of the first link with relation type `rel`, in the `Link` headers
+/// of the HTTP `response` given a set of `variables`.
+fn eval_query_link(
+    response: &http::Response,
+    rel: &Template,
+    variables: &VariableSet,
+) -> QueryResult {
+    let rel = eval_template(rel, variables)?;
+    let value = response
+        .headers
+        .links()
+        .into_iter()
+        .find(|link| link.has_rel(&rel))
+        .map(|link| Value::String(link.target));
+    Ok(value)
+}
+
+/// Converts a list of header `values` to a single string value, a list of string values, or
+/// `None` if there are no values.
+fn header_values(values: &[&str]) -> Option<Value> {
     if values.is_empty() {
-        Ok(None)
+        None
     } else if values.len() == 1 {
         let value = values.first().unwrap().to_string();
-        Ok(Some(Value::String(value)))
+        Some(Value::String(value))
     } else {
         let values = values
             .iter()
             .map(|v| Value::String(v.to_string()))
             .collect();
-        Ok(Some(Value::List(values)))
+        Some(Value::List(values))
     }
 }
 
@@ -140,17 +277,19 @@ fn eval_query_body(response: &http::Response, query_source_info: SourceInfo) ->
 
 /// Evaluates a XPath expression on the HTTP `response` body, given a set of `variables`.
 ///
+/// The namespaces bindings `xpath_namespaces` are registered in the XML document.
 /// `query_source_info` is the source position of the query, used if an error is returned.
 fn eval_query_xpath(
     response: &http::Response,
     cache: &mut BodyCache,
     expr: &Template,
     variables: &VariableSet,
+    xpath_namespaces: &[(String, String)],
     query_source_info: SourceInfo,
 ) -> QueryResult {
     let doc = match cache.xml() {
         Some(d) => d,
-        None => parse_cache_xml(response, cache, query_source_info)?,
+        None => parse_cache_xml(response, cache, xpath_namespaces, query_source_info)?,
     };
     filter::eval_xpath_doc(doc, expr, variables)
 }
@@ -158,10 +297,12 @@ fn eval_query_xpath(
 /// Parse this HTTP `response` body to a structured XML document, and store the document to the
 /// response `cache`.
 ///
+/// The namespaces bindings `xpath_namespaces` are registered in the document.
 /// `query_source_info` is used for error reporting.
 fn parse_cache_xml<'cache>(
     response: &http::Response,
     cache: &'cache mut BodyCache,
+    xpath_namespaces: &[(String, String)],
     query_source_info: SourceInfo,
 ) -> Result<&'cache Document, RunnerError> {
     // Get the response as text if possible
@@ -180,13 +321,16 @@ fn parse_cache_xml<'cache>(
     } else {
         Format::Xml
     };
-    let Ok(doc) = Document::parse(&text, format) else {
+    let Ok(mut doc) = Document::parse(strip_bom(&text), format) else {
         return Err(RunnerError::new(
             query_source_info,
             RunnerErrorKind::QueryInvalidXml,
             false,
         ));
     };
+    for (prefix, uri) in xpath_namespaces {
+        doc.register_namespace(prefix, uri);
+    }
     // Everything is ok, we can put the response in the cache
     cache.set_xml(doc);
     Ok(cache.xml().unwrap())
@@ -201,12 +345,44 @@ fn eval_query_jsonpath(
     expr: &Template,
     variables: &VariableSet,
     query_source_info: SourceInfo,
+) -> QueryResult {
+    let result = eval_query_jsonpath_result(response, cache, expr, variables, query_source_info)?;
+    Ok(result.map(filter::jsonpath_value))
+}
+
+/// Evaluates a JSONPath expression on the HTTP `response` body, given a set of `variables`,
+/// keeping the shape of the JSONPath result.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+pub fn eval_query_jsonpath_result(
+    response: &http::Response,
+    cache: &mut BodyCache,
+    expr: &Template,
+    variables: &VariableSet,
+    query_source_info: SourceInfo,
+) -> Result<Option<JsonpathResult>, RunnerError> {
+    let json = match cache.json() {
+        Some(j) => j,
+        None => parse_cache_json(response, cache, query_source_info)?,
+    };
+    filter::eval_jsonpath_result(json, expr, variables)
+}
+
+/// Evaluates a JSON Pointer on the HTTP `response` body, given a set of `variables`.
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_jsonpointer(
+    response: &http::Response,
+    cache: &mut BodyCache,
+    expr: &Template,
+    variables: &VariableSet,
+    query_source_info: SourceInfo,
 ) -> QueryResult {
     let json = match cache.json() {
         Some(j) => j,
         None => parse_cache_json(response, cache, query_source_info)?,
     };
-    filter::eval_jsonpath_json(json, expr, variables)
+    filter::eval_jsonpointer_json(json, expr, variables)
 }
 
 /// Parse this HTTP `response` body to JSON, and store the document to the response `cache`.
@@ -228,7 +404,7 @@ fn parse_cache_json<'cache>(
             ))
         }
     };
-    let json = match serde_json::from_str(&text) {
+    let json = match serde_json::from_str(strip_bom(&text)) {
         Err(_) => {
             return Err(RunnerError::new(
                 query_source_info,
@@ -243,6 +419,13 @@ fn parse_cache_json<'cache>(
     Ok(cache.json().unwrap())
 }
 
+/// Removes a leading byte order mark (BOM) from a decoded `text`.
+///
+/// UTF-8 and UTF-16 BOMs are both decoded to the U+FEFF character.
+fn strip_bom(text: &str) -> &str {
+    text.strip_prefix('\u{feff}').unwrap_or(text)
+}
+
 /// Evaluates a regex query on the HTTP `response` body, given a set of `variables`.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -297,14 +480,36 @@ fn eval_query_variable(name: &Template, variables: &VariableSet) -> QueryResult
     }
 }
 
-/// Evaluates the effective duration of the HTTP `response` (only transfer time, assert and captures
-/// are not taken into account).
-fn eval_query_duration(response: &http::Response) -> QueryResult {
+/// Evaluates the effective duration of an entry, given its `transfer_duration` (only transfer time,
+/// assert and captures are not taken into account).
+///
+/// When the entry is retried, `transfer_duration` is the duration of the last attempt.
+fn eval_query_duration(transfer_duration: Duration) -> QueryResult {
     Ok(Some(Value::Number(Number::Integer(
-        response.duration.as_millis() as i64,
+        transfer_duration.as_millis() as i64,
     ))))
 }
 
+/// Evaluates a phase of the last HTTP call `timings`, in milliseconds.
+fn eval_query_timings(
+    timings: Option<&http::Timings>,
+    timings_attribute: TimingsAttributeName,
+) -> QueryResult {
+    let Some(timings) = timings else {
+        return Ok(None);
+    };
+    let duration = match timings_attribute {
+        TimingsAttributeName::NameLookup => timings.name_lookup,
+        TimingsAttributeName::Connect => timings.connect,
+        TimingsAttributeName::AppConnect => timings.app_connect,
+        TimingsAttributeName::PreTransfer => timings.pre_transfer,
+        TimingsAttributeName::StartTransfer => timings.start_transfer,
+        TimingsAttributeName::Total => timings.total,
+    };
+    let millis = duration.as_secs_f64() * 1000.0;
+    Ok(Some(Value::Number(Number::Float(millis))))
+}
+
 /// Evaluates the HTTP `response` body as bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -319,6 +524,11 @@ fn eval_query_bytes(response: &http::Response, query_source_info: SourceInfo) ->
     }
 }
 
+/// Evaluates the HTTP `response` body bytes as received, before any content decoding.
+fn eval_query_raw_bytes(response: &http::Response) -> QueryResult {
+    Ok(Some(Value::Bytes(response.body.clone())))
+}
+
 /// Evaluates the SHA-256 hash of the HTTP `response` body bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -359,24 +569,28 @@ fn eval_query_md5(response: &http::Response, query_source_info: SourceInfo) -> Q
 }
 
 /// Evaluates the SSL certificate attribute, of the HTTP `response`.
+///
+/// Returns an error if the `response` has no certificate (plain HTTP connection).
 fn eval_query_certificate(
     response: &http::Response,
     certificate_attribute: CertificateAttributeName,
+    query_source_info: SourceInfo,
 ) -> QueryResult {
-    if let Some(certificate) = &response.certificate {
-        let value = match certificate_attribute {
-            CertificateAttributeName::Subject => Value::String(certificate.subject.clone()),
-            CertificateAttributeName::Issuer => Value::String(certificate.issuer.clone()),
-            CertificateAttributeName::StartDate => Value::Date(certificate.start_date),
-            CertificateAttributeName::ExpireDate => Value::Date(certificate.expire_date),
-            CertificateAttributeName::SerialNumber => {
-                Value::String(certificate.serial_number.clone())
-            }
-        };
-        Ok(Some(value))
-    } else {
-        Ok(None)
-    }
+    let Some(certificate) = &response.certificate else {
+        return Err(RunnerError::new(
+            query_source_info,
+            RunnerErrorKind::QueryCertificateNotFound,
+            false,
+        ));
+    };
+    let value = match certificate_attribute {
+        CertificateAttributeName::Subject => Value::String(certificate.subject.clone()),
+        CertificateAttributeName::Issuer => Value::String(certificate.issuer.clone()),
+        CertificateAttributeName::StartDate => Value::Date(certificate.start_date),
+        CertificateAttributeName::ExpireDate => Value::Date(certificate.expire_date),
+        CertificateAttributeName::SerialNumber => Value::String(certificate.serial_number.clone()),
+    };
+    Ok(Some(value))
 }
 
 fn eval_cookie_attribute_name(
@@ -455,17 +669,19 @@ pub mod tests {
     use std::num::ParseIntError;
 
     use super::*;
-    use crate::http::{HeaderVec, HttpError, HttpVersion};
+    use crate::http::{Header, HeaderVec, HttpError, HttpVersion};
 
     fn default_response() -> http::Response {
         http::Response {
             version: HttpVersion::Http10,
             status: 200,
             headers: HeaderVec::new(),
+            trailers: HeaderVec::new(),
             body: vec![],
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            charset: None,
         }
     }
 
@@ -680,6 +896,7 @@ pub mod tests {
                 &variables,
                 &http::hello_http_response(),
                 &mut cache,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -718,7 +935,8 @@ pub mod tests {
                 &query_header,
                 &variables,
                 &http::hello_http_response(),
-                &mut cache
+                &mut cache,
+                &EvalContext::default()
             )
             .unwrap(),
             None
@@ -753,7 +971,8 @@ pub mod tests {
                 &query_header,
                 &variables,
                 &http::hello_http_response(),
-                &mut cache
+                &mut cache,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -762,103 +981,444 @@ pub mod tests {
     }
 
     #[test]
-    fn test_query_cookie() {
+    fn test_header_multiple_values() {
+        // header Set-Cookie
         let variables = VariableSet::new();
         let mut cache = BodyCache::new();
 
-        let space = Whitespace {
-            value: String::new(),
+        let query_header = Query {
             source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Header {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "Set-Cookie".to_string(),
+                        encoded: "Set-Cookie".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 20)),
+                },
+            },
         };
         let mut headers = HeaderVec::new();
-        headers.push(http::Header::new("Set-Cookie", "LSID=DQAAAKEaem_vYg; Path=/accounts; Expires=Wed, 13 Jan 2021 22:23:01 GMT; Secure; HttpOnly"));
-
+        headers.push(http::Header::new("Set-Cookie", "cookie1=value1"));
+        headers.push(http::Header::new("Content-Type", "text/plain"));
+        headers.push(http::Header::new("set-cookie", "cookie2=value2"));
+        headers.push(http::Header::new("Set-Cookie", "cookie3=value3"));
         let response = http::Response {
             headers,
             ..default_response()
         };
 
-        // cookie "LSID"
+        // Values are returned in the order of the response headers.
+        assert_eq!(
+            eval_query(
+                &query_header,
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String(String::from("cookie1=value1")),
+                Value::String(String::from("cookie2=value2")),
+                Value::String(String::from("cookie3=value3")),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_redirects() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
         let query = Query {
-            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-            value: QueryValue::Cookie {
-                space0: space.clone(),
-                expr: CookiePath {
-                    name: Template {
-                        delimiter: Some('"'),
-                        elements: vec![TemplateElement::String {
-                            value: "LSID".to_string(),
-                            encoded: "LSID".to_string(),
-                        }],
-                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-                    },
-                    attribute: None,
-                },
-            },
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: QueryValue::Redirects,
         };
+
+        let redirects = vec![
+            "http://localhost:8000/redirect/start".parse().unwrap(),
+            "http://localhost:8000/redirect/step2".parse().unwrap(),
+        ];
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
-            Value::String("DQAAAKEaem_vYg".to_string())
+            eval_query(
+                &query,
+                &variables,
+                &http::hello_http_response(),
+                &mut cache,
+                &EvalContext {
+                    redirects: &redirects,
+                    ..Default::default()
+                }
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String("http://localhost:8000/redirect/start".to_string()),
+                Value::String("http://localhost:8000/redirect/step2".to_string()),
+            ])
         );
 
-        // cookie "LSID[Path]"
-        let query = Query {
+        // Without any redirection, the query returns an empty list.
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::hello_http_response(),
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![])
+        );
+    }
+
+    #[test]
+    fn test_trailer() {
+        // trailer "grpc-status"
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+
+        let query_trailer = Query {
             source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-            value: QueryValue::Cookie {
-                space0: space.clone(),
-                expr: CookiePath {
-                    name: Template {
-                        delimiter: Some('"'),
-                        elements: vec![TemplateElement::String {
-                            value: "LSID".to_string(),
-                            encoded: "LSID".to_string(),
-                        }],
-                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-                    },
-                    attribute: Some(CookieAttribute {
-                        space0: space.clone(),
-                        name: CookieAttributeName::Path("Path".to_string()),
-                        space1: space.clone(),
-                    }),
+            value: QueryValue::Trailer {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "grpc-status".to_string(),
+                        encoded: "grpc-status".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 22)),
                 },
             },
         };
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new("grpc-status", "header"));
+        let mut trailers = HeaderVec::new();
+        trailers.push(http::Header::new("grpc-status", "0"));
+        let response = http::Response {
+            headers,
+            trailers,
+            ..default_response()
+        };
+
+        // Trailers are distinct from headers.
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
-            Value::String("/accounts".to_string())
+            eval_query(
+                &query_trailer,
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(String::from("0"))
+        );
+        assert_eq!(
+            eval_query(
+                &query_trailer,
+                &variables,
+                &http::hello_http_response(),
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap(),
+            None
         );
+    }
 
-        // cookie "LSID[Secure]"
-        let query = Query {
-            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-            value: QueryValue::Cookie {
-                space0: space.clone(),
-                expr: CookiePath {
+    #[test]
+    fn test_server_timing() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+
+        // server-timing "<name>"
+        fn query_server_timing(name: &str) -> Query {
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+                value: QueryValue::ServerTiming {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 15)),
+                    },
                     name: Template {
                         delimiter: Some('"'),
                         elements: vec![TemplateElement::String {
-                            value: "LSID".to_string(),
-                            encoded: "LSID".to_string(),
+                            value: name.to_string(),
+                            encoded: name.to_string(),
                         }],
-                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                        source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 20)),
                     },
-                    attribute: Some(CookieAttribute {
-                        space0: space.clone(),
-                        name: CookieAttributeName::Secure("Secure".to_string()),
-                        space1: space.clone(),
-                    }),
                 },
-            },
+            }
+        }
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new(
+            "Server-Timing",
+            "db;dur=53, app;dur=47.2",
+        ));
+        headers.push(http::Header::new(
+            "Server-Timing",
+            "cache;desc=\"Cache Read\"",
+        ));
+        let response = http::Response {
+            headers,
+            ..default_response()
         };
+
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
-            Value::Unit
+            eval_query(
+                &query_server_timing("db"),
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(53.0))
+        );
+        assert_eq!(
+            eval_query(
+                &query_server_timing("app"),
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(47.2))
+        );
+
+        // A metric without duration returns null.
+        assert_eq!(
+            eval_query(
+                &query_server_timing("cache"),
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Null
+        );
+
+        // A missing metric is an error.
+        let error = eval_query(
+            &query_server_timing("cpu"),
+            &variables,
+            &response,
+            &mut cache,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryServerTimingMetricNotFound {
+                name: "cpu".to_string()
+            }
+        );
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20))
+        );
+    }
+
+    #[test]
+    fn test_link() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+
+        // link "<rel>"
+        fn query_link(rel: &str) -> Query {
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+                value: QueryValue::Link {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                    },
+                    rel: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: rel.to_string(),
+                            encoded: rel.to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 12)),
+                    },
+                },
+            }
+        }
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new(
+            "Link",
+            "<https://api.example.com/items?page=1>; rel=\"first prev\", \
+             <https://api.example.com/items?page=3>; rel=\"next\"",
+        ));
+        headers.push(http::Header::new(
+            "Link",
+            "<https://api.example.com/items?page=9>; rel=\"last\"",
+        ));
+        let response = http::Response {
+            headers,
+            ..default_response()
+        };
+
+        let eval = |rel: &str, cache: &mut BodyCache| {
+            eval_query(
+                &query_link(rel),
+                &variables,
+                &response,
+                cache,
+                &EvalContext::default(),
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            eval("next", &mut cache).unwrap(),
+            Value::String("https://api.example.com/items?page=3".to_string())
+        );
+        assert_eq!(
+            eval("prev", &mut cache).unwrap(),
+            Value::String("https://api.example.com/items?page=1".to_string())
+        );
+        assert_eq!(
+            eval("last", &mut cache).unwrap(),
+            Value::String("https://api.example.com/items?page=9".to_string())
+        );
+        assert_eq!(eval("alternate", &mut cache), None);
+    }
+
+    #[test]
+    fn test_query_cookie() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+
+        let space = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new("Set-Cookie", "LSID=DQAAAKEaem_vYg; Path=/accounts; Expires=Wed, 13 Jan 2021 22:23:01 GMT; Secure; HttpOnly"));
+
+        let response = http::Response {
+            headers,
+            ..default_response()
+        };
+
+        // cookie "LSID"
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Cookie {
+                space0: space.clone(),
+                expr: CookiePath {
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "LSID".to_string(),
+                            encoded: "LSID".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    attribute: None,
+                },
+            },
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("DQAAAKEaem_vYg".to_string())
+        );
+
+        // cookie "LSID[Path]"
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Cookie {
+                space0: space.clone(),
+                expr: CookiePath {
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "LSID".to_string(),
+                            encoded: "LSID".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    attribute: Some(CookieAttribute {
+                        space0: space.clone(),
+                        name: CookieAttributeName::Path("Path".to_string()),
+                        space1: space.clone(),
+                    }),
+                },
+            },
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("/accounts".to_string())
+        );
+
+        // cookie "LSID[Secure]"
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Cookie {
+                space0: space.clone(),
+                expr: CookiePath {
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "LSID".to_string(),
+                            encoded: "LSID".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    attribute: Some(CookieAttribute {
+                        space0: space.clone(),
+                        name: CookieAttributeName::Secure("Secure".to_string()),
+                        space1: space.clone(),
+                    }),
+                },
+            },
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Unit
         );
 
         // cookie "LSID[Domain]"
@@ -884,7 +1444,14 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache).unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap(),
             None
         );
     }
@@ -985,6 +1552,7 @@ pub mod tests {
                 &variables,
                 &http::hello_http_response(),
                 &mut cache,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -998,6 +1566,7 @@ pub mod tests {
             &variables,
             &http::bytes_http_response(),
             &mut cache,
+            &EvalContext::default(),
         )
         .err()
         .unwrap();
@@ -1022,9 +1591,15 @@ pub mod tests {
             body: vec![200],
             ..default_response()
         };
-        let error = eval_query(&xpath_users(), &variables, &http_response, &mut cache)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &xpath_users(),
+            &variables,
+            &http_response,
+            &mut cache,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(
             error.kind,
@@ -1062,6 +1637,7 @@ pub mod tests {
             &variables,
             &http::xml_two_users_http_response(),
             &mut cache,
+            &EvalContext::default(),
         )
         .unwrap_err();
         assert_eq!(error.kind, RunnerErrorKind::QueryInvalidXpathEval);
@@ -1079,6 +1655,7 @@ pub mod tests {
                 &variables,
                 &http::xml_two_users_http_response(),
                 &mut cache,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1090,6 +1667,7 @@ pub mod tests {
                 &variables,
                 &http::xml_two_users_http_response(),
                 &mut cache,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1131,6 +1709,7 @@ pub mod tests {
                 &variables,
                 &http::html_http_response(),
                 &mut cache,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1167,6 +1746,7 @@ pub mod tests {
             &variables,
             &http::json_http_response(),
             &mut cache,
+            &EvalContext::default(),
         )
         .unwrap_err();
         assert_eq!(
@@ -1192,9 +1772,15 @@ pub mod tests {
             body: String::into_bytes(String::from("xxx")),
             ..default_response()
         };
-        let error = eval_query(&jsonpath_success(), &variables, &http_response, &mut cache)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &jsonpath_success(),
+            &variables,
+            &http_response,
+            &mut cache,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
     }
@@ -1209,11 +1795,170 @@ pub mod tests {
             ..default_response()
         };
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http_response, &mut cache).unwrap(),
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http_response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap(),
             None
         );
     }
 
+    #[test]
+    fn test_query_json_with_bom() {
+        let variables = VariableSet::new();
+
+        // UTF-8 BOM
+        let mut cache = BodyCache::new();
+        let mut body = vec![0xef, 0xbb, 0xbf];
+        body.extend_from_slice(b"{\"success\":true}");
+        let http_response = http::Response {
+            body,
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http_response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bool(true)
+        );
+
+        // UTF-16 BOM
+        let mut cache = BodyCache::new();
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new(
+            "Content-Type",
+            "application/json; charset=utf-16le",
+        ));
+        let mut body = vec![0xff, 0xfe];
+        body.extend(
+            "{\"success\":true}"
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes),
+        );
+        let http_response = http::Response {
+            headers,
+            body,
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http_response,
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bool(true)
+        );
+    }
+
+    #[test]
+    fn test_query_xpath_with_bom() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+
+        let mut headers = HeaderVec::new();
+        headers.push(http::Header::new(
+            "Content-Type",
+            "application/xml; charset=utf-8",
+        ));
+        let mut body = vec![0xef, 0xbb, 0xbf];
+        body.extend_from_slice(
+            b"<?xml version=\"1.0\"?><users><user id=\"1\">Bob</user><user id=\"2\">Bill</user></users>",
+        );
+        let http_response = http::Response {
+            headers,
+            body,
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &xpath_users(),
+                &variables,
+                &http_response,
+                &mut cache,
+                &EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Nodeset(2)
+        );
+    }
+
+    #[test]
+    fn test_query_jsonpointer() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = |pointer: &str| Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: QueryValue::Jsonpointer {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                },
+                expr: Template {
+                    elements: vec![TemplateElement::String {
+                        value: pointer.to_string(),
+                        encoded: pointer.to_string(),
+                    }],
+                    delimiter: Some('"'),
+                    source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 1)),
+                },
+            },
+        };
+
+        assert_eq!(
+            eval_query(
+                &query("/errors/1/id"),
+                &variables,
+                &http::json_http_response(),
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(String::from("error2"))
+        );
+        assert_eq!(
+            eval_query(
+                &query("/errors/2"),
+                &variables,
+                &http::json_http_response(),
+                &mut cache,
+                &EvalContext::default()
+            )
+            .unwrap(),
+            None
+        );
+        let error = eval_query(
+            &query("errors"),
+            &variables,
+            &http::json_http_response(),
+            &mut cache,
+            &EvalContext::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidJsonpointerExpression {
+                value: "errors".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_query_json() {
         let variables = VariableSet::new();
@@ -1224,7 +1969,8 @@ pub mod tests {
                 &jsonpath_success(),
                 &variables,
                 &http::json_http_response(),
-                &mut cache
+                &mut cache,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -1235,7 +1981,8 @@ pub mod tests {
                 &jsonpath_errors(),
                 &variables,
                 &http::json_http_response(),
-                &mut cache
+                &mut cache,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -1262,7 +2009,8 @@ pub mod tests {
                 &regex_name(),
                 &variables,
                 &http::hello_http_response(),
-                &mut cache
+                &mut cache,
+                &EvalContext::default()
             )
             .unwrap()
             .unwrap(),
@@ -1274,6 +2022,7 @@ pub mod tests {
             &variables,
             &http::hello_http_response(),
             &mut cache,
+            &EvalContext::default(),
         )
         .err()
         .unwrap();
@@ -1298,6 +2047,7 @@ pub mod tests {
                 &variables,
                 &http::hello_http_response(),
                 &mut cache,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1329,6 +2079,7 @@ pub mod tests {
                     ..default_response()
                 },
                 &mut cache,
+                &EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1339,16 +2090,75 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_raw_bytes() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let compressed = vec![
+            0x1f, 0x8b, 0x08, 0x00, 0xed, 0x0c, 0x84, 0x5f, 0x00, 0x03, 0xf3, 0x48, 0xcd, 0xc9,
+            0xc9, 0x57, 0x08, 0xcf, 0x2f, 0xca, 0x49, 0x51, 0x04, 0x00, 0xa3, 0x1c, 0x29, 0x1c,
+            0x0c, 0x00, 0x00, 0x00,
+        ];
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "gzip"));
+        let response = http::Response {
+            headers,
+            body: compressed.clone(),
+            ..default_response()
+        };
+        let mut eval = |value| {
+            let query = Query {
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                value,
+            };
+            eval_query(
+                &query,
+                &variables,
+                &response,
+                &mut cache,
+                &EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap()
+        };
+
+        // `rawBytes` is the compressed payload, while `bytes` is decompressed.
+        let Value::Bytes(raw_bytes) = eval(QueryValue::RawBytes) else {
+            panic!("rawBytes query must return bytes");
+        };
+        assert_eq!(raw_bytes, compressed);
+        assert_eq!(
+            sha2::Sha256::digest(&raw_bytes).to_vec(),
+            decode_hex("9e48be43c79fdb714678ce5406b43e27371a31c3d55ce23247114ee138768f4f").unwrap()
+        );
+        assert_eq!(
+            eval(QueryValue::Bytes),
+            Value::Bytes(b"Hello World!".to_vec())
+        );
+        assert_eq!(
+            eval(QueryValue::Sha256),
+            Value::Bytes(
+                decode_hex("7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069")
+                    .unwrap()
+            )
+        );
+    }
+
     #[test]
     fn test_query_certificate() {
-        assert!(eval_query_certificate(
+        let error = eval_query_certificate(
             &http::Response {
                 ..default_response()
             },
-            CertificateAttributeName::Subject
+            CertificateAttributeName::Subject,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 22)),
         )
-        .unwrap()
-        .is_none());
+        .unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::QueryCertificateNotFound);
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 22))
+        );
         assert_eq!(
             eval_query_certificate(
                 &http::Response {
@@ -1361,11 +2171,168 @@ pub mod tests {
                     }),
                     ..default_response()
                 },
-                CertificateAttributeName::Subject
+                CertificateAttributeName::Subject,
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 22)),
             )
             .unwrap()
             .unwrap(),
             Value::String("A=B, C=D".to_string())
         );
     }
+
+    #[test]
+    fn test_query_duration() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: QueryValue::Duration,
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::hello_http_response(),
+                &mut cache,
+                &EvalContext {
+                    transfer_duration: Duration::from_millis(230),
+                    ..Default::default()
+                }
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(230))
+        );
+    }
+
+    #[test]
+    fn test_query_timings() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let timings = http::Timings {
+            name_lookup: Duration::from_micros(1_500),
+            start_transfer: Duration::from_millis(120),
+            total: Duration::from_millis(230),
+            ..Default::default()
+        };
+        let query = |attribute_name| Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 16)),
+            value: QueryValue::Timings {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                attribute_name,
+            },
+        };
+        let values = [
+            (TimingsAttributeName::NameLookup, 1.5),
+            (TimingsAttributeName::Connect, 0.0),
+            (TimingsAttributeName::StartTransfer, 120.0),
+            (TimingsAttributeName::Total, 230.0),
+        ];
+        for (attribute_name, expected) in values {
+            assert_eq!(
+                eval_query(
+                    &query(attribute_name),
+                    &variables,
+                    &http::hello_http_response(),
+                    &mut cache,
+                    &EvalContext {
+                        timings: Some(&timings),
+                        ..Default::default()
+                    }
+                )
+                .unwrap()
+                .unwrap(),
+                Value::Number(Number::Float(expected))
+            );
+        }
+    }
+
+    /// A custom query returning the byte size of the response body.
+    fn body_size(response: &http::Response, args: &[Value]) -> Result<Option<Value>, EvalError> {
+        match args {
+            [] => Ok(Some(Value::Number(Number::Integer(
+                response.body.len() as i64
+            )))),
+            _ => Err(EvalError::new("no argument expected")),
+        }
+    }
+
+    #[test]
+    fn test_query_custom() {
+        let variables = VariableSet::new();
+        let custom_queries = vec![("bodySize".to_string(), body_size as CustomQueryFn)];
+        let context = EvalContext {
+            custom_queries: &custom_queries,
+            ..Default::default()
+        };
+        let mut cache = BodyCache::new();
+
+        let query = |args: Vec<(Whitespace, Template)>| Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: QueryValue::Custom {
+                name: "bodySize".to_string(),
+                args,
+            },
+        };
+        assert_eq!(
+            eval_query(
+                &query(vec![]),
+                &variables,
+                &http::hello_http_response(),
+                &mut cache,
+                &context
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(12))
+        );
+
+        let arg = (
+            Whitespace {
+                value: " ".to_string(),
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            },
+            Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: "foo".to_string(),
+                    encoded: "foo".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            },
+        );
+        let error = eval_query(
+            &query(vec![arg]),
+            &variables,
+            &http::hello_http_response(),
+            &mut cache,
+            &context,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryCustom {
+                name: "bodySize".to_string(),
+                message: "no argument expected".to_string(),
+            }
+        );
+
+        let error = eval_query(
+            &query(vec![]),
+            &variables,
+            &http::hello_http_response(),
+            &mut BodyCache::new(),
+            &EvalContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryUnknown {
+                name: "bodySize".to_string()
+            }
+        );
+    }
 }
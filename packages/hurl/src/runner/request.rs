@@ -194,8 +194,8 @@ fn eval_method(method: &Method) -> http::Method {
 mod tests {
     use crate::runner::Value;
     use hurl_core::ast::{
-        Comment, Expr, ExprKind, KeyValue, LineTerminator, Placeholder, Section, SectionValue,
-        SourceInfo, TemplateElement, Variable, Whitespace,
+        Comment, Cookie, Expr, ExprKind, KeyValue, LineTerminator, Placeholder, Section,
+        SectionValue, SourceInfo, TemplateElement, Variable, Whitespace,
     };
     use hurl_core::reader::Pos;
 
@@ -352,6 +352,72 @@ mod tests {
         }
     }
 
+    fn cookies_request() -> Request {
+        let line_terminator = LineTerminator {
+            space0: whitespace(),
+            comment: None,
+            newline: whitespace(),
+        };
+        let template = |value: &str| Template {
+            delimiter: None,
+            elements: vec![TemplateElement::String {
+                value: value.to_string(),
+                encoded: value.to_string(),
+            }],
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let cookie = |name: Template, value: Template| Cookie {
+            line_terminators: vec![],
+            space0: whitespace(),
+            name,
+            space1: whitespace(),
+            space2: whitespace(),
+            value,
+            line_terminator0: line_terminator.clone(),
+        };
+        Request {
+            line_terminators: vec![],
+            space0: whitespace(),
+            method: Method("GET".to_string()),
+            space1: whitespace(),
+            url: template("http://localhost:8000/cookies"),
+            line_terminator0: line_terminator.clone(),
+            headers: vec![],
+            sections: vec![Section {
+                line_terminators: vec![],
+                space0: whitespace(),
+                line_terminator0: line_terminator.clone(),
+                value: SectionValue::Cookies(vec![
+                    cookie(template("cookie1"), template("valueA")),
+                    cookie(
+                        template("cookie2"),
+                        Template {
+                            delimiter: None,
+                            elements: vec![TemplateElement::Placeholder(Placeholder {
+                                space0: whitespace(),
+                                expr: Expr {
+                                    kind: ExprKind::Variable(Variable {
+                                        name: "name".to_string(),
+                                        source_info: SourceInfo::new(
+                                            Pos::new(1, 7),
+                                            Pos::new(1, 11),
+                                        ),
+                                    }),
+                                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 11)),
+                                },
+                                space1: whitespace(),
+                            })],
+                            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                        },
+                    ),
+                ]),
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            }],
+            body: None,
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        }
+    }
+
     #[test]
     fn test_error_variable() {
         let variables = VariableSet::new();
@@ -398,6 +464,29 @@ mod tests {
         assert_eq!(http_request, http::query_http_request());
     }
 
+    #[test]
+    fn test_cookies_request() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert(String::from("name"), Value::String(String::from("Bob")))
+            .unwrap();
+        let http_request =
+            eval_request(&cookies_request(), &variables, &ContextDir::default()).unwrap();
+        assert_eq!(
+            http_request.cookies,
+            vec![
+                http::RequestCookie {
+                    name: "cookie1".to_string(),
+                    value: "valueA".to_string(),
+                },
+                http::RequestCookie {
+                    name: "cookie2".to_string(),
+                    value: "Bob".to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn clear_cookie_store() {
         assert!(!cookie_storage_clear(&hello_request()));
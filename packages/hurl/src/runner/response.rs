@@ -15,11 +15,15 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Base64, Body, Bytes, Hex, Response, SourceInfo, StatusValue};
+use hurl_core::ast::{
+    Assert, Base64, Body, Bytes, Hex, Query, QueryValue, Response, SourceInfo, StatusValue,
+};
 
 use crate::http;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::eval_context::EvalContext;
+use crate::runner::predicate::strip_trailing_newline;
 use crate::runner::result::{AssertResult, CaptureResult};
 use crate::runner::{assert, body, capture, json, multiline, template, Value, VariableSet};
 use crate::util::path::ContextDir;
@@ -50,19 +54,27 @@ pub fn eval_version_status_asserts(
     asserts
 }
 
-/// Returns a list of assert results, given a set of `variables`, an actual `http_response` and a spec `response`.
+/// Returns a list of assert results, given a set of `variables`, an actual `http_request` /
+/// `http_response` and a spec `response`.
 ///
 /// Asserts on status and version and not run in this function, there are run with `eval_version_status_asserts`
 /// as they're semantically stronger.
 ///
 /// The `cache` is used to store XML / JSON structured response data and avoid redundant parsing
 /// operation on the response.
+///
+/// If `ignore_trailing_newline` is `true`, a single trailing newline is ignored when comparing the
+/// response body.
+#[allow(clippy::too_many_arguments)]
 pub fn eval_asserts(
     response: &Response,
     variables: &VariableSet,
+    http_request: &http::Request,
     http_response: &http::Response,
     cache: &mut BodyCache,
     context_dir: &ContextDir,
+    ignore_trailing_newline: bool,
+    context: &EvalContext,
 ) -> Vec<AssertResult> {
     let mut asserts = vec![];
 
@@ -142,18 +154,104 @@ pub fn eval_asserts(
     // Second, evaluates implicit asserts on response body.
     if let Some(body) = &response.body {
         let assert = eval_implicit_body_asserts(body, variables, http_response, context_dir);
+        let assert = if ignore_trailing_newline {
+            strip_body_trailing_newline(assert)
+        } else {
+            assert
+        };
         asserts.push(assert);
     }
 
     // Then, checks all the explicit asserts.
     for assert in response.asserts() {
-        let assert_result =
-            assert::eval_explicit_assert(assert, variables, http_response, cache, context_dir);
+        let assert_result = assert::eval_explicit_assert(
+            assert,
+            variables,
+            http_response,
+            cache,
+            context_dir,
+            ignore_trailing_newline,
+            context,
+        );
+        let assert_result = match assert_result {
+            AssertResult::Explicit {
+                actual: Err(error),
+                source_info,
+                predicate_result,
+            } => AssertResult::Explicit {
+                actual: Err(check_head_request(error, &assert.query, http_request)),
+                source_info,
+                predicate_result,
+            },
+            _ => assert_result,
+        };
         asserts.push(assert_result);
     }
     asserts
 }
 
+/// Returns a list of assert results for `asserts` that are not part of the Hurl file (like the
+/// ones injected with `--assert` on the command line), given a set of `variables` and an actual
+/// `http_response`.
+///
+/// As these asserts have no location in the Hurl file, their results and errors are reported at
+/// `source_info` (the source of the entry).
+#[allow(clippy::too_many_arguments)]
+pub fn eval_injected_asserts(
+    asserts: &[Assert],
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    http_response: &http::Response,
+    cache: &mut BodyCache,
+    context_dir: &ContextDir,
+    ignore_trailing_newline: bool,
+    context: &EvalContext,
+) -> Vec<AssertResult> {
+    asserts
+        .iter()
+        .map(|assert| {
+            let assert_result = assert::eval_explicit_assert(
+                assert,
+                variables,
+                http_response,
+                cache,
+                context_dir,
+                ignore_trailing_newline,
+                context,
+            );
+            match assert_result {
+                AssertResult::Explicit {
+                    actual,
+                    predicate_result,
+                    ..
+                } => AssertResult::Explicit {
+                    actual: actual.map_err(|e| RunnerError { source_info, ..e }),
+                    source_info,
+                    predicate_result: predicate_result
+                        .map(|r| r.map_err(|e| RunnerError { source_info, ..e })),
+                },
+                _ => assert_result,
+            }
+        })
+        .collect()
+}
+
+/// Removes a single trailing newline from the actual and expected values of a body assert `result`.
+fn strip_body_trailing_newline(result: AssertResult) -> AssertResult {
+    match result {
+        AssertResult::Body {
+            actual,
+            expected,
+            source_info,
+        } => AssertResult::Body {
+            actual: actual.map(|v| strip_trailing_newline(&v)),
+            expected: expected.map(|v| strip_trailing_newline(&v)),
+            source_info,
+        },
+        _ => result,
+    }
+}
+
 /// Check the body of an actual HTTP response against a spec body, given a set of variables.
 fn eval_implicit_body_asserts(
     spec_body: &Body,
@@ -347,16 +445,21 @@ fn eval_implicit_body_asserts(
     }
 }
 
-/// Evaluates captures from this HTTP `http_response`, given a set of `variables`.
+/// Evaluates captures from this HTTP `http_response` (answered to `http_request`), given a set of
+/// `variables`.
 pub fn eval_captures(
     response: &Response,
+    http_request: &http::Request,
     http_response: &http::Response,
     cache: &mut BodyCache,
     variables: &mut VariableSet,
+    context: &EvalContext,
 ) -> Result<Vec<CaptureResult>, RunnerError> {
     let mut captures = vec![];
     for capture in response.captures() {
-        let capture_result = capture::eval_capture(capture, variables, http_response, cache)?;
+        let capture_result =
+            capture::eval_capture(capture, variables, http_response, cache, context)
+                .map_err(|error| check_head_request(error, &capture.query, http_request))?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         let name = capture_result.name.clone();
@@ -390,6 +493,48 @@ pub fn eval_captures(
     Ok(captures)
 }
 
+/// Checks if an `error` raised by the evaluation of a `query` is due to the missing body of a
+/// response to a `HEAD` request.
+///
+/// A response to a `HEAD` request has no body: in this case, errors on body queries (parsing an
+/// empty JSON document for instance) are replaced by a more explicit [`RunnerErrorKind::BodyOnHeadRequest`].
+fn check_head_request(
+    error: RunnerError,
+    query: &Query,
+    http_request: &http::Request,
+) -> RunnerError {
+    if http_request.method != "HEAD" || !is_body_query(query) {
+        return error;
+    }
+    match error.kind {
+        RunnerErrorKind::FilterMissingInput
+        | RunnerErrorKind::NoQueryResult
+        | RunnerErrorKind::QueryInvalidJson
+        | RunnerErrorKind::QueryInvalidXml => RunnerError::new(
+            query.source_info,
+            RunnerErrorKind::BodyOnHeadRequest,
+            error.assert,
+        ),
+        _ => error,
+    }
+}
+
+/// Returns `true` if this `query` is evaluated on the response body.
+fn is_body_query(query: &Query) -> bool {
+    matches!(
+        query.value,
+        QueryValue::Body
+            | QueryValue::Bytes
+            | QueryValue::Jsonpath { .. }
+            | QueryValue::Jsonpointer { .. }
+            | QueryValue::Md5
+            | QueryValue::RawBytes
+            | QueryValue::Regex { .. }
+            | QueryValue::Sha256
+            | QueryValue::Xpath { .. }
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::{
@@ -399,8 +544,14 @@ mod tests {
 
     use self::super::super::{assert, capture};
     use super::*;
+    use crate::http::{HeaderVec, Url};
     use crate::runner::Number;
 
+    fn users_http_request(method: &str) -> http::Request {
+        let url = "http://localhost:8000/users".parse::<Url>().unwrap();
+        http::Request::new(method, url, HeaderVec::new(), vec![])
+    }
+
     pub fn user_response() -> Response {
         let whitespace = Whitespace {
             value: String::from(" "),
@@ -457,9 +608,12 @@ mod tests {
             eval_asserts(
                 &user_response(),
                 &variables,
+                &users_http_request("GET"),
                 &http::xml_two_users_http_response(),
                 &mut cache,
                 &context_dir,
+                false,
+                &EvalContext::default(),
             ),
             vec![AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(2)))),
@@ -504,9 +658,11 @@ mod tests {
         assert_eq!(
             eval_captures(
                 &user_response(),
+                &users_http_request("GET"),
                 &http::xml_two_users_http_response(),
                 &mut cache,
                 &mut variables,
+                &EvalContext::default(),
             )
             .unwrap(),
             vec![CaptureResult {
@@ -515,4 +671,68 @@ mod tests {
             }]
         );
     }
+
+    #[test]
+    pub fn test_eval_body_queries_on_head_request() {
+        let mut variables = VariableSet::new();
+        let context_dir = ContextDir::default();
+        let http_response = http::Response {
+            body: vec![],
+            ..http::xml_two_users_http_response()
+        };
+
+        let mut cache = BodyCache::new();
+        assert_eq!(
+            eval_asserts(
+                &user_response(),
+                &variables,
+                &users_http_request("HEAD"),
+                &http_response,
+                &mut cache,
+                &context_dir,
+                false,
+                &EvalContext::default(),
+            ),
+            vec![AssertResult::Explicit {
+                actual: Err(RunnerError::new(
+                    SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+                    RunnerErrorKind::BodyOnHeadRequest,
+                    false,
+                )),
+                source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 24)),
+                predicate_result: None,
+            }]
+        );
+
+        let mut cache = BodyCache::new();
+        assert_eq!(
+            eval_captures(
+                &user_response(),
+                &users_http_request("HEAD"),
+                &http_response,
+                &mut cache,
+                &mut variables,
+                &EvalContext::default(),
+            )
+            .unwrap_err()
+            .kind,
+            RunnerErrorKind::BodyOnHeadRequest
+        );
+
+        // On other methods, the original error is kept.
+        let mut cache = BodyCache::new();
+        assert_eq!(
+            eval_captures(
+                &user_response(),
+                &users_http_request("GET"),
+                &http_response,
+                &mut cache,
+                &mut variables,
+                &EvalContext::default(),
+            )
+            .unwrap_err()
+            .kind,
+            RunnerErrorKind::QueryInvalidXml
+        );
+    }
 }
@@ -71,6 +71,48 @@ impl HurlResult {
         }
         errors
     }
+
+    /// Returns the number of entries executed in this `HurlResult`.
+    ///
+    /// Retried entries are only counted once.
+    pub fn entries_count(&self) -> usize {
+        self.effective_entries().count()
+    }
+
+    /// Returns the number of effective failed asserts of this `HurlResult`.
+    pub fn failed_asserts_count(&self) -> usize {
+        self.errors()
+            .iter()
+            .filter(|(error, _)| error.assert)
+            .count()
+    }
+
+    /// Returns the ratio (between 0 and 1) of entries executed without errors.
+    ///
+    /// A result without any entry has a success rate of 1.
+    pub fn success_rate(&self) -> f64 {
+        let count = self.entries_count();
+        if count == 0 {
+            return 1.0;
+        }
+        let succeeded = self
+            .effective_entries()
+            .filter(|entry| entry.errors.is_empty())
+            .count();
+        succeeded as f64 / count as f64
+    }
+
+    /// Returns the effective entries of this `HurlResult`: entries results that are followed by a
+    /// retry of the same entry are ignored.
+    fn effective_entries(&self) -> impl Iterator<Item = &EntryResult> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| match self.entries.get(i + 1) {
+                Some(next) if next.entry_index == entry.entry_index => None,
+                _ => Some(entry),
+            })
+    }
 }
 
 /// Represents the execution result of an entry.
@@ -95,8 +137,14 @@ pub struct EntryResult {
     /// server is requested to send compressed response, and the response should be uncompressed
     /// when outputted on stdout.
     pub compressed: bool,
+    /// The number of requests of this entry executed without verifying SSL certificates.
+    pub insecure_requests: usize,
     /// The debug curl command line from this entry result.
     pub curl_cmd: CurlCmd,
+    /// The response output set in the entry `[Options]` section, if any.
+    pub output: Option<Output>,
+    /// The name of the entry set in the entry `[Options]` section, if any.
+    pub name: Option<String>,
 }
 
 impl Default for EntryResult {
@@ -110,7 +158,10 @@ impl Default for EntryResult {
             errors: vec![],
             transfer_duration: Duration::from_millis(0),
             compressed: false,
+            insecure_requests: 0,
             curl_cmd: CurlCmd::default(),
+            output: None,
+            name: None,
         }
     }
 }
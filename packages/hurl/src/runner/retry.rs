@@ -0,0 +1,150 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// Strategy used to compute the interval between two retries of an entry (see `--retry-backoff`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RetryBackoff {
+    /// The interval is the same for each retry.
+    #[default]
+    Constant,
+    /// The interval doubles after each retry.
+    Exponential,
+}
+
+impl RetryBackoff {
+    /// Returns the interval to pause before the retry number `retry_count` (starting at 1), given
+    /// a base `interval` and an optional `max_interval` cap.
+    pub fn interval(
+        self,
+        interval: Duration,
+        max_interval: Option<Duration>,
+        retry_count: usize,
+    ) -> Duration {
+        let interval = match self {
+            RetryBackoff::Constant => interval,
+            RetryBackoff::Exponential => {
+                let exp = retry_count.saturating_sub(1).min(31) as u32;
+                interval.saturating_mul(1 << exp)
+            }
+        };
+        match max_interval {
+            Some(max_interval) => interval.min(max_interval),
+            None => interval,
+        }
+    }
+}
+
+impl fmt::Display for RetryBackoff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            RetryBackoff::Constant => "constant",
+            RetryBackoff::Exponential => "exponential",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// Parses the `value` of a `Retry-After` response header, and returns the duration to wait from
+/// `now`.
+///
+/// The header value is either a number of seconds or an HTTP date
+/// (see <https://www.rfc-editor.org/rfc/rfc9110#field.retry-after>). A date in the past gives a
+/// zero duration.
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let delta = date.with_timezone(&Utc) - now;
+    Some(delta.to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_constant_backoff() {
+        let backoff = RetryBackoff::Constant;
+        let interval = Duration::from_secs(1);
+        for retry_count in 1..5 {
+            assert_eq!(
+                backoff.interval(interval, None, retry_count),
+                Duration::from_secs(1)
+            );
+        }
+        assert_eq!(
+            backoff.interval(interval, Some(Duration::from_millis(500)), 1),
+            Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn test_exponential_backoff() {
+        let backoff = RetryBackoff::Exponential;
+        let interval = Duration::from_secs(1);
+        let max_interval = Some(Duration::from_secs(10));
+        let intervals = (1..7)
+            .map(|retry_count| backoff.interval(interval, max_interval, retry_count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            intervals,
+            vec![
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+                Duration::from_secs(4),
+                Duration::from_secs(8),
+                Duration::from_secs(10),
+                Duration::from_secs(10),
+            ]
+        );
+
+        // Without cap, the interval is still bounded.
+        assert_eq!(backoff.interval(interval, None, 5), Duration::from_secs(16));
+        assert_eq!(
+            backoff.interval(interval, None, 1000),
+            Duration::from_secs(1 << 31)
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("tomorrow", now), None);
+        assert_eq!(parse_retry_after("-1", now), None);
+    }
+}
@@ -17,37 +17,55 @@
  */
 use std::time::Duration;
 
-use hurl_core::ast::Entry;
+use hurl_core::ast::{Assert, Entry};
 use hurl_core::typing::{BytesPerSec, Count};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
-use crate::runner::Output;
+use crate::http::{Cookie, IpResolve, RequestCompression, RequestedHttpVersion};
+use crate::runner::eval_context::EvalContext;
+use crate::runner::{CustomFilterFn, CustomQueryFn, Output, RetryBackoff};
 use crate::util::path::ContextDir;
 
 pub struct RunnerOptionsBuilder {
+    asserts: Vec<Assert>,
     aws_sigv4: Option<String>,
     cacert_file: Option<String>,
     client_cert_file: Option<String>,
     client_key_file: Option<String>,
+    compress_request: Option<RequestCompression>,
     compressed: bool,
+    concurrency_per_file: usize,
     connect_timeout: Duration,
     connects_to: Vec<String>,
     context_dir: ContextDir,
     continue_on_error: bool,
     cookie_input_file: Option<String>,
+    cookies: Vec<Cookie>,
+    custom_filters: Vec<(String, CustomFilterFn)>,
+    custom_queries: Vec<(String, CustomQueryFn)>,
     delay: Duration,
+    delay_jitter: Duration,
+    delay_seed: Option<u64>,
+    dns_servers: Option<String>,
+    fail_if_empty: bool,
+    follow_link: Option<String>,
+    follow_link_max: Count,
     follow_location: bool,
     follow_location_trusted: bool,
     from_entry: Option<usize>,
     headers: Vec<String>,
+    host_headers: Vec<String>,
     http_version: RequestedHttpVersion,
     ignore_asserts: bool,
+    ignore_trailing_newline: bool,
     insecure: bool,
+    insecure_hosts: Vec<String>,
     ip_resolve: IpResolve,
+    max_entries: Option<usize>,
     max_filesize: Option<u64>,
     max_recv_speed: Option<BytesPerSec>,
     max_redirect: Count,
     max_send_speed: Option<BytesPerSec>,
+    name: Option<String>,
     netrc: bool,
     netrc_file: Option<String>,
     netrc_optional: bool,
@@ -58,44 +76,70 @@ pub struct RunnerOptionsBuilder {
     pre_entry: Option<fn(&Entry) -> bool>,
     proxy: Option<String>,
     repeat: Option<Count>,
+    request_charset: Option<String>,
+    require_http_version: bool,
     resolves: Vec<String>,
+    response_charset: Option<String>,
     retry: Option<Count>,
+    retry_backoff: RetryBackoff,
     retry_interval: Duration,
+    retry_max_interval: Option<Duration>,
+    retry_on_status: Vec<u16>,
     skip: bool,
     ssl_no_revoke: bool,
+    strip_newlines: bool,
     timeout: Duration,
     to_entry: Option<usize>,
     unix_socket: Option<String>,
     user: Option<String>,
     user_agent: Option<String>,
+    warmup: usize,
+    xpath_namespaces: Vec<(String, String)>,
 }
 
 impl Default for RunnerOptionsBuilder {
     fn default() -> Self {
         RunnerOptionsBuilder {
+            asserts: vec![],
             aws_sigv4: None,
             cacert_file: None,
             client_cert_file: None,
             client_key_file: None,
+            compress_request: None,
             compressed: false,
+            concurrency_per_file: 1,
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
             context_dir: ContextDir::default(),
             continue_on_error: false,
             cookie_input_file: None,
+            cookies: vec![],
+            custom_filters: vec![],
+            custom_queries: vec![],
             delay: Duration::from_millis(0),
+            delay_jitter: Duration::from_millis(0),
+            delay_seed: None,
+            dns_servers: None,
+            fail_if_empty: false,
+            follow_link: None,
+            follow_link_max: Count::Finite(50),
             follow_location: false,
             follow_location_trusted: false,
             from_entry: None,
             headers: vec![],
+            host_headers: vec![],
             http_version: RequestedHttpVersion::default(),
             ignore_asserts: false,
+            ignore_trailing_newline: false,
             insecure: false,
+            insecure_hosts: vec![],
             ip_resolve: IpResolve::default(),
+            max_entries: None,
             max_filesize: None,
             max_recv_speed: None,
             max_redirect: Count::Finite(50),
             max_send_speed: None,
+            name: None,
             netrc: false,
             netrc_file: None,
             netrc_optional: false,
@@ -106,16 +150,25 @@ impl Default for RunnerOptionsBuilder {
             pre_entry: None,
             proxy: None,
             repeat: None,
+            request_charset: None,
+            require_http_version: false,
             resolves: vec![],
+            response_charset: None,
             retry: None,
+            retry_backoff: RetryBackoff::Constant,
             retry_interval: Duration::from_millis(1000),
+            retry_max_interval: None,
+            retry_on_status: vec![],
             skip: false,
             ssl_no_revoke: false,
+            strip_newlines: false,
             timeout: Duration::from_secs(300),
             to_entry: None,
             unix_socket: None,
             user: None,
             user_agent: None,
+            warmup: 0,
+            xpath_namespaces: vec![],
         }
     }
 }
@@ -126,6 +179,12 @@ impl RunnerOptionsBuilder {
         RunnerOptionsBuilder::default()
     }
 
+    /// Sets additional asserts, evaluated on the response of every entry.
+    pub fn asserts(&mut self, asserts: &[Assert]) -> &mut Self {
+        self.asserts = asserts.to_vec();
+        self
+    }
+
     /// Specifies the AWS SigV4 option
     pub fn aws_sigv4(&mut self, aws_sigv4: Option<String>) -> &mut Self {
         self.aws_sigv4 = aws_sigv4;
@@ -151,6 +210,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Compresses the request body with `compress_request` and sets the `Content-Encoding` header.
+    ///
+    /// The body is not compressed if a `Content-Encoding` header is already set.
+    pub fn compress_request(&mut self, compress_request: Option<RequestCompression>) -> &mut Self {
+        self.compress_request = compress_request;
+        self
+    }
+
     /// Requests a compressed response using one of the algorithms br, gzip, deflate and
     /// automatically decompress the content.
     pub fn compressed(&mut self, compressed: bool) -> &mut Self {
@@ -158,6 +225,17 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of entries of a Hurl file run concurrently.
+    ///
+    /// Entries are only run concurrently if they are independent: no entry of the file can have
+    /// captures or define variables in its `[Options]` section. Each entry runs with its own HTTP
+    /// client, so cookies are not shared between entries. Default is 1 (entries are run
+    /// sequentially).
+    pub fn concurrency_per_file(&mut self, concurrency_per_file: usize) -> &mut Self {
+        self.concurrency_per_file = concurrency_per_file;
+        self
+    }
+
     /// Sets maximum time that you allow Hurl’s connection to take.
     ///
     /// Default 300 seconds.
@@ -183,6 +261,39 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets a random jitter added to each non-zero delay: the effective delay is a random duration
+    /// between `delay` and `delay + delay_jitter`.
+    ///
+    /// Default is 0 ms (no jitter).
+    pub fn delay_jitter(&mut self, delay_jitter: Duration) -> &mut Self {
+        self.delay_jitter = delay_jitter;
+        self
+    }
+
+    /// Sets the seed of the random generator used to compute jittered delays.
+    ///
+    /// Default is `None`, the generator is seeded with the current time.
+    pub fn delay_seed(&mut self, delay_seed: Option<u64>) -> &mut Self {
+        self.delay_seed = delay_seed;
+        self
+    }
+
+    /// Sets the list of DNS servers to use instead of the system default (ex: "1.1.1.1,8.8.8.8").
+    ///
+    /// This option requires libcurl to be built with the c-ares backend.
+    pub fn dns_servers(&mut self, dns_servers: Option<String>) -> &mut Self {
+        self.dns_servers = dns_servers;
+        self
+    }
+
+    /// Sets failing the run of a Hurl file without any entry.
+    ///
+    /// By default, a file without entry is run successfully.
+    pub fn fail_if_empty(&mut self, fail_if_empty: bool) -> &mut Self {
+        self.fail_if_empty = fail_if_empty;
+        self
+    }
+
     /// Sets root file system to import files in Hurl.
     ///
     /// This is used for both files in multipart form data and request body.
@@ -206,6 +317,36 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Adds these `cookies` to the cookie storage before running the first entry.
+    ///
+    /// This is used to share cookies between successive Hurl files of a sequential run.
+    pub fn cookies(&mut self, cookies: &[Cookie]) -> &mut Self {
+        self.cookies = cookies.to_vec();
+        self
+    }
+
+    /// Registers a custom `filter` function under `name`.
+    ///
+    /// Filters used in a Hurl file that are not built in Hurl are evaluated with the function
+    /// registered under the same name, and are prefixed with `@` (for instance `@reverseString`).
+    /// Registering a filter under an already registered name replaces the previous filter.
+    pub fn custom_filter(&mut self, name: &str, filter: CustomFilterFn) -> &mut Self {
+        self.custom_filters.retain(|(n, _)| n != name);
+        self.custom_filters.push((name.to_string(), filter));
+        self
+    }
+
+    /// Registers a custom `query` function under `name`.
+    ///
+    /// Queries used in a Hurl file that are not built in Hurl are evaluated with the function
+    /// registered under the same name, and are prefixed with `@` (for instance `@wordCount`).
+    /// Registering a query under an already registered name replaces the previous query.
+    pub fn custom_query(&mut self, name: &str, query: CustomQueryFn) -> &mut Self {
+        self.custom_queries.retain(|(n, _)| n != name);
+        self.custom_queries.push((name.to_string(), query));
+        self
+    }
+
     /// Sets stopping or continuing executing requests to the end of the Hurl file even when an assert error occurs.
     ///
     /// By default, Hurl exits after an assert error in the HTTP response. Note that this option does
@@ -215,6 +356,21 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the relation type of the `Link` response header to follow, for instance `next`.
+    ///
+    /// The request is run again with the target URL of the link, until there is no more link to
+    /// follow. To limit the amount of links to follow use [`self.follow_link_max()`]
+    pub fn follow_link(&mut self, follow_link: Option<String>) -> &mut Self {
+        self.follow_link = follow_link;
+        self
+    }
+
+    /// Sets the maximum number of links to follow.
+    pub fn follow_link_max(&mut self, follow_link_max: Count) -> &mut Self {
+        self.follow_link_max = follow_link_max;
+        self
+    }
+
     /// Sets follow redirect.
     ///
     /// To limit the amount of redirects to follow use [`self.max_redirect()`]
@@ -243,6 +399,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets additional headers only sent to a given host, each header being formatted as `HOST:HEADER`.
+    pub fn host_headers(&mut self, host_headers: &[String]) -> &mut Self {
+        self.host_headers = host_headers.to_vec();
+        self
+    }
+
     /// Set requested HTTP version (can be different of the effective HTTP version).
     pub fn http_version(&mut self, version: RequestedHttpVersion) -> &mut Self {
         self.http_version = version;
@@ -255,18 +417,40 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Ignores a single trailing newline when comparing response bodies.
+    ///
+    /// This applies to implicit body asserts and to explicit `body ==` / `body !=` asserts.
+    pub fn ignore_trailing_newline(&mut self, ignore_trailing_newline: bool) -> &mut Self {
+        self.ignore_trailing_newline = ignore_trailing_newline;
+        self
+    }
+
     /// Allows Hurl to perform “insecure” SSL connections and transfers.
     pub fn insecure(&mut self, insecure: bool) -> &mut Self {
         self.insecure = insecure;
         self
     }
 
+    /// Allows Hurl to perform “insecure” SSL connections and transfers, only for these `hosts`.
+    pub fn insecure_hosts(&mut self, hosts: &[String]) -> &mut Self {
+        self.insecure_hosts = hosts.to_vec();
+        self
+    }
+
     /// Set IP version.
     pub fn ip_resolve(&mut self, ip_resolve: IpResolve) -> &mut Self {
         self.ip_resolve = ip_resolve;
         self
     }
 
+    /// Sets the maximum number of entries of a Hurl file.
+    ///
+    /// A file with more entries fails before any entry is run. Default is `None` (no limit).
+    pub fn max_entries(&mut self, max_entries: Option<usize>) -> &mut Self {
+        self.max_entries = max_entries;
+        self
+    }
+
     /// Set the file size limit
     pub fn max_filesize(&mut self, max_filesize: Option<u64>) -> &mut Self {
         self.max_filesize = max_filesize;
@@ -357,12 +541,34 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the charset used to encode text request bodies.
+    pub fn request_charset(&mut self, request_charset: Option<String>) -> &mut Self {
+        self.request_charset = request_charset;
+        self
+    }
+
+    /// Fails requests whose response HTTP version doesn't match the requested HTTP version.
+    ///
+    /// By default, libcurl silently falls back to an earlier HTTP version if the requested
+    /// version can't be negotiated.
+    pub fn require_http_version(&mut self, require_http_version: bool) -> &mut Self {
+        self.require_http_version = require_http_version;
+        self
+    }
+
     /// Provides a custom address for a specific host and port pair.
     pub fn resolves(&mut self, resolves: &[String]) -> &mut Self {
         self.resolves = resolves.to_vec();
         self
     }
 
+    /// Sets the charset used to decode response bodies, overriding the charset of the
+    /// `Content-Type` response header.
+    pub fn response_charset(&mut self, response_charset: Option<String>) -> &mut Self {
+        self.response_charset = response_charset;
+        self
+    }
+
     /// Sets maximum number of retries.
     ///
     /// Default is 0.
@@ -371,6 +577,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the strategy used to compute the interval between each retry.
+    ///
+    /// Default is [`RetryBackoff::Constant`]: the retry interval is the same for each retry.
+    pub fn retry_backoff(&mut self, retry_backoff: RetryBackoff) -> &mut Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
     /// Sets duration between each retry.
     ///
     /// Default is 1000 ms.
@@ -379,11 +593,37 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum duration between each retry.
+    ///
+    /// Default is `None` (no maximum).
+    pub fn retry_max_interval(&mut self, retry_max_interval: Option<Duration>) -> &mut Self {
+        self.retry_max_interval = retry_max_interval;
+        self
+    }
+
+    /// Sets the list of HTTP response status codes that trigger a retry.
+    ///
+    /// When this list is not empty, an entry in error is retried only if its last response status
+    /// is in the list. Default is empty (any error triggers a retry).
+    pub fn retry_on_status(&mut self, retry_on_status: &[u16]) -> &mut Self {
+        self.retry_on_status = retry_on_status.to_vec();
+        self
+    }
+
     pub fn ssl_no_revoke(&mut self, ssl_no_revoke: bool) -> &mut Self {
         self.ssl_no_revoke = ssl_no_revoke;
         self
     }
 
+    /// Sets the strip newlines flag: carriage returns and line feeds are removed from the request
+    /// body before sending it, like curl `--data` does.
+    ///
+    /// Default is false, the request body is sent as is.
+    pub fn strip_newlines(&mut self, strip_newlines: bool) -> &mut Self {
+        self.strip_newlines = strip_newlines;
+        self
+    }
+
     /// Sets maximum time allowed for the transfer.
     ///
     /// Default 300 seconds.
@@ -416,32 +656,66 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sends each entry `warmup` times before its measured run.
+    ///
+    /// Warmup requests results are not recorded: they're not part of the run result and their
+    /// timings are excluded from the run duration.
+    pub fn warmup(&mut self, warmup: usize) -> &mut Self {
+        self.warmup = warmup;
+        self
+    }
+
+    /// Binds XML namespaces `prefix` to `uri`, to be used in XPath queries and filters.
+    ///
+    /// These bindings are added to the namespaces declared in the XML document.
+    pub fn xpath_namespaces(&mut self, xpath_namespaces: &[(String, String)]) -> &mut Self {
+        self.xpath_namespaces = xpath_namespaces.to_vec();
+        self
+    }
+
     /// Create an instance of [`RunnerOptions`].
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
+            asserts: self.asserts.clone(),
             aws_sigv4: self.aws_sigv4.clone(),
             cacert_file: self.cacert_file.clone(),
             client_cert_file: self.client_cert_file.clone(),
             client_key_file: self.client_key_file.clone(),
+            compress_request: self.compress_request,
             compressed: self.compressed,
+            concurrency_per_file: self.concurrency_per_file,
             connect_timeout: self.connect_timeout,
             connects_to: self.connects_to.clone(),
             delay: self.delay,
+            delay_jitter: self.delay_jitter,
+            delay_seed: self.delay_seed,
+            dns_servers: self.dns_servers.clone(),
+            fail_if_empty: self.fail_if_empty,
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
+            cookies: self.cookies.clone(),
+            custom_filters: self.custom_filters.clone(),
+            custom_queries: self.custom_queries.clone(),
+            follow_link: self.follow_link.clone(),
+            follow_link_max: self.follow_link_max,
             follow_location: self.follow_location,
             follow_location_trusted: self.follow_location_trusted,
             from_entry: self.from_entry,
             headers: self.headers.clone(),
+            host_headers: self.host_headers.clone(),
             http_version: self.http_version,
             ignore_asserts: self.ignore_asserts,
+            ignore_trailing_newline: self.ignore_trailing_newline,
             insecure: self.insecure,
+            insecure_hosts: self.insecure_hosts.clone(),
             ip_resolve: self.ip_resolve,
+            max_entries: self.max_entries,
             max_filesize: self.max_filesize,
             max_recv_speed: self.max_recv_speed,
             max_redirect: self.max_redirect,
             max_send_speed: self.max_send_speed,
+            name: self.name.clone(),
             netrc: self.netrc,
             netrc_file: self.netrc_file.clone(),
             netrc_optional: self.netrc_optional,
@@ -452,45 +726,71 @@ impl RunnerOptionsBuilder {
             pre_entry: self.pre_entry,
             proxy: self.proxy.clone(),
             repeat: self.repeat,
+            request_charset: self.request_charset.clone(),
+            require_http_version: self.require_http_version,
             resolves: self.resolves.clone(),
+            response_charset: self.response_charset.clone(),
             retry: self.retry,
+            retry_backoff: self.retry_backoff,
             retry_interval: self.retry_interval,
+            retry_max_interval: self.retry_max_interval,
+            retry_on_status: self.retry_on_status.clone(),
             skip: self.skip,
             ssl_no_revoke: self.ssl_no_revoke,
+            strip_newlines: self.strip_newlines,
             timeout: self.timeout,
             to_entry: self.to_entry,
             unix_socket: self.unix_socket.clone(),
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
+            warmup: self.warmup,
+            xpath_namespaces: self.xpath_namespaces.clone(),
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RunnerOptions {
+    pub(crate) asserts: Vec<Assert>,
     pub(crate) aws_sigv4: Option<String>,
     pub(crate) cacert_file: Option<String>,
     pub(crate) client_cert_file: Option<String>,
     pub(crate) client_key_file: Option<String>,
+    pub(crate) compress_request: Option<RequestCompression>,
     pub(crate) compressed: bool,
+    pub(crate) concurrency_per_file: usize,
     pub(crate) connect_timeout: Duration,
     pub(crate) connects_to: Vec<String>,
     pub(crate) delay: Duration,
+    pub(crate) delay_jitter: Duration,
+    pub(crate) delay_seed: Option<u64>,
+    pub(crate) dns_servers: Option<String>,
+    pub(crate) fail_if_empty: bool,
     pub(crate) context_dir: ContextDir,
     pub(crate) continue_on_error: bool,
     pub(crate) cookie_input_file: Option<String>,
+    pub(crate) cookies: Vec<Cookie>,
+    pub(crate) custom_filters: Vec<(String, CustomFilterFn)>,
+    pub(crate) custom_queries: Vec<(String, CustomQueryFn)>,
+    pub(crate) follow_link: Option<String>,
+    pub(crate) follow_link_max: Count,
     pub(crate) follow_location: bool,
     pub(crate) follow_location_trusted: bool,
     pub(crate) from_entry: Option<usize>,
     pub(crate) headers: Vec<String>,
+    pub(crate) host_headers: Vec<String>,
     pub(crate) http_version: RequestedHttpVersion,
     pub(crate) ignore_asserts: bool,
+    pub(crate) ignore_trailing_newline: bool,
     pub(crate) ip_resolve: IpResolve,
     pub(crate) insecure: bool,
+    pub(crate) insecure_hosts: Vec<String>,
+    pub(crate) max_entries: Option<usize>,
     pub(crate) max_filesize: Option<u64>,
     pub(crate) max_recv_speed: Option<BytesPerSec>,
     pub(crate) max_redirect: Count,
     pub(crate) max_send_speed: Option<BytesPerSec>,
+    pub(crate) name: Option<String>,
     pub(crate) netrc: bool,
     pub(crate) netrc_file: Option<String>,
     pub(crate) netrc_optional: bool,
@@ -501,16 +801,25 @@ pub struct RunnerOptions {
     pub(crate) pre_entry: Option<fn(&Entry) -> bool>,
     pub(crate) proxy: Option<String>,
     pub(crate) repeat: Option<Count>,
+    pub(crate) request_charset: Option<String>,
+    pub(crate) require_http_version: bool,
     pub(crate) resolves: Vec<String>,
+    pub(crate) response_charset: Option<String>,
     pub(crate) retry: Option<Count>,
+    pub(crate) retry_backoff: RetryBackoff,
     pub(crate) retry_interval: Duration,
+    pub(crate) retry_max_interval: Option<Duration>,
+    pub(crate) retry_on_status: Vec<u16>,
     pub(crate) skip: bool,
     pub(crate) ssl_no_revoke: bool,
+    pub(crate) strip_newlines: bool,
     pub(crate) timeout: Duration,
     pub(crate) to_entry: Option<usize>,
     pub(crate) unix_socket: Option<String>,
     pub(crate) user: Option<String>,
     pub(crate) user_agent: Option<String>,
+    pub(crate) warmup: usize,
+    pub(crate) xpath_namespaces: Vec<(String, String)>,
 }
 
 impl Default for RunnerOptions {
@@ -518,3 +827,18 @@ impl Default for RunnerOptions {
         RunnerOptionsBuilder::default().build()
     }
 }
+
+impl RunnerOptions {
+    /// Returns the context used to evaluate queries and filters with these options.
+    pub(crate) fn eval_context(&self) -> EvalContext<'_> {
+        EvalContext {
+            xpath_namespaces: &self.xpath_namespaces,
+            custom_filters: &self.custom_filters,
+            custom_queries: &self.custom_queries,
+            content_type: None,
+            redirects: &[],
+            transfer_duration: Duration::ZERO,
+            timings: None,
+        }
+    }
+}
@@ -0,0 +1,179 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::time::Duration;
+
+use crate::runner::HurlResult;
+
+/// Aggregated stats over a list of [`HurlResult`].
+///
+/// # Example
+///
+/// ```
+/// use hurl::runner::{HurlResult, RunSummary};
+///
+/// let results = vec![HurlResult { success: true, ..Default::default() }];
+/// let summary = RunSummary::new(&results);
+/// assert_eq!(summary.total_files, 1);
+/// assert_eq!(summary.success_rate(), 1.0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunSummary {
+    /// Number of results (i.e. executed files).
+    pub total_files: usize,
+    /// Number of successful results.
+    pub succeeded_files: usize,
+    /// Number of executed entries, retried entries being counted once.
+    pub total_entries: usize,
+    /// Number of failed asserts.
+    pub failed_asserts: usize,
+    /// Sum of the results durations.
+    pub duration: Duration,
+}
+
+impl RunSummary {
+    /// Creates a new summary from a list of `results`.
+    pub fn new(results: &[HurlResult]) -> Self {
+        results
+            .iter()
+            .fold(RunSummary::default(), |mut summary, result| {
+                summary.total_files += 1;
+                if result.success {
+                    summary.succeeded_files += 1;
+                }
+                summary.total_entries += result.entries_count();
+                summary.failed_asserts += result.failed_asserts_count();
+                summary.duration += result.duration;
+                summary
+            })
+    }
+
+    /// Returns the number of failed results.
+    pub fn failed_files(&self) -> usize {
+        self.total_files - self.succeeded_files
+    }
+
+    /// Returns the ratio (between 0 and 1) of successful results.
+    ///
+    /// An empty summary has a success rate of 1.
+    pub fn success_rate(&self) -> f64 {
+        if self.total_files == 0 {
+            return 1.0;
+        }
+        self.succeeded_files as f64 / self.total_files as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::SourceInfo;
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::{EntryResult, RunnerError, RunnerErrorKind};
+
+    fn error(assert: bool) -> RunnerError {
+        let source_info = SourceInfo::new(Pos::new(2, 1), Pos::new(2, 10));
+        let kind = if assert {
+            RunnerErrorKind::AssertStatus {
+                actual: "500".to_string(),
+            }
+        } else {
+            RunnerErrorKind::FilterInvalidInput("unknown".to_string())
+        };
+        RunnerError::new(source_info, kind, assert)
+    }
+
+    fn entry(entry_index: usize, errors: Vec<RunnerError>) -> EntryResult {
+        EntryResult {
+            entry_index,
+            errors,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hurl_result_stats() {
+        // Entry 2 is retried once, only its last execution is taken into account.
+        let result = HurlResult {
+            entries: vec![
+                entry(1, vec![]),
+                entry(2, vec![error(true), error(true)]),
+                entry(2, vec![error(true)]),
+                entry(3, vec![]),
+                entry(4, vec![]),
+            ],
+            success: false,
+            ..Default::default()
+        };
+        assert_eq!(result.entries_count(), 4);
+        assert_eq!(result.failed_asserts_count(), 1);
+        assert_eq!(result.success_rate(), 0.75);
+
+        let result = HurlResult::default();
+        assert_eq!(result.entries_count(), 0);
+        assert_eq!(result.failed_asserts_count(), 0);
+        assert_eq!(result.success_rate(), 1.0);
+    }
+
+    #[test]
+    fn test_run_summary() {
+        let results = vec![
+            HurlResult {
+                entries: vec![entry(1, vec![]), entry(2, vec![])],
+                duration: Duration::from_millis(100),
+                success: true,
+                ..Default::default()
+            },
+            HurlResult {
+                entries: vec![entry(1, vec![error(true), error(true)])],
+                duration: Duration::from_millis(20),
+                success: false,
+                ..Default::default()
+            },
+            HurlResult {
+                entries: vec![entry(1, vec![]), entry(2, vec![error(false)])],
+                duration: Duration::from_millis(30),
+                success: false,
+                ..Default::default()
+            },
+            HurlResult {
+                entries: vec![entry(1, vec![])],
+                duration: Duration::from_millis(50),
+                success: true,
+                ..Default::default()
+            },
+        ];
+        let summary = RunSummary::new(&results);
+        assert_eq!(
+            summary,
+            RunSummary {
+                total_files: 4,
+                succeeded_files: 2,
+                total_entries: 6,
+                failed_asserts: 2,
+                duration: Duration::from_millis(200),
+            }
+        );
+        assert_eq!(summary.failed_files(), 2);
+        assert_eq!(summary.success_rate(), 0.5);
+
+        let summary = RunSummary::new(&[]);
+        assert_eq!(summary.failed_files(), 0);
+        assert_eq!(summary.success_rate(), 1.0);
+    }
+}
@@ -167,6 +167,8 @@ impl VariableSet {
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
+
     use crate::runner::Number::{Float, Integer};
     use crate::runner::{Value, Variable, VariableSet, Visibility};
 
@@ -268,6 +270,22 @@ mod test {
             .is_err());
     }
 
+    #[test]
+    fn secret_overrides_public_variable() {
+        // Variables from files and command line are public and inserted first, a secret with the
+        // same name makes the variable secret.
+        let mut variables = VariableSet::from(&HashMap::from([(
+            "token".to_string(),
+            Value::String("plain".to_string()),
+        )]));
+        variables
+            .insert_secret("token".to_string(), "s3cr3t".to_string())
+            .unwrap();
+        let token = variables.get("token").unwrap();
+        assert!(token.is_secret());
+        assert_eq!(token.value(), &Value::String("s3cr3t".to_string()));
+    }
+
     #[test]
     fn get_secrets() {
         let mut variables = VariableSet::new();
@@ -38,6 +38,8 @@ pub struct Document {
     inner: libxml::tree::Document,
     /// Format use for parsing: HTML or XML
     format: Format,
+    /// User defined namespaces, registered in addition to the document namespaces.
+    namespaces: Vec<Namespace>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -64,17 +66,31 @@ impl Document {
             return Err("no root element".to_string());
         }
 
-        let doc = Document { inner: doc, format };
+        let doc = Document {
+            inner: doc,
+            format,
+            namespaces: vec![],
+        };
         Ok(doc)
     }
 
+    /// Binds a namespace `prefix` to `href`, so XPath expressions can use this prefix.
+    ///
+    /// A user defined prefix takes precedence over a prefix declared in the document.
+    pub fn register_namespace(&mut self, prefix: &str, href: &str) {
+        self.namespaces.push(Namespace {
+            prefix: prefix.to_string(),
+            href: href.to_string(),
+        });
+    }
+
     /// Evaluates a XPath 1.0 expression `expr` against a document.
     pub fn eval_xpath(&self, expr: &str) -> Result<Value, XPathError> {
         let support_ns = match self.format {
             Format::Html => false,
             Format::Xml => true,
         };
-        libxml_eval_xpath(&self.inner, expr, support_ns)
+        libxml_eval_xpath(&self.inner, expr, support_ns, &self.namespaces)
     }
 }
 
@@ -137,16 +153,21 @@ extern "C" {
     );
 }
 
-/// Registers all XML namespaces from a document `doc` to a `context`.
-fn register_namespaces(doc: &libxml::tree::Document, context: &libxml::xpath::Context) {
+/// Registers all XML namespaces from a document `doc` to a `context`, then the user defined
+/// `namespaces`.
+fn register_namespaces(
+    doc: &libxml::tree::Document,
+    context: &libxml::xpath::Context,
+    namespaces: &[Namespace],
+) {
     // We walk through the xml document to register each namespace,
     // so we can eval xpath queries with namespace. For convenience, we register the
     // first default namespace with _ prefix. Other default namespaces are not registered
     // and should be referenced vi `local-name` or `name` XPath functions.
-    let namespaces = document_namespaces(doc);
+    let doc_namespaces = document_namespaces(doc);
     let mut default_registered = false;
 
-    for n in namespaces {
+    for n in doc_namespaces {
         if n.prefix.is_empty() {
             if !default_registered {
                 context.register_namespace("_", &n.href).unwrap();
@@ -156,13 +177,20 @@ fn register_namespaces(doc: &libxml::tree::Document, context: &libxml::xpath::Co
             context.register_namespace(&n.prefix, &n.href).unwrap();
         }
     }
+    for n in namespaces {
+        context.register_namespace(&n.prefix, &n.href).unwrap();
+    }
 }
 
 /// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, optionally using namespace.
+///
+/// When namespaces are supported, user defined `namespaces` are registered in addition to the
+/// document namespaces.
 fn libxml_eval_xpath(
     doc: &libxml::tree::Document,
     expr: &str,
     support_ns: bool,
+    namespaces: &[Namespace],
 ) -> Result<Value, XPathError> {
     let context = libxml::xpath::Context::new(doc).expect("error setting context in xpath module");
 
@@ -172,7 +200,7 @@ fn libxml_eval_xpath(
     }
 
     if support_ns {
-        register_namespaces(doc, &context);
+        register_namespaces(doc, &context, namespaces);
     }
 
     let result = match context.evaluate(expr) {
@@ -204,7 +232,7 @@ fn libxml_eval_xpath(
 }
 
 /// A XML namespace
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 struct Namespace {
     prefix: String,
     href: String,
@@ -506,4 +534,70 @@ mod tests {
         let expr = "//_:book/_:notes/*[local-name()='p']";
         assert_eq!(doc.eval_xpath(expr).unwrap(), Value::Nodeset(1));
     }
+
+    #[test]
+    fn test_register_namespaces() {
+        // Only the first default namespace is registered with `_` prefix: the SOAP payload, in
+        // its own default namespace, can only be queried with a user defined prefix.
+        let xml = r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope/">
+    <soap:Header>
+        <Auth xmlns="urn:example:auth">
+            <Token>abc</Token>
+        </Auth>
+    </soap:Header>
+    <soap:Body>
+        <GetPriceResponse xmlns="https://www.example.org/prices">
+            <Price>1.90</Price>
+        </GetPriceResponse>
+    </soap:Body>
+</soap:Envelope>"#;
+
+        let mut doc = Document::parse(xml, Format::Xml).unwrap();
+
+        let expr = "string(//soap:Body/m:GetPriceResponse/m:Price)";
+        assert_eq!(doc.eval_xpath(expr).unwrap_err(), XPathError::Eval);
+        let expr = "//soap:Body/_:GetPriceResponse/_:Price";
+        assert_eq!(doc.eval_xpath(expr).unwrap(), Value::Nodeset(0));
+
+        doc.register_namespace("m", "https://www.example.org/prices");
+        doc.register_namespace("auth", "urn:example:auth");
+
+        let expr = "string(//soap:Body/m:GetPriceResponse/m:Price)";
+        assert_eq!(
+            doc.eval_xpath(expr).unwrap(),
+            Value::String("1.90".to_string())
+        );
+        let expr = "string(//soap:Header/auth:Auth/auth:Token)";
+        assert_eq!(
+            doc.eval_xpath(expr).unwrap(),
+            Value::String("abc".to_string())
+        );
+    }
+
+    #[test]
+    fn test_register_namespace_default() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+    <title>Example Feed</title>
+    <entry>
+        <title>Atom-Powered Robots Run Amok</title>
+    </entry>
+</feed>"#;
+
+        let mut doc = Document::parse(xml, Format::Xml).unwrap();
+        doc.register_namespace("atom", "http://www.w3.org/2005/Atom");
+
+        let expr = "string(//atom:feed/atom:entry/atom:title)";
+        assert_eq!(
+            doc.eval_xpath(expr).unwrap(),
+            Value::String("Atom-Powered Robots Run Amok".to_string())
+        );
+        // Document namespaces are still available.
+        let expr = "string(//_:feed/_:title)";
+        assert_eq!(
+            doc.eval_xpath(expr).unwrap(),
+            Value::String("Example Feed".to_string())
+        );
+    }
 }
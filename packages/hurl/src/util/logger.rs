@@ -55,6 +55,8 @@ pub struct Logger {
     pub(crate) verbosity: Option<Verbosity>,
     pub(crate) stderr: Stderr,
     secrets: Vec<String>,
+    /// Optional id prefixed to each log line (used to correlate parallel runs).
+    correlation_id: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -125,6 +127,19 @@ impl Logger {
             verbosity: options.verbosity,
             stderr: term,
             secrets: secrets.to_vec(),
+            correlation_id: None,
+        }
+    }
+
+    /// Returns a new logger with the same settings and secrets as this logger, writing to `term`.
+    pub(crate) fn with_stderr(&self, term: Stderr) -> Self {
+        Logger {
+            color: self.color,
+            error_format: self.error_format,
+            verbosity: self.verbosity,
+            stderr: term,
+            secrets: self.secrets.clone(),
+            correlation_id: self.correlation_id.clone(),
         }
     }
 
@@ -235,19 +250,25 @@ impl Logger {
 
     /// Prints a HTTP request header to this logger [`Stderr`] instance, in verbose and very verbose mode.
     ///
-    /// Request HTTP headers start with `>`.
-    pub fn debug_headers_out(&mut self, headers: &[(&str, &str)]) {
+    /// Request HTTP headers start with `>`. Each header is a tuple of name, value and a flag set to
+    /// `true` if the header has been automatically added (by libcurl or Hurl) and has not been
+    /// explicitly set by the user. Automatic headers are suffixed with `(auto)`.
+    pub fn debug_headers_out(&mut self, headers: &[(&str, &str, bool)]) {
         if self.verbosity.is_none() {
             return;
         }
         let fmt = self.format();
 
-        for (name, value) in headers {
+        for (name, value, auto) in headers {
             let mut s = StyledString::new();
             s.push("> ");
             s.push_with(name, Style::new().cyan().bold());
             s.push(": ");
             s.push(value);
+            if *auto {
+                s.push(" ");
+                s.push_with("(auto)", Style::new().bright_black());
+            }
             self.eprintln(&s.to_string(fmt));
         }
         self.eprintln(">");
@@ -277,6 +298,18 @@ impl Logger {
         self.eprintln(&s.to_string(fmt));
     }
 
+    /// Prints an error given message to this logger [`Stderr`] instance, no matter what is the verbosity.
+    ///
+    /// Displayed error messages start with `error:`.
+    pub fn error(&mut self, message: &str) {
+        let fmt = self.format();
+        let mut s = StyledString::new();
+        s.push_with("error", Style::new().red().bold());
+        s.push(": ");
+        s.push_with(message, Style::new().bold());
+        self.eprintln(&s.to_string(fmt));
+    }
+
     pub fn error_parsing_rich<E: DisplaySourceError>(
         &mut self,
         content: &str,
@@ -362,12 +395,88 @@ impl Logger {
         }
     }
 
+    /// Sets a correlation id for this logger.
+    ///
+    /// Each line written by this logger is prefixed with `[correlation_id]`, so that logs of files
+    /// executed concurrently can be told apart.
+    pub(crate) fn set_correlation_id(&mut self, correlation_id: &str) {
+        self.correlation_id = Some(correlation_id.to_string());
+    }
+
     fn eprintln(&mut self, message: &str) {
+        let message = match &self.correlation_id {
+            Some(id) => prefix_lines(message, id),
+            None => message.to_string(),
+        };
         if self.secrets.is_empty() {
-            self.stderr.eprintln(message);
+            self.stderr.eprintln(&message);
             return;
         }
         let redacted = message.redact(&self.secrets);
         self.stderr.eprintln(&redacted);
     }
 }
+
+/// Prefixes each line of `message` with `[id]`.
+fn prefix_lines(message: &str, id: &str) -> String {
+    let (message, trailing) = match message.strip_suffix('\n') {
+        Some(m) => (m, "\n"),
+        None => (message, ""),
+    };
+    let lines = message
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                format!("[{id}]")
+            } else {
+                format!("[{id}] {line}")
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("{}{trailing}", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_logger(verbosity: Option<Verbosity>) -> Logger {
+        let options = LoggerOptionsBuilder::new().verbosity(verbosity).build();
+        Logger::new(&options, Stderr::new(WriteMode::Buffered), &[])
+    }
+
+    #[test]
+    fn log_lines_with_correlation_id() {
+        let mut logger = new_logger(Some(Verbosity::VeryVerbose));
+        logger.set_correlation_id("worker=1 file=foo.hurl");
+        logger.debug("Executing entry 1");
+        logger.debug("");
+        logger.debug_status_version_in("HTTP/1.1 200 OK");
+        logger.warning("Something is wrong");
+        assert_eq!(
+            logger.stderr.buffer(),
+            "[worker=1 file=foo.hurl] * Executing entry 1\n\
+             [worker=1 file=foo.hurl] *\n\
+             [worker=1 file=foo.hurl] < HTTP/1.1 200 OK\n\
+             [worker=1 file=foo.hurl] warning: Something is wrong\n"
+        );
+    }
+
+    #[test]
+    fn log_lines_with_correlation_id_in_default_verbosity() {
+        let mut logger = new_logger(None);
+        logger.set_correlation_id("worker=1 file=foo.hurl");
+        logger.debug("Executing entry 1");
+        logger.warning("Something is wrong");
+        assert_eq!(
+            logger.stderr.buffer(),
+            "[worker=1 file=foo.hurl] warning: Something is wrong\n"
+        );
+    }
+
+    #[test]
+    fn multilines_with_correlation_id() {
+        assert_eq!(prefix_lines("a\n\nb\n", "w0"), "[w0] a\n[w0]\n[w0] b\n");
+        assert_eq!(prefix_lines("", "w0"), "[w0]");
+    }
+}
@@ -18,9 +18,11 @@
 use std::str::FromStr;
 use std::time::Duration;
 
-use hurl::http::{Call, HttpVersion, Request, Response, Url};
+use hurl::http::{Call, Header, HttpVersion, Request, Response, Url};
 use hurl::runner;
-use hurl::runner::{EntryResult, HurlResult, RunnerOptionsBuilder, VariableSet};
+use hurl::runner::{
+    EntryResult, EvalError, HurlResult, Number, RunnerOptionsBuilder, Value, VariableSet,
+};
 use hurl::util::logger::LoggerOptionsBuilder;
 use hurl::util::path::ContextDir;
 use hurl_core::input::Input;
@@ -48,7 +50,14 @@ fn simple_sample() {
         assert!(!entry.compressed);
     }
 
-    fn check_call(_: &Call) {}
+    fn check_call(call: &Call) {
+        assert!(call.timings.begin_call <= call.timings.end_call);
+        assert!(call.timings.total < Duration::from_secs(1));
+    }
+
+    fn check_header(header: &Header) {
+        assert!(!header.name.is_empty());
+    }
 
     fn check_request(request: &Request) {
         assert_eq!(
@@ -64,6 +73,7 @@ fn simple_sample() {
         assert!(header_names.contains(&"Accept".to_string()));
         assert!(header_names.contains(&"Host".to_string()));
         assert!(header_names.contains(&"User-Agent".to_string()));
+        request.headers.iter().for_each(check_header);
         assert_eq!(request.body.len(), 0);
     }
 
@@ -81,6 +91,7 @@ fn simple_sample() {
         assert!(header_names.contains(&"Content-Type".to_string()));
         assert!(header_names.contains(&"Date".to_string()));
         assert!(header_names.contains(&"Server".to_string())); // There are two 'Server' HTTP headers
+        response.headers.iter().for_each(check_header);
         assert_eq!(response.body.len(), 12);
         assert!(response.duration < Duration::from_secs(1));
         assert_eq!(
@@ -157,3 +168,71 @@ fn simple_sample() {
     let response = &call.response;
     check_response(response);
 }
+
+#[test]
+fn custom_filter() {
+    fn reverse_string(value: Value, _: &[Value]) -> Result<Value, EvalError> {
+        match value {
+            Value::String(s) => Ok(Value::String(s.chars().rev().collect())),
+            v => Err(EvalError::new(&format!("{} is not a string", v.repr()))),
+        }
+    }
+
+    let content = r#"
+    GET http://localhost:8000/hello
+    HTTP 200
+    [Captures]
+    reversed: body @reverseString
+    [Asserts]
+    body @reverseString == "!dlroW olleH"
+    body @reverseString @reverseString == "Hello World!"
+    "#;
+
+    let runner_opts = RunnerOptionsBuilder::new()
+        .custom_filter("reverseString", reverse_string)
+        .build();
+    let logger_opts = LoggerOptionsBuilder::new().build();
+    let variables = VariableSet::new();
+
+    let result = runner::run(content, None, &runner_opts, &variables, &logger_opts).unwrap();
+    assert!(result.success);
+    let entry = result.entries.first().unwrap();
+    let capture = entry.captures.first().unwrap();
+    assert_eq!(capture.value, Value::String("!dlroW olleH".to_string()));
+
+    // Without registration, the filter can't be evaluated.
+    let runner_opts = RunnerOptionsBuilder::new().build();
+    let result = runner::run(content, None, &runner_opts, &variables, &logger_opts).unwrap();
+    assert!(!result.success);
+}
+
+#[test]
+fn custom_query() {
+    /// Returns the number of words of the response body.
+    fn word_count(response: &Response, _: &[Value]) -> Result<Option<Value>, EvalError> {
+        let body = String::from_utf8_lossy(&response.body);
+        let count = body.split_whitespace().count() as i64;
+        Ok(Some(Value::Number(Number::Integer(count))))
+    }
+
+    let content = r#"
+    GET http://localhost:8000/hello
+    HTTP 200
+    [Captures]
+    count: @wordCount
+    [Asserts]
+    @wordCount == 2
+    "#;
+
+    let runner_opts = RunnerOptionsBuilder::new()
+        .custom_query("wordCount", word_count)
+        .build();
+    let logger_opts = LoggerOptionsBuilder::new().build();
+    let variables = VariableSet::new();
+
+    let result = runner::run(content, None, &runner_opts, &variables, &logger_opts).unwrap();
+    assert!(result.success);
+    let entry = result.entries.first().unwrap();
+    let capture = entry.captures.first().unwrap();
+    assert_eq!(capture.value, Value::Number(Number::Integer(2)));
+}
@@ -316,10 +316,23 @@ pub struct Query {
 pub enum QueryValue {
     Status,
     Url,
+    Redirects,
     Header {
         space0: Whitespace,
         name: Template,
     },
+    Trailer {
+        space0: Whitespace,
+        name: Template,
+    },
+    ServerTiming {
+        space0: Whitespace,
+        name: Template,
+    },
+    Link {
+        space0: Whitespace,
+        rel: Template,
+    },
     Cookie {
         space0: Whitespace,
         expr: CookiePath,
@@ -333,6 +346,10 @@ pub enum QueryValue {
         space0: Whitespace,
         expr: Template,
     },
+    Jsonpointer {
+        space0: Whitespace,
+        expr: Template,
+    },
     Regex {
         space0: Whitespace,
         value: RegexValue,
@@ -343,12 +360,23 @@ pub enum QueryValue {
     },
     Duration,
     Bytes,
+    /// The response body bytes, before any content decoding.
+    RawBytes,
     Sha256,
     Md5,
     Certificate {
         space0: Whitespace,
         attribute_name: CertificateAttributeName,
     },
+    Timings {
+        space0: Whitespace,
+        attribute_name: TimingsAttributeName,
+    },
+    /// A query that is not built in Hurl, evaluated by a resolver registered by the runner.
+    Custom {
+        name: String,
+        args: Vec<(Whitespace, Template)>,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -406,6 +434,29 @@ pub enum CertificateAttributeName {
     SerialNumber,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimingsAttributeName {
+    NameLookup,
+    Connect,
+    AppConnect,
+    PreTransfer,
+    StartTransfer,
+    Total,
+}
+
+impl TimingsAttributeName {
+    pub fn value(&self) -> &'static str {
+        match self {
+            TimingsAttributeName::NameLookup => "namelookup",
+            TimingsAttributeName::Connect => "connect",
+            TimingsAttributeName::AppConnect => "appconnect",
+            TimingsAttributeName::PreTransfer => "pretransfer",
+            TimingsAttributeName::StartTransfer => "starttransfer",
+            TimingsAttributeName::Total => "total",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Predicate {
     pub not: bool,
@@ -425,6 +476,14 @@ pub struct PredicateFunc {
     pub value: PredicateFuncValue,
 }
 
+/// Tolerance of a `nearlyEquals` predicate, for instance `epsilon 0.001`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Epsilon {
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub value: PredicateValue,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[allow(clippy::large_enum_variant)]
 pub enum PredicateValue {
@@ -479,6 +538,17 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    ContainTimes {
+        space0: Whitespace,
+        value: PredicateValue,
+        space1: Whitespace,
+        count: U64,
+    },
+    NearlyEqual {
+        space0: Whitespace,
+        value: PredicateValue,
+        epsilon: Option<Epsilon>,
+    },
     Include {
         space0: Whitespace,
         value: PredicateValue,
@@ -493,10 +563,19 @@ pub enum PredicateFuncValue {
     IsString,
     IsCollection,
     IsDate,
+    IsDateFormat {
+        space0: Whitespace,
+        fmt: Template,
+    },
     IsIsoDate,
     Exist,
     IsEmpty,
     IsNumber,
+    IsUuid,
+    IsEmail,
+    IsUrl,
+    IsIpv4,
+    IsIpv6,
 }
 
 //
@@ -520,6 +599,7 @@ pub enum MultilineStringKind {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MultilineStringAttribute {
     Escape,
+    NoNewline,
     NoVariable,
 }
 
@@ -786,6 +866,7 @@ pub enum OptionKind {
     CaCertificate(Template),
     ClientCert(Template),
     ClientKey(Template),
+    CompressRequest(Template),
     Compressed(BooleanOption),
     ConnectTo(Template),
     ConnectTimeout(DurationOption),
@@ -798,26 +879,35 @@ pub enum OptionKind {
     Insecure(BooleanOption),
     IpV4(BooleanOption),
     IpV6(BooleanOption),
+    FollowLink(Template),
+    FollowLinkMax(CountOption),
     FollowLocation(BooleanOption),
     FollowLocationTrusted(BooleanOption),
     LimitRate(NaturalOption),
     MaxRedirect(CountOption),
+    MaxTime(DurationOption),
+    Name(Template),
     NetRc(BooleanOption),
     NetRcFile(Template),
     NetRcOptional(BooleanOption),
+    NoProxy(Template),
     Output(Template),
     PathAsIs(BooleanOption),
     Proxy(Template),
     Repeat(CountOption),
+    RequestCharset(Template),
     Resolve(Template),
+    ResponseCharset(Template),
     Retry(CountOption),
     RetryInterval(DurationOption),
     Skip(BooleanOption),
+    StripNewlines(BooleanOption),
     UnixSocket(Template),
     User(Template),
     Variable(VariableDefinition),
     Verbose(BooleanOption),
     VeryVerbose(BooleanOption),
+    XPathNamespace(VariableDefinition),
 }
 
 impl OptionKind {
@@ -827,10 +917,13 @@ impl OptionKind {
             OptionKind::CaCertificate(_) => "cacert",
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
+            OptionKind::CompressRequest(_) => "compress-request",
             OptionKind::Compressed(_) => "compressed",
             OptionKind::ConnectTo(_) => "connect-to",
             OptionKind::ConnectTimeout(_) => "connect-timeout",
             OptionKind::Delay(_) => "delay",
+            OptionKind::FollowLink(_) => "follow-link",
+            OptionKind::FollowLinkMax(_) => "follow-link-max",
             OptionKind::FollowLocation(_) => "location",
             OptionKind::FollowLocationTrusted(_) => "location-trusted",
             OptionKind::Header(_) => "header",
@@ -843,22 +936,29 @@ impl OptionKind {
             OptionKind::IpV6(_) => "ipv6",
             OptionKind::LimitRate(_) => "limit-rate",
             OptionKind::MaxRedirect(_) => "max-redirs",
+            OptionKind::MaxTime(_) => "max-time",
+            OptionKind::Name(_) => "name",
             OptionKind::NetRc(_) => "netrc",
             OptionKind::NetRcFile(_) => "netrc-file",
             OptionKind::NetRcOptional(_) => "netrc-optional",
+            OptionKind::NoProxy(_) => "noproxy",
             OptionKind::Output(_) => "output",
             OptionKind::PathAsIs(_) => "path-as-is",
             OptionKind::Proxy(_) => "proxy",
             OptionKind::Repeat(_) => "repeat",
+            OptionKind::RequestCharset(_) => "request-charset",
             OptionKind::Resolve(_) => "resolve",
+            OptionKind::ResponseCharset(_) => "response-charset",
             OptionKind::Retry(_) => "retry",
             OptionKind::RetryInterval(_) => "retry-interval",
             OptionKind::Skip(_) => "skip",
+            OptionKind::StripNewlines(_) => "strip-newlines",
             OptionKind::UnixSocket(_) => "unix-socket",
             OptionKind::User(_) => "user",
             OptionKind::Variable(_) => "variable",
             OptionKind::Verbose(_) => "verbose",
             OptionKind::VeryVerbose(_) => "very-verbose",
+            OptionKind::XPathNamespace(_) => "xpath-namespace",
         }
     }
 
@@ -868,10 +968,13 @@ impl OptionKind {
             OptionKind::CaCertificate(filename) => filename.to_string(),
             OptionKind::ClientCert(filename) => filename.to_string(),
             OptionKind::ClientKey(filename) => filename.to_string(),
+            OptionKind::CompressRequest(value) => value.to_string(),
             OptionKind::Compressed(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.to_string(),
             OptionKind::ConnectTimeout(value) => value.to_string(),
             OptionKind::Delay(value) => value.to_string(),
+            OptionKind::FollowLink(value) => value.to_string(),
+            OptionKind::FollowLinkMax(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
             OptionKind::FollowLocationTrusted(value) => value.to_string(),
             OptionKind::Header(value) => value.to_string(),
@@ -884,17 +987,23 @@ impl OptionKind {
             OptionKind::IpV6(value) => value.to_string(),
             OptionKind::LimitRate(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
+            OptionKind::MaxTime(value) => value.to_string(),
+            OptionKind::Name(value) => value.to_string(),
             OptionKind::NetRc(value) => value.to_string(),
             OptionKind::NetRcFile(filename) => filename.to_string(),
             OptionKind::NetRcOptional(value) => value.to_string(),
+            OptionKind::NoProxy(value) => value.to_string(),
             OptionKind::Output(filename) => filename.to_string(),
             OptionKind::PathAsIs(value) => value.to_string(),
             OptionKind::Proxy(value) => value.to_string(),
             OptionKind::Repeat(value) => value.to_string(),
+            OptionKind::RequestCharset(value) => value.to_string(),
             OptionKind::Resolve(value) => value.to_string(),
+            OptionKind::ResponseCharset(value) => value.to_string(),
             OptionKind::Retry(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
             OptionKind::Skip(value) => value.to_string(),
+            OptionKind::StripNewlines(value) => value.to_string(),
             OptionKind::UnixSocket(value) => value.to_string(),
             OptionKind::User(value) => value.to_string(),
             OptionKind::Variable(VariableDefinition { name, value, .. }) => {
@@ -902,6 +1011,9 @@ impl OptionKind {
             }
             OptionKind::Verbose(value) => value.to_string(),
             OptionKind::VeryVerbose(value) => value.to_string(),
+            OptionKind::XPathNamespace(VariableDefinition { name, value, .. }) => {
+                format!("{name}={value}")
+            }
         }
     }
 }
@@ -955,29 +1067,51 @@ pub struct Filter {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum FilterValue {
+    Base32Decode,
+    Base32Encode,
     Base64Decode,
     Base64Encode,
+    Base64UrlSafeDecode,
+    Base64UrlSafeEncode,
     Count,
+    /// A filter that is not built in Hurl, evaluated by a function registered by the runner.
+    Custom {
+        name: String,
+        args: Vec<(Whitespace, Template)>,
+    },
     DaysAfterNow,
     DaysBeforeNow,
     Decode {
         space0: Whitespace,
         encoding: Template,
     },
+    DecodeAuto,
+    Decompress,
     Format {
         space0: Whitespace,
         fmt: Template,
     },
+    HexDecode,
+    HexEncode,
     HtmlEscape,
     HtmlUnescape,
+    JsonEscape,
+    Jsonl,
     JsonPath {
         space0: Whitespace,
         expr: Template,
     },
+    JsonPointer {
+        space0: Whitespace,
+        expr: Template,
+    },
+    JsonUnescape,
+    Md5,
     Nth {
         space0: Whitespace,
         n: U64,
     },
+    PathEncode,
     Regex {
         space0: Whitespace,
         value: RegexValue,
@@ -988,6 +1122,8 @@ pub enum FilterValue {
         space1: Whitespace,
         new_value: Template,
     },
+    Sha1,
+    Sha256,
     Split {
         space0: Whitespace,
         sep: Template,
@@ -998,8 +1134,10 @@ pub enum FilterValue {
     },
     ToFloat,
     ToInt,
+    ToString,
     UrlDecode,
     UrlEncode,
+    XmlEscape,
     XPath {
         space0: Whitespace,
         expr: Template,
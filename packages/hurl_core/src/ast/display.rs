@@ -17,10 +17,10 @@
  */
 use crate::ast::{
     BooleanOption, CookieAttribute, CookieAttributeName, CookiePath, CountOption, DurationOption,
-    Expr, ExprKind, Float, Function, Hex, Method, MultilineString, MultilineStringAttribute,
-    MultilineStringKind, NaturalOption, Number, Placeholder, PredicateFuncValue, Regex, Status,
-    StatusValue, Template, TemplateElement, Variable, VariableDefinition, VariableValue, Version,
-    VersionValue,
+    Expr, ExprKind, FilterValue, Float, Function, Hex, Method, MultilineString,
+    MultilineStringAttribute, MultilineStringKind, NaturalOption, Number, Placeholder,
+    PredicateFuncValue, Regex, Status, StatusValue, Template, TemplateElement, Variable,
+    VariableDefinition, VariableValue, Version, VersionValue,
 };
 use core::fmt;
 
@@ -207,6 +207,7 @@ impl fmt::Display for MultilineStringAttribute {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             MultilineStringAttribute::Escape => write!(f, "escape"),
+            MultilineStringAttribute::NoNewline => write!(f, "nonewline"),
             MultilineStringAttribute::NoVariable => write!(f, "novariable"),
         }
     }
@@ -266,6 +267,52 @@ impl fmt::Display for VariableValue {
     }
 }
 
+impl FilterValue {
+    pub fn name(&self) -> &str {
+        match self {
+            FilterValue::Base32Decode => "base32Decode",
+            FilterValue::Base32Encode => "base32Encode",
+            FilterValue::Base64Decode => "base64Decode",
+            FilterValue::Base64Encode => "base64Encode",
+            FilterValue::Base64UrlSafeDecode => "base64UrlSafeDecode",
+            FilterValue::Base64UrlSafeEncode => "base64UrlSafeEncode",
+            FilterValue::Count => "count",
+            FilterValue::Custom { name, .. } => name,
+            FilterValue::DaysAfterNow => "daysAfterNow",
+            FilterValue::DaysBeforeNow => "daysBeforeNow",
+            FilterValue::Decode { .. } => "decode",
+            FilterValue::DecodeAuto => "decode",
+            FilterValue::Decompress => "decompress",
+            FilterValue::Format { .. } => "format",
+            FilterValue::HexDecode => "hexDecode",
+            FilterValue::HexEncode => "hexEncode",
+            FilterValue::HtmlEscape => "htmlEscape",
+            FilterValue::HtmlUnescape => "htmlUnescape",
+            FilterValue::JsonEscape => "jsonEscape",
+            FilterValue::Jsonl => "jsonl",
+            FilterValue::JsonPath { .. } => "jsonpath",
+            FilterValue::JsonPointer { .. } => "jsonpointer",
+            FilterValue::JsonUnescape => "jsonUnescape",
+            FilterValue::Md5 => "md5",
+            FilterValue::Nth { .. } => "nth",
+            FilterValue::PathEncode => "pathEncode",
+            FilterValue::Regex { .. } => "regex",
+            FilterValue::Replace { .. } => "replace",
+            FilterValue::Sha1 => "sha1",
+            FilterValue::Sha256 => "sha256",
+            FilterValue::Split { .. } => "split",
+            FilterValue::ToDate { .. } => "toDate",
+            FilterValue::ToFloat => "toFloat",
+            FilterValue::ToInt => "toInt",
+            FilterValue::ToString => "toString",
+            FilterValue::UrlDecode => "urlDecode",
+            FilterValue::UrlEncode => "urlEncode",
+            FilterValue::XmlEscape => "xmlEscape",
+            FilterValue::XPath { .. } => "xpath",
+        }
+    }
+}
+
 impl PredicateFuncValue {
     pub fn name(&self) -> &str {
         match self {
@@ -278,6 +325,8 @@ impl PredicateFuncValue {
             PredicateFuncValue::StartWith { .. } => "startsWith",
             PredicateFuncValue::EndWith { .. } => "endsWith",
             PredicateFuncValue::Contain { .. } => "contains",
+            PredicateFuncValue::ContainTimes { .. } => "containsTimes",
+            PredicateFuncValue::NearlyEqual { .. } => "nearlyEquals",
             PredicateFuncValue::Include { .. } => "includes",
             PredicateFuncValue::Match { .. } => "matches",
             PredicateFuncValue::IsInteger => "isInteger",
@@ -286,10 +335,16 @@ impl PredicateFuncValue {
             PredicateFuncValue::IsString => "isString",
             PredicateFuncValue::IsCollection => "isCollection",
             PredicateFuncValue::IsDate => "isDate",
+            PredicateFuncValue::IsDateFormat { .. } => "isDate",
             PredicateFuncValue::IsIsoDate => "isIsoDate",
             PredicateFuncValue::Exist => "exists",
             PredicateFuncValue::IsEmpty => "isEmpty",
             PredicateFuncValue::IsNumber => "isNumber",
+            PredicateFuncValue::IsUuid => "isUuid",
+            PredicateFuncValue::IsEmail => "isEmail",
+            PredicateFuncValue::IsUrl => "isUrl",
+            PredicateFuncValue::IsIpv4 => "isIpv4",
+            PredicateFuncValue::IsIpv6 => "isIpv6",
         }
     }
 }
@@ -226,10 +226,13 @@ impl HtmlFormatter {
             OptionKind::CaCertificate(filename) => self.fmt_filename(filename),
             OptionKind::ClientCert(filename) => self.fmt_filename(filename),
             OptionKind::ClientKey(filename) => self.fmt_filename(filename),
+            OptionKind::CompressRequest(value) => self.fmt_template(value),
             OptionKind::Compressed(value) => self.fmt_bool_option(value),
             OptionKind::ConnectTo(value) => self.fmt_template(value),
             OptionKind::ConnectTimeout(value) => self.fmt_duration_option(value),
             OptionKind::Delay(value) => self.fmt_duration_option(value),
+            OptionKind::FollowLink(value) => self.fmt_template(value),
+            OptionKind::FollowLinkMax(value) => self.fmt_count_option(value),
             OptionKind::FollowLocation(value) => self.fmt_bool_option(value),
             OptionKind::FollowLocationTrusted(value) => self.fmt_bool_option(value),
             OptionKind::Header(value) => self.fmt_template(value),
@@ -242,22 +245,29 @@ impl HtmlFormatter {
             OptionKind::IpV6(value) => self.fmt_bool_option(value),
             OptionKind::LimitRate(value) => self.fmt_natural_option(value),
             OptionKind::MaxRedirect(value) => self.fmt_count_option(value),
+            OptionKind::MaxTime(value) => self.fmt_duration_option(value),
+            OptionKind::Name(value) => self.fmt_template(value),
             OptionKind::NetRc(value) => self.fmt_bool_option(value),
             OptionKind::NetRcFile(filename) => self.fmt_filename(filename),
             OptionKind::NetRcOptional(value) => self.fmt_bool_option(value),
+            OptionKind::NoProxy(value) => self.fmt_template(value),
             OptionKind::Output(filename) => self.fmt_filename(filename),
             OptionKind::PathAsIs(value) => self.fmt_bool_option(value),
             OptionKind::Proxy(value) => self.fmt_template(value),
             OptionKind::Repeat(value) => self.fmt_count_option(value),
+            OptionKind::RequestCharset(value) => self.fmt_template(value),
             OptionKind::Resolve(value) => self.fmt_template(value),
+            OptionKind::ResponseCharset(value) => self.fmt_template(value),
             OptionKind::Retry(value) => self.fmt_count_option(value),
             OptionKind::RetryInterval(value) => self.fmt_duration_option(value),
             OptionKind::Skip(value) => self.fmt_bool_option(value),
+            OptionKind::StripNewlines(value) => self.fmt_bool_option(value),
             OptionKind::UnixSocket(value) => self.fmt_template(value),
             OptionKind::User(value) => self.fmt_template(value),
             OptionKind::Variable(value) => self.fmt_variable_definition(value),
             OptionKind::Verbose(value) => self.fmt_bool_option(value),
             OptionKind::VeryVerbose(value) => self.fmt_bool_option(value),
+            OptionKind::XPathNamespace(value) => self.fmt_variable_definition(value),
         };
         self.fmt_span_close();
         self.fmt_lt(&option.line_terminator0);
@@ -375,11 +385,27 @@ impl HtmlFormatter {
         match query_value {
             QueryValue::Status => self.fmt_span("query-type", "status"),
             QueryValue::Url => self.fmt_span("query-type", "url"),
+            QueryValue::Redirects => self.fmt_span("query-type", "redirects"),
             QueryValue::Header { space0, name } => {
                 self.fmt_span("query-type", "header");
                 self.fmt_space(space0);
                 self.fmt_template(name);
             }
+            QueryValue::Trailer { space0, name } => {
+                self.fmt_span("query-type", "trailer");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
+            QueryValue::ServerTiming { space0, name } => {
+                self.fmt_span("query-type", "server-timing");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
+            QueryValue::Link { space0, rel } => {
+                self.fmt_span("query-type", "link");
+                self.fmt_space(space0);
+                self.fmt_template(rel);
+            }
             QueryValue::Cookie { space0, expr } => {
                 self.fmt_span("query-type", "cookie");
                 self.fmt_space(space0);
@@ -396,6 +422,11 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(expr);
             }
+            QueryValue::Jsonpointer { space0, expr } => {
+                self.fmt_span("query-type", "jsonpointer");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
+            }
             QueryValue::Regex { space0, value } => {
                 self.fmt_span("query-type", "regex");
                 self.fmt_space(space0);
@@ -408,6 +439,7 @@ impl HtmlFormatter {
             }
             QueryValue::Duration => self.fmt_span("query-type", "duration"),
             QueryValue::Bytes => self.fmt_span("query-type", "bytes"),
+            QueryValue::RawBytes => self.fmt_span("query-type", "rawBytes"),
             QueryValue::Sha256 => self.fmt_span("query-type", "sha256"),
             QueryValue::Md5 => self.fmt_span("query-type", "md5"),
             QueryValue::Certificate {
@@ -418,6 +450,21 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_certificate_attribute_name(field);
             }
+            QueryValue::Timings {
+                space0,
+                attribute_name: field,
+            } => {
+                self.fmt_span("query-type", "timings");
+                self.fmt_space(space0);
+                self.fmt_span("string", &format!("\"{}\"", field.value()));
+            }
+            QueryValue::Custom { name, args } => {
+                self.fmt_span("query-type", &format!("@{name}"));
+                for (space, arg) in args {
+                    self.fmt_space(space);
+                    self.fmt_template(arg);
+                }
+            }
         }
     }
 
@@ -532,6 +579,31 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::ContainTimes {
+                space0,
+                value,
+                space1,
+                count,
+            } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+                self.fmt_space(space1);
+                self.fmt_number(count);
+            }
+            PredicateFuncValue::NearlyEqual {
+                space0,
+                value,
+                epsilon,
+            } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+                if let Some(epsilon) = epsilon {
+                    self.fmt_space(&epsilon.space0);
+                    self.fmt_span("predicate-type", "epsilon");
+                    self.fmt_space(&epsilon.space1);
+                    self.fmt_predicate_value(&epsilon.value);
+                }
+            }
             PredicateFuncValue::Include { space0, value } => {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
@@ -546,10 +618,19 @@ impl HtmlFormatter {
             PredicateFuncValue::IsString => {}
             PredicateFuncValue::IsCollection => {}
             PredicateFuncValue::IsDate => {}
+            PredicateFuncValue::IsDateFormat { space0, fmt } => {
+                self.fmt_space(space0);
+                self.fmt_template(fmt);
+            }
             PredicateFuncValue::IsIsoDate => {}
             PredicateFuncValue::Exist => {}
             PredicateFuncValue::IsEmpty => {}
             PredicateFuncValue::IsNumber => {}
+            PredicateFuncValue::IsUuid => {}
+            PredicateFuncValue::IsEmail => {}
+            PredicateFuncValue::IsUrl => {}
+            PredicateFuncValue::IsIpv4 => {}
+            PredicateFuncValue::IsIpv6 => {}
         }
     }
 
@@ -802,9 +883,20 @@ impl HtmlFormatter {
     fn fmt_filter_value(&mut self, filter_value: &FilterValue) {
         let class = "filter-type";
         match filter_value {
+            FilterValue::Base32Decode => self.fmt_span(class, "base32Decode"),
+            FilterValue::Base32Encode => self.fmt_span(class, "base32Encode"),
             FilterValue::Base64Decode => self.fmt_span(class, "base64Decode"),
             FilterValue::Base64Encode => self.fmt_span(class, "base64Encode"),
+            FilterValue::Base64UrlSafeDecode => self.fmt_span(class, "base64UrlSafeDecode"),
+            FilterValue::Base64UrlSafeEncode => self.fmt_span(class, "base64UrlSafeEncode"),
             FilterValue::Count => self.fmt_span(class, "count"),
+            FilterValue::Custom { name, args } => {
+                self.fmt_span(class, &format!("@{name}"));
+                for (space, arg) in args {
+                    self.fmt_space(space);
+                    self.fmt_template(arg);
+                }
+            }
             FilterValue::DaysAfterNow => self.fmt_span(class, "daysAfterNow"),
             FilterValue::DaysBeforeNow => self.fmt_span(class, "daysBeforeNow"),
             FilterValue::Decode { space0, encoding } => {
@@ -812,23 +904,39 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(encoding);
             }
+            FilterValue::DecodeAuto => self.fmt_span(class, "decode"),
+            FilterValue::Decompress => self.fmt_span(class, "decompress"),
             FilterValue::Format { space0, fmt } => {
                 self.fmt_span(class, "format");
                 self.fmt_space(space0);
                 self.fmt_template(fmt);
             }
+            FilterValue::HexDecode => self.fmt_span(class, "hexDecode"),
+            FilterValue::HexEncode => self.fmt_span(class, "hexEncode"),
             FilterValue::HtmlEscape => self.fmt_span(class, "htmlEscape"),
             FilterValue::HtmlUnescape => self.fmt_span(class, "htmlUnescape"),
+            FilterValue::JsonEscape => self.fmt_span(class, "jsonEscape"),
+            FilterValue::Jsonl => self.fmt_span(class, "jsonl"),
+            FilterValue::Md5 => self.fmt_span(class, "md5"),
+            FilterValue::Sha1 => self.fmt_span(class, "sha1"),
+            FilterValue::Sha256 => self.fmt_span(class, "sha256"),
             FilterValue::JsonPath { space0, expr } => {
                 self.fmt_span(class, "jsonpath");
                 self.fmt_space(space0);
                 self.fmt_template(expr);
             }
+            FilterValue::JsonPointer { space0, expr } => {
+                self.fmt_span(class, "jsonpointer");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
+            }
+            FilterValue::JsonUnescape => self.fmt_span(class, "jsonUnescape"),
             FilterValue::Nth { space0, n: value } => {
                 self.fmt_span(class, "nth");
                 self.fmt_space(space0);
                 self.fmt_number(value);
             }
+            FilterValue::PathEncode => self.fmt_span(class, "pathEncode"),
             FilterValue::Regex { space0, value } => {
                 self.fmt_span(class, "regex");
                 self.fmt_space(space0);
@@ -858,8 +966,10 @@ impl HtmlFormatter {
             }
             FilterValue::ToFloat => self.fmt_span(class, "toFloat"),
             FilterValue::ToInt => self.fmt_span(class, "toInt"),
+            FilterValue::ToString => self.fmt_span(class, "toString"),
             FilterValue::UrlDecode => self.fmt_span(class, "urlDecode"),
             FilterValue::UrlEncode => self.fmt_span(class, "urlEncode"),
+            FilterValue::XmlEscape => self.fmt_span(class, "xmlEscape"),
             FilterValue::XPath { space0, expr } => {
                 self.fmt_span(class, "xpath");
                 self.fmt_space(space0);
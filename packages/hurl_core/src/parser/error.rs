@@ -159,9 +159,12 @@ impl DisplaySourceError for ParseError {
                     "aws-sigv4",
                     "cacert",
                     "cert",
+                    "compress-request",
                     "compressed",
                     "connect-to",
                     "delay",
+                    "follow-link",
+                    "follow-link-max",
                     "insecure",
                     "header",
                     "http1.0",
@@ -176,10 +179,13 @@ impl DisplaySourceError for ParseError {
                     "output",
                     "path-as-is",
                     "proxy",
+                    "request-charset",
                     "resolve",
+                    "response-charset",
                     "retry",
                     "retry-interval",
                     "skip",
+                    "strip-newlines",
                     "unix-socket",
                     "variable",
                     "verbose",
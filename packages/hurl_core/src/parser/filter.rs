@@ -53,25 +53,43 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     let start = reader.cursor();
     let value = choice(
         &[
+            custom_filter,
+            base32_decode_filter,
+            base32_encode_filter,
             base64_decode_filter,
             base64_encode_filter,
+            base64_url_safe_decode_filter,
+            base64_url_safe_encode_filter,
             count_filter,
             days_after_now_filter,
             days_before_now_filter,
             decode_filter,
+            decompress_filter,
             format_filter,
+            hex_decode_filter,
+            hex_encode_filter,
             html_decode_filter,
             html_encode_filter,
+            json_escape_filter,
+            jsonl_filter,
             jsonpath_filter,
+            jsonpointer_filter,
+            json_unescape_filter,
+            md5_filter,
             nth_filter,
+            path_encode_filter,
             regex_filter,
             replace_filter,
+            sha1_filter,
+            sha256_filter,
             split_filter,
             to_float_filter,
             to_int_filter,
             to_date_filter,
+            to_string_filter,
             url_decode_filter,
             url_encode_filter,
+            xml_escape_filter,
             xpath_filter,
         ],
         reader,
@@ -94,6 +112,45 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     Ok(Filter { source_info, value })
 }
 
+/// Parses a filter that is not built in Hurl: `@` followed by the filter name, optionally followed by
+/// quoted arguments.
+fn custom_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("@", reader)?;
+    let start = reader.cursor();
+    if !reader.peek().is_some_and(|c| c.is_ascii_lowercase()) {
+        let kind = ParseErrorKind::Expecting {
+            value: "filter name".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    let name = reader.read_while(|c| c.is_ascii_alphanumeric());
+    let mut args = vec![];
+    loop {
+        let save = reader.cursor();
+        match one_or_more_spaces(reader) {
+            Ok(space) if reader.peek() == Some('"') => {
+                let arg = quoted_template(reader)?;
+                args.push((space, arg));
+            }
+            _ => {
+                reader.seek(save);
+                break;
+            }
+        }
+    }
+    Ok(FilterValue::Custom { name, args })
+}
+
+fn base32_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base32Decode", reader)?;
+    Ok(FilterValue::Base32Decode)
+}
+
+fn base32_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base32Encode", reader)?;
+    Ok(FilterValue::Base32Encode)
+}
+
 fn base64_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("base64Decode", reader)?;
     Ok(FilterValue::Base64Decode)
@@ -104,6 +161,16 @@ fn base64_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Base64Encode)
 }
 
+fn base64_url_safe_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64UrlSafeDecode", reader)?;
+    Ok(FilterValue::Base64UrlSafeDecode)
+}
+
+fn base64_url_safe_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64UrlSafeEncode", reader)?;
+    Ok(FilterValue::Base64UrlSafeEncode)
+}
+
 fn count_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("count", reader)?;
     Ok(FilterValue::Count)
@@ -121,9 +188,22 @@ fn days_before_now_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
 
 fn decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("decode", reader)?;
-    let space0 = one_or_more_spaces(reader)?;
-    let encoding = quoted_template(reader)?;
-    Ok(FilterValue::Decode { space0, encoding })
+    // `decode` can be optionally followed by an encoding, otherwise the encoding is taken from
+    // the response Content-Type header.
+    let save = reader.cursor();
+    if let Ok(space0) = one_or_more_spaces(reader) {
+        if reader.peek() == Some('"') {
+            let encoding = quoted_template(reader)?;
+            return Ok(FilterValue::Decode { space0, encoding });
+        }
+    }
+    reader.seek(save);
+    Ok(FilterValue::DecodeAuto)
+}
+
+fn decompress_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("decompress", reader)?;
+    Ok(FilterValue::Decompress)
 }
 
 fn format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
@@ -138,11 +218,31 @@ fn html_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::HtmlEscape)
 }
 
+fn hex_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("hexDecode", reader)?;
+    Ok(FilterValue::HexDecode)
+}
+
+fn hex_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("hexEncode", reader)?;
+    Ok(FilterValue::HexEncode)
+}
+
 fn html_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("htmlUnescape", reader)?;
     Ok(FilterValue::HtmlUnescape)
 }
 
+fn json_escape_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonEscape", reader)?;
+    Ok(FilterValue::JsonEscape)
+}
+
+fn jsonl_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonl", reader)?;
+    Ok(FilterValue::Jsonl)
+}
+
 fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("jsonpath", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -150,6 +250,23 @@ fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::JsonPath { space0, expr })
 }
 
+fn jsonpointer_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonpointer", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::JsonPointer { space0, expr })
+}
+
+fn json_unescape_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonUnescape", reader)?;
+    Ok(FilterValue::JsonUnescape)
+}
+
+fn md5_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("md5", reader)?;
+    Ok(FilterValue::Md5)
+}
+
 fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("nth", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -157,6 +274,11 @@ fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Nth { space0, n })
 }
 
+fn path_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("pathEncode", reader)?;
+    Ok(FilterValue::PathEncode)
+}
+
 fn regex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("regex", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -178,6 +300,16 @@ fn replace_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     })
 }
 
+fn sha1_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("sha1", reader)?;
+    Ok(FilterValue::Sha1)
+}
+
+fn sha256_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("sha256", reader)?;
+    Ok(FilterValue::Sha256)
+}
+
 fn split_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("split", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -202,6 +334,11 @@ fn to_int_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::ToInt)
 }
 
+fn to_string_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("toString", reader)?;
+    Ok(FilterValue::ToString)
+}
+
 fn url_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("urlEncode", reader)?;
     Ok(FilterValue::UrlEncode)
@@ -212,6 +349,11 @@ fn url_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::UrlDecode)
 }
 
+fn xml_escape_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("xmlEscape", reader)?;
+    Ok(FilterValue::XmlEscape)
+}
+
 fn xpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("xpath", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -222,6 +364,7 @@ fn xpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::{Template, TemplateElement};
     use crate::parser::ParseErrorKind;
     use crate::reader::Pos;
 
@@ -237,6 +380,136 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode() {
+        let mut reader = Reader::new("decode \"gb2312\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 16)),
+                value: FilterValue::Decode {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                    },
+                    encoding: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "gb2312".to_string(),
+                            encoded: "gb2312".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 16)),
+                    },
+                },
+            }
+        );
+
+        let mut reader = Reader::new("decode == \"café\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+                value: FilterValue::DecodeAuto,
+            }
+        );
+        assert_eq!(reader.cursor().index, 6);
+    }
+
+    #[test]
+    fn test_path_encode() {
+        let mut reader = Reader::new("pathEncode");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 11)),
+                value: FilterValue::PathEncode,
+            }
+        );
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut reader = Reader::new("sha256 == \"abc\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+                value: FilterValue::Sha256,
+            }
+        );
+
+        let mut reader = Reader::new("sha1");
+        assert_eq!(filter(&mut reader).unwrap().value, FilterValue::Sha1);
+
+        let mut reader = Reader::new("md5");
+        assert_eq!(filter(&mut reader).unwrap().value, FilterValue::Md5);
+    }
+
+    #[test]
+    fn test_to_string() {
+        let mut reader = Reader::new("toString");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+                value: FilterValue::ToString,
+            }
+        );
+    }
+
+    #[test]
+    fn test_custom() {
+        let mut reader = Reader::new("@reverseString == \"olleh\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 15)),
+                value: FilterValue::Custom {
+                    name: "reverseString".to_string(),
+                    args: vec![],
+                },
+            }
+        );
+        assert_eq!(reader.cursor().index, 14);
+
+        let mut reader = Reader::new("@pad \"-\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+                value: FilterValue::Custom {
+                    name: "pad".to_string(),
+                    args: vec![(
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                        },
+                        Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "-".to_string(),
+                                encoded: "-".to_string(),
+                            }],
+                            source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 9)),
+                        }
+                    )],
+                },
+            }
+        );
+
+        // A custom filter name is mandatory after `@`.
+        let mut reader = Reader::new("@ \"-\"");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Expecting {
+                value: "filter name".to_string()
+            }
+        );
+        assert_eq!(err.pos, Pos::new(1, 2));
+        assert!(!err.recoverable);
+    }
+
     #[test]
     fn test_error() {
         let mut reader = Reader::new("xcount");
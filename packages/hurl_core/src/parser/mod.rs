@@ -22,13 +22,45 @@ pub fn parse_hurl_file(s: &str) -> ParseResult<HurlFile> {
     parsers::hurl_file(&mut reader)
 }
 
+/// Parses a single assert like `status < 400` or `jsonpath "$.id" exists`.
+pub fn parse_assert(s: &str) -> ParseResult<Assert> {
+    let mut reader = Reader::new(s);
+    let assert = sections::assert(&mut reader)?;
+    primitives::eof(&mut reader)?;
+    Ok(assert)
+}
+
+/// Parses a template like `build/{{slug}}.json`, where every character outside placeholders is
+/// taken literally.
+pub fn parse_template(s: &str) -> ParseResult<Template> {
+    let mut reader = Reader::new(s);
+    let start = reader.cursor();
+    let mut chars = vec![];
+    loop {
+        let pos = reader.cursor().pos;
+        match reader.read() {
+            Some(c) => chars.push((c, c.to_string(), pos)),
+            None => break,
+        }
+    }
+    let end = reader.cursor();
+    let source_info = SourceInfo::new(start.pos, end.pos);
+    let encoded_string = template::EncodedString { source_info, chars };
+    let elements = templatize(encoded_string)?;
+    Ok(Template {
+        delimiter: None,
+        elements,
+        source_info,
+    })
+}
+
 pub use self::error::{JsonErrorVariant, ParseError, ParseErrorKind};
 pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
     number_value as parse_json_number, parse as parse_json,
 };
 pub use self::template::templatize;
-use crate::ast::HurlFile;
+use crate::ast::{Assert, HurlFile, SourceInfo, Template};
 use crate::reader::Reader;
 
 mod base64;
@@ -56,3 +88,38 @@ mod sections;
 mod string;
 mod template;
 mod xml;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{PredicateFuncValue, QueryValue};
+    use crate::reader::Pos;
+
+    #[test]
+    fn test_parse_assert() {
+        let assert = parse_assert("status < 400").unwrap();
+        assert_eq!(assert.query.value, QueryValue::Status);
+        assert!(matches!(
+            assert.predicate.predicate_func.value,
+            PredicateFuncValue::LessThan { .. }
+        ));
+
+        let error = parse_assert("status < 400 xxx").unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 14));
+
+        assert!(parse_assert("status <").is_err());
+    }
+
+    #[test]
+    fn test_parse_template() {
+        let template = parse_template("build/{{slug}}.json").unwrap();
+        assert_eq!(template.elements.len(), 3);
+        assert_eq!(template.to_string(), "build/{{slug}}.json");
+
+        let template = parse_template("build/out put#1.json").unwrap();
+        assert_eq!(template.to_string(), "build/out put#1.json");
+
+        let error = parse_template("build/{{slug.json").unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 18));
+    }
+}
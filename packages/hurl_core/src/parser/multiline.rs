@@ -143,6 +143,8 @@ fn multiline_string_attributes(reader: &mut Reader) -> ParseResult<Vec<Multiline
             .to_string();
         if attribute == "escape" {
             attributes.push(MultilineStringAttribute::Escape);
+        } else if attribute == "nonewline" {
+            attributes.push(MultilineStringAttribute::NoNewline);
         } else if attribute == "novariable" {
             attributes.push(MultilineStringAttribute::NoVariable);
         } else {
@@ -621,6 +623,16 @@ mod tests {
         assert_eq!(multiline_string_attributes(&mut reader).unwrap(), vec![]);
         assert_eq!(reader.cursor().index, 0);
 
+        let mut reader = Reader::new("escape,nonewline\n```");
+        assert_eq!(
+            multiline_string_attributes(&mut reader).unwrap(),
+            vec![
+                MultilineStringAttribute::Escape,
+                MultilineStringAttribute::NoNewline
+            ]
+        );
+        assert_eq!(reader.cursor().index, 16);
+
         let mut reader = Reader::new("toto\n```");
         let error = multiline_string_attributes(&mut reader).unwrap_err();
         assert_eq!(
@@ -667,6 +679,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multiline_string_nonewline() {
+        let mut reader = Reader::new("```json,nonewline\n{}\n```");
+        assert_eq!(
+            multiline_string(&mut reader).unwrap(),
+            MultilineString {
+                kind: MultilineStringKind::Json(Text {
+                    space: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(1, 18), Pos::new(1, 18)),
+                    },
+                    newline: Whitespace {
+                        value: "\n".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 18), Pos::new(2, 1)),
+                    },
+                    value: Template {
+                        delimiter: None,
+                        elements: vec![TemplateElement::String {
+                            value: "{}\n".to_string(),
+                            encoded: "{}\n".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(2, 1), Pos::new(3, 1)),
+                    },
+                }),
+                attributes: vec![MultilineStringAttribute::NoNewline]
+            }
+        );
+    }
+
     #[test]
     fn test_multiline_string_error() {
         let mut reader = Reader::new("xxx");
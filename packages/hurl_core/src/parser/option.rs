@@ -50,10 +50,13 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "aws-sigv4" => option_aws_sigv4(reader)?,
         "cacert" => option_cacert(reader)?,
         "cert" => option_cert(reader)?,
+        "compress-request" => option_compress_request(reader)?,
         "compressed" => option_compressed(reader)?,
         "connect-to" => option_connect_to(reader)?,
         "connect-timeout" => option_connect_timeout(reader)?,
         "delay" => option_delay(reader)?,
+        "follow-link" => option_follow_link(reader)?,
+        "follow-link-max" => option_follow_link_max(reader)?,
         "insecure" => option_insecure(reader)?,
         "header" => option_header(reader)?,
         "http1.0" => option_http_10(reader)?,
@@ -67,22 +70,29 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "location" => option_follow_location(reader)?,
         "location-trusted" => option_follow_location_trusted(reader)?,
         "max-redirs" => option_max_redirect(reader)?,
+        "max-time" => option_max_time(reader)?,
+        "name" => option_name(reader)?,
         "netrc" => option_netrc(reader)?,
         "netrc-file" => option_netrc_file(reader)?,
         "netrc-optional" => option_netrc_optional(reader)?,
+        "noproxy" => option_noproxy(reader)?,
         "output" => option_output(reader)?,
         "path-as-is" => option_path_as_is(reader)?,
         "proxy" => option_proxy(reader)?,
         "repeat" => option_repeat(reader)?,
+        "request-charset" => option_request_charset(reader)?,
         "resolve" => option_resolve(reader)?,
+        "response-charset" => option_response_charset(reader)?,
         "retry" => option_retry(reader)?,
         "retry-interval" => option_retry_interval(reader)?,
         "skip" => option_skip(reader)?,
+        "strip-newlines" => option_strip_newlines(reader)?,
         "unix-socket" => option_unix_socket(reader)?,
         "user" => option_user(reader)?,
         "variable" => option_variable(reader)?,
         "verbose" => option_verbose(reader)?,
         "very-verbose" => option_very_verbose(reader)?,
+        "xpath-namespace" => option_xpath_namespace(reader)?,
         _ => {
             return Err(ParseError::new(
                 start.pos,
@@ -118,6 +128,11 @@ fn option_cert(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::ClientCert(value))
 }
 
+fn option_compress_request(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::CompressRequest(value))
+}
+
 fn option_compressed(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Compressed(value))
@@ -138,6 +153,16 @@ fn option_delay(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Delay(value))
 }
 
+fn option_follow_link(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::FollowLink(value))
+}
+
+fn option_follow_link_max(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(count_option, reader)?;
+    Ok(OptionKind::FollowLinkMax(value))
+}
+
 fn option_follow_location(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::FollowLocation(value))
@@ -203,6 +228,16 @@ fn option_max_redirect(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::MaxRedirect(value))
 }
 
+fn option_max_time(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = duration_option(reader)?;
+    Ok(OptionKind::MaxTime(value))
+}
+
+fn option_name(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::Name(value))
+}
+
 fn option_netrc(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::NetRc(value))
@@ -218,6 +253,11 @@ fn option_netrc_optional(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::NetRcOptional(value))
 }
 
+fn option_noproxy(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::NoProxy(value))
+}
+
 fn option_output(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename::parse(reader)?;
     Ok(OptionKind::Output(value))
@@ -238,11 +278,21 @@ fn option_repeat(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Repeat(value))
 }
 
+fn option_request_charset(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::RequestCharset(value))
+}
+
 fn option_resolve(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::Resolve(value))
 }
 
+fn option_response_charset(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::ResponseCharset(value))
+}
+
 fn option_retry(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(count_option, reader)?;
     Ok(OptionKind::Retry(value))
@@ -258,6 +308,11 @@ fn option_skip(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Skip(value))
 }
 
+fn option_strip_newlines(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::StripNewlines(value))
+}
+
 fn option_user(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::User(value))
@@ -283,6 +338,11 @@ fn option_very_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::VeryVerbose(value))
 }
 
+fn option_xpath_namespace(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = variable_definition(reader)?;
+    Ok(OptionKind::XPathNamespace(value))
+}
+
 fn count(reader: &mut Reader) -> ParseResult<Count> {
     let start = reader.cursor();
     let value = non_recover(integer, reader)?;
@@ -445,9 +505,34 @@ fn variable_value(reader: &mut Reader) -> ParseResult<VariableValue> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{LineTerminator, Number, Template, TemplateElement, Whitespace, I64};
+    use crate::ast::{
+        Expr, ExprKind, LineTerminator, Number, Placeholder, Template, TemplateElement, Variable,
+        Whitespace, I64,
+    };
     use crate::reader::Pos;
 
+    #[test]
+    fn test_option_follow_link() {
+        let mut reader = Reader::new("follow-link: next\nfollow-link-max: 10");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(
+            option.kind,
+            OptionKind::FollowLink(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "next".to_string(),
+                    encoded: "next".to_string()
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 18)),
+            })
+        );
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(
+            option.kind,
+            OptionKind::FollowLinkMax(CountOption::Literal(Count::Finite(10)))
+        );
+    }
+
     #[test]
     fn test_option_insecure() {
         let mut reader = Reader::new("insecure: true");
@@ -630,6 +715,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_name() {
+        let mut reader = Reader::new("Login with {{user}}");
+
+        assert_eq!(
+            option_name(&mut reader).unwrap(),
+            OptionKind::Name(Template {
+                delimiter: None,
+                elements: vec![
+                    TemplateElement::String {
+                        value: "Login with ".to_string(),
+                        encoded: "Login with ".to_string()
+                    },
+                    TemplateElement::Placeholder(Placeholder {
+                        space0: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 14)),
+                        },
+                        expr: Expr {
+                            kind: ExprKind::Variable(Variable {
+                                name: "user".to_string(),
+                                source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 18)),
+                            }),
+                            source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 18)),
+                        },
+                        space1: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(Pos::new(1, 18), Pos::new(1, 18)),
+                        },
+                    }),
+                ],
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            }),
+        );
+    }
+
+    #[test]
+    fn test_option_noproxy() {
+        let mut reader = Reader::new("localhost,example.com");
+
+        assert_eq!(
+            option_noproxy(&mut reader).unwrap(),
+            OptionKind::NoProxy(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "localhost,example.com".to_string(),
+                    encoded: "localhost,example.com".to_string()
+                }],
+                source_info: SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos {
+                        line: 1,
+                        column: 22,
+                    },
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn test_option_proxy_empty() {
+        // An empty proxy disables the use of any proxy for this entry.
+        let mut reader = Reader::new("\n");
+
+        assert_eq!(
+            option_proxy(&mut reader).unwrap(),
+            OptionKind::Proxy(Template {
+                delimiter: None,
+                elements: vec![],
+                source_info: SourceInfo {
+                    start: Pos { line: 1, column: 1 },
+                    end: Pos { line: 1, column: 1 },
+                },
+            }),
+        );
+    }
+
     #[test]
     fn test_option_retry_error() {
         let mut reader = Reader::new("retry: ###");
@@ -18,9 +18,11 @@
 use crate::ast::{
     Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo, Whitespace,
 };
-use crate::combinator::choice;
-use crate::parser::predicate_value::predicate_value;
+use crate::combinator::{choice, ParseError as ParseErrorTrait};
+use crate::parser::number::natural;
+use crate::parser::predicate_value::{optional_epsilon, predicate_value};
 use crate::parser::primitives::{one_or_more_spaces, try_literal, zero_or_more_spaces};
+use crate::parser::string::quoted_template;
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
 
@@ -79,7 +81,9 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             less_predicate,
             start_with_predicate,
             end_with_predicate,
+            contain_times_predicate,
             contain_predicate,
+            nearly_equal_predicate,
             include_predicate,
             match_predicate,
             integer_predicate,
@@ -92,6 +96,11 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             exist_predicate,
             is_empty_predicate,
             is_number_predicate,
+            is_uuid_predicate,
+            is_email_predicate,
+            is_url_predicate,
+            is_ipv4_predicate,
+            is_ipv6_predicate,
         ],
         reader,
     ) {
@@ -242,6 +251,48 @@ fn contain_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Contain { space0, value })
 }
 
+fn contain_times_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("containsTimes", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_string() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let count = natural(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(PredicateFuncValue::ContainTimes {
+        space0,
+        value,
+        space1,
+        count,
+    })
+}
+
+fn nearly_equal_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("nearlyEquals", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_number() && !value.is_expression() {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    let epsilon = optional_epsilon(reader)?;
+    Ok(PredicateFuncValue::NearlyEqual {
+        space0,
+        value,
+        epsilon,
+    })
+}
+
 fn include_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("includes", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -254,7 +305,10 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     let space0 = one_or_more_spaces(reader)?;
     let save = reader.cursor();
     let value = predicate_value(reader)?;
-    if !matches!(value, PredicateValue::String(_)) && !matches!(value, PredicateValue::Regex(_)) {
+    if !matches!(
+        value,
+        PredicateValue::String(_) | PredicateValue::Regex(_) | PredicateValue::Placeholder(_)
+    ) {
         return Err(ParseError::new(
             save.pos,
             false,
@@ -291,6 +345,15 @@ fn collection_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
 
 fn date_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("isDate", reader)?;
+    // `isDate` can be optionally followed by a format, to check that a string is a date.
+    let save = reader.cursor();
+    if let Ok(space0) = one_or_more_spaces(reader) {
+        if reader.peek() == Some('"') {
+            let fmt = quoted_template(reader)?;
+            return Ok(PredicateFuncValue::IsDateFormat { space0, fmt });
+        }
+    }
+    reader.seek(save);
     Ok(PredicateFuncValue::IsDate)
 }
 
@@ -314,11 +377,37 @@ fn is_number_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::IsNumber)
 }
 
+fn is_uuid_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isUuid", reader)?;
+    Ok(PredicateFuncValue::IsUuid)
+}
+
+fn is_email_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isEmail", reader)?;
+    Ok(PredicateFuncValue::IsEmail)
+}
+
+fn is_url_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isUrl", reader)?;
+    Ok(PredicateFuncValue::IsUrl)
+}
+
+fn is_ipv4_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isIpv4", reader)?;
+    Ok(PredicateFuncValue::IsIpv4)
+}
+
+fn is_ipv6_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isIpv6", reader)?;
+    Ok(PredicateFuncValue::IsIpv6)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ast::{
-        Expr, ExprKind, Float, Number, Placeholder, Template, TemplateElement, Variable, I64,
+        Epsilon, Expr, ExprKind, Float, Number, Placeholder, Template, TemplateElement, Variable,
+        I64, U64,
     };
     use crate::reader::Pos;
 
@@ -477,6 +566,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_match_expression_predicate() {
+        let mut reader = Reader::new("matches {{pattern}}");
+        assert_eq!(
+            match_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::Match {
+                value: PredicateValue::Placeholder(Placeholder {
+                    space0: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 11)),
+                    },
+                    expr: Expr {
+                        kind: ExprKind::Variable(Variable {
+                            name: "pattern".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 18)),
+                        }),
+                        source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 18)),
+                    },
+                    space1: Whitespace {
+                        value: String::new(),
+                        source_info: SourceInfo::new(Pos::new(1, 18), Pos::new(1, 18)),
+                    },
+                }),
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("matches 2");
+        let error = match_predicate(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 9));
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
     #[test]
     fn test_start_with_predicate() {
         let mut reader = Reader::new("startsWith 2");
@@ -492,10 +617,122 @@ mod tests {
         assert_eq!(error.kind, ParseErrorKind::PredicateValue);
     }
 
+    #[test]
+    fn test_contain_times_predicate() {
+        let mut reader = Reader::new("containsTimes \"Hello\" 3");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::ContainTimes {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 15)),
+                },
+                value: PredicateValue::String(Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "Hello".to_string(),
+                        encoded: "Hello".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 22)),
+                }),
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 23)),
+                },
+                count: U64::new(3, "3".to_string()),
+            }
+        );
+
+        let mut reader = Reader::new("containsTimes \"Hello\"");
+        let error = contain_times_predicate(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 22));
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::new("containsTimes 2 3");
+        let error = contain_times_predicate(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 15));
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
+    #[test]
+    fn test_nearly_equal_predicate() {
+        let mut reader = Reader::new("nearlyEquals 19.95");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::NearlyEqual {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 14)),
+                },
+                value: PredicateValue::Number(Number::Float(Float {
+                    value: 19.95,
+                    encoded: "19.95".to_string(),
+                })),
+                epsilon: None,
+            }
+        );
+
+        let mut reader = Reader::new("nearlyEquals 20 epsilon 0.1");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::NearlyEqual {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 14)),
+                },
+                value: PredicateValue::Number(Number::Integer(I64::new(20, "20".to_string()))),
+                epsilon: Some(Epsilon {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 16), Pos::new(1, 17)),
+                    },
+                    space1: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 24), Pos::new(1, 25)),
+                    },
+                    value: PredicateValue::Number(Number::Float(Float {
+                        value: 0.1,
+                        encoded: "0.1".to_string(),
+                    })),
+                }),
+            }
+        );
+
+        let mut reader = Reader::new("nearlyEquals \"19.95\"");
+        let error = nearly_equal_predicate(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 14));
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
     #[test]
     fn test_date_predicate() {
         let mut reader = Reader::new("isDate");
         let result = date_predicate(&mut reader);
         assert_eq!(result.unwrap(), PredicateFuncValue::IsDate);
+
+        let mut reader = Reader::new("isDate # comment");
+        let result = date_predicate(&mut reader);
+        assert_eq!(result.unwrap(), PredicateFuncValue::IsDate);
+        assert_eq!(reader.cursor().index, 6);
+
+        let mut reader = Reader::new("isDate \"%Y-%m-%d\"");
+        let result = date_predicate(&mut reader);
+        assert_eq!(
+            result.unwrap(),
+            PredicateFuncValue::IsDateFormat {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                fmt: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "%Y-%m-%d".to_string(),
+                        encoded: "%Y-%m-%d".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 18)),
+                },
+            }
+        );
     }
 }
@@ -15,11 +15,13 @@
  * limitations under the License.
  *
  */
-use crate::ast::PredicateValue;
-use crate::combinator::choice;
+use crate::ast::{Epsilon, PredicateValue};
+use crate::combinator::{choice, ParseError as ParseErrorTrait};
 use crate::parser::multiline::multiline_string;
 use crate::parser::number::number;
-use crate::parser::primitives::{base64, boolean, file, hex, null, regex};
+use crate::parser::primitives::{
+    base64, boolean, file, hex, null, one_or_more_spaces, regex, try_literal,
+};
 use crate::parser::string::{backtick_template, quoted_template};
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
@@ -86,10 +88,43 @@ pub fn predicate_value(reader: &mut Reader) -> ParseResult<PredicateValue> {
     })
 }
 
+/// Parses an optional tolerance of a `nearlyEquals` predicate, for instance ` epsilon 0.001`.
+///
+/// The tolerance value must be a number or a placeholder.
+pub fn optional_epsilon(reader: &mut Reader) -> ParseResult<Option<Epsilon>> {
+    let save = reader.cursor();
+    let space0 = match one_or_more_spaces(reader) {
+        Ok(space) => space,
+        Err(_) => {
+            reader.seek(save);
+            return Ok(None);
+        }
+    };
+    if try_literal("epsilon", reader).is_err() {
+        reader.seek(save);
+        return Ok(None);
+    }
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let start = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !value.is_number() && !value.is_expression() {
+        return Err(ParseError::new(
+            start.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(Some(Epsilon {
+        space0,
+        space1,
+        value,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{Float, Number, I64};
+    use crate::ast::{Float, Number, SourceInfo, Whitespace, I64};
     use crate::parser::ParseErrorKind;
     use crate::reader::Pos;
 
@@ -117,6 +152,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_optional_epsilon() {
+        let mut reader = Reader::new(" epsilon 0.001");
+        assert_eq!(
+            optional_epsilon(&mut reader).unwrap(),
+            Some(Epsilon {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 2)),
+                },
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 10)),
+                },
+                value: PredicateValue::Number(Number::Float(Float {
+                    value: 0.001,
+                    encoded: "0.001".to_string(),
+                })),
+            })
+        );
+
+        // No tolerance: the reader is not consumed.
+        let mut reader = Reader::new(" # comment");
+        assert_eq!(optional_epsilon(&mut reader).unwrap(), None);
+        assert_eq!(reader.cursor().index, 0);
+
+        let mut reader = Reader::new(" epsilon \"0.001\"");
+        let error = optional_epsilon(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 10));
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_predicate_value_error() {
         let mut reader = Reader::new("xx");
@@ -145,4 +213,19 @@ mod tests {
         );
         assert!(!error.recoverable);
     }
+
+    #[test]
+    fn test_predicate_value_error_odd_hex() {
+        let mut reader = Reader::new("hex,89504e4;");
+        let error = predicate_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 12
+            }
+        );
+        assert_eq!(error.kind, ParseErrorKind::OddNumberOfHexDigits);
+        assert!(!error.recoverable);
+    }
 }
@@ -15,7 +15,9 @@
  * limitations under the License.
  *
  */
-use crate::ast::{CertificateAttributeName, Query, QueryValue, RegexValue, SourceInfo};
+use crate::ast::{
+    CertificateAttributeName, Query, QueryValue, RegexValue, SourceInfo, TimingsAttributeName,
+};
 use crate::combinator::{choice, ParseError as ParseErrorTrait};
 use crate::parser::cookiepath::cookiepath;
 use crate::parser::primitives::{literal, one_or_more_spaces, regex, try_literal};
@@ -36,25 +38,62 @@ pub fn query(reader: &mut Reader) -> ParseResult<Query> {
 fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
     choice(
         &[
+            custom_query,
             status_query,
             url_query,
+            redirects_query,
             header_query,
+            trailer_query,
+            server_timing_query,
+            link_query,
             cookie_query,
             body_query,
             xpath_query,
             jsonpath_query,
+            jsonpointer_query,
             regex_query,
             variable_query,
             duration_query,
             bytes_query,
+            raw_bytes_query,
             sha256_query,
             md5_query,
             certificate_query,
+            timings_query,
         ],
         reader,
     )
 }
 
+/// Parses a query that is not built in Hurl: `@` followed by the query name, optionally followed by
+/// quoted arguments.
+fn custom_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("@", reader)?;
+    let start = reader.cursor();
+    if !reader.peek().is_some_and(|c| c.is_ascii_lowercase()) {
+        let kind = ParseErrorKind::Expecting {
+            value: "query name".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    let name = reader.read_while(|c| c.is_ascii_alphanumeric());
+    let mut args = vec![];
+    loop {
+        let save = reader.cursor();
+        match one_or_more_spaces(reader) {
+            Ok(space) if reader.peek() == Some('"') => {
+                let arg = quoted_template(reader)?;
+                args.push((space, arg));
+            }
+            _ => {
+                reader.seek(save);
+                break;
+            }
+        }
+    }
+    Ok(QueryValue::Custom { name, args })
+}
+
 fn status_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("status", reader)?;
     Ok(QueryValue::Status)
@@ -65,6 +104,11 @@ fn url_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Url)
 }
 
+fn redirects_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("redirects", reader)?;
+    Ok(QueryValue::Redirects)
+}
+
 fn header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("header", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -72,6 +116,27 @@ fn header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Header { space0, name })
 }
 
+fn trailer_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("trailer", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::Trailer { space0, name })
+}
+
+fn server_timing_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("server-timing", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::ServerTiming { space0, name })
+}
+
+fn link_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("link", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let rel = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::Link { space0, rel })
+}
+
 fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("cookie", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -123,6 +188,13 @@ fn jsonpath_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Jsonpath { space0, expr })
 }
 
+fn jsonpointer_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("jsonpointer", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::Jsonpointer { space0, expr })
+}
+
 fn regex_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("regex", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -169,6 +241,11 @@ fn bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Bytes)
 }
 
+fn raw_bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("rawBytes", reader)?;
+    Ok(QueryValue::RawBytes)
+}
+
 fn sha256_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("sha256", reader)?;
     Ok(QueryValue::Sha256)
@@ -210,6 +287,38 @@ fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeNam
     }
 }
 
+fn timings_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("timings", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let field = timings_field(reader)?;
+    Ok(QueryValue::Timings {
+        space0,
+        attribute_name: field,
+    })
+}
+
+fn timings_field(reader: &mut Reader) -> ParseResult<TimingsAttributeName> {
+    literal("\"", reader)?;
+    if try_literal(r#"namelookup""#, reader).is_ok() {
+        Ok(TimingsAttributeName::NameLookup)
+    } else if try_literal(r#"connect""#, reader).is_ok() {
+        Ok(TimingsAttributeName::Connect)
+    } else if try_literal(r#"appconnect""#, reader).is_ok() {
+        Ok(TimingsAttributeName::AppConnect)
+    } else if try_literal(r#"pretransfer""#, reader).is_ok() {
+        Ok(TimingsAttributeName::PreTransfer)
+    } else if try_literal(r#"starttransfer""#, reader).is_ok() {
+        Ok(TimingsAttributeName::StartTransfer)
+    } else if try_literal(r#"total""#, reader).is_ok() {
+        Ok(TimingsAttributeName::Total)
+    } else {
+        let value = "Field <namelookup>, <connect>, <appconnect>, <pretransfer>, <starttransfer> or <total>".to_string();
+        let kind = ParseErrorKind::Expecting { value };
+        let cur = reader.cursor();
+        Err(ParseError::new(cur.pos, false, kind))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +353,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_redirects_query() {
+        let mut reader = Reader::new("redirects count");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+                value: QueryValue::Redirects,
+            }
+        );
+    }
+
     #[test]
     fn test_header_query() {
         let mut reader = Reader::new("header \"Foo\"");
@@ -266,6 +387,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trailer_query() {
+        let mut reader = Reader::new("trailer \"grpc-status\"");
+        assert_eq!(
+            trailer_query(&mut reader).unwrap(),
+            QueryValue::Trailer {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "grpc-status".to_string(),
+                        encoded: "grpc-status".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 22)),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_server_timing_query() {
+        let mut reader = Reader::new("server-timing \"db\"");
+        assert_eq!(
+            server_timing_query(&mut reader).unwrap(),
+            QueryValue::ServerTiming {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 15)),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "db".to_string(),
+                        encoded: "db".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 19)),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_link_query() {
+        let mut reader = Reader::new("link \"next\"");
+        assert_eq!(
+            link_query(&mut reader).unwrap(),
+            QueryValue::Link {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+                rel: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "next".to_string(),
+                        encoded: "next".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 12)),
+                },
+            }
+        );
+    }
+
     #[test]
     fn test_cookie_query() {
         let mut reader = Reader::new("cookie \"Foo[Domain]\"");
@@ -383,6 +570,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_jsonpointer_query() {
+        let mut reader = Reader::new("jsonpointer \"/book/0/author\"");
+        assert_eq!(
+            jsonpointer_query(&mut reader).unwrap(),
+            QueryValue::Jsonpointer {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                },
+                expr: Template {
+                    elements: vec![TemplateElement::String {
+                        value: "/book/0/author".to_string(),
+                        encoded: "/book/0/author".to_string(),
+                    }],
+                    delimiter: Some('"'),
+                    source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 29)),
+                },
+            },
+        );
+
+        let mut reader = Reader::new("jsonpointer \"/book");
+        let error = jsonpointer_query(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_timings_query() {
+        let mut reader = Reader::new("timings \"starttransfer\"");
+        assert_eq!(
+            timings_query(&mut reader).unwrap(),
+            QueryValue::Timings {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                attribute_name: TimingsAttributeName::StartTransfer,
+            }
+        );
+
+        let mut reader = Reader::new("timings \"dns\"");
+        let error = timings_query(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 10));
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_custom_query() {
+        let mut reader = Reader::new("@protobuf \"user.name\" == \"Bob\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 22)),
+                value: QueryValue::Custom {
+                    name: "protobuf".to_string(),
+                    args: vec![(
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                        },
+                        Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "user.name".to_string(),
+                                encoded: "user.name".to_string(),
+                            }],
+                            source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 22)),
+                        }
+                    )],
+                },
+            }
+        );
+
+        let mut reader = Reader::new("protobuf");
+        let error = custom_query(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 1));
+        assert!(error.recoverable);
+
+        // A custom query name is mandatory after `@`.
+        let mut reader = Reader::new("@ \"user.name\"");
+        let error = custom_query(&mut reader).err().unwrap();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "query name".to_string()
+            }
+        );
+        assert_eq!(error.pos, Pos::new(1, 2));
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_query_with_filters() {
         let mut reader = Reader::new("body urlDecode ");
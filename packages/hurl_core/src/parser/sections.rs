@@ -309,7 +309,7 @@ fn capture(reader: &mut Reader) -> ParseResult<Capture> {
     })
 }
 
-fn assert(reader: &mut Reader) -> ParseResult<Assert> {
+pub fn assert(reader: &mut Reader) -> ParseResult<Assert> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
     let query0 = query(reader)?;
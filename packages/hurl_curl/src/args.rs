@@ -113,8 +113,8 @@ impl Parser {
 
 #[cfg(test)]
 mod test {
-    use crate::curl::args;
-    use crate::curl::args::Parser;
+    use crate::args;
+    use crate::args::Parser;
 
     #[test]
     fn test_split() {
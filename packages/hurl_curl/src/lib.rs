@@ -139,7 +139,7 @@ fn additional_asserts(options: &[HurlOption]) -> Vec<String> {
 
 #[cfg(test)]
 mod test {
-    use crate::curl::*;
+    use crate::*;
 
     #[test]
     fn test_parse() {
@@ -43,6 +43,14 @@ pub fn color() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn diff() -> clap::Arg {
+    clap::Arg::new("diff")
+        .long("diff")
+        .help("Print a diff of the changes in check mode")
+        .conflicts_with("output")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn in_place() -> clap::Arg {
     clap::Arg::new("in_place")
         .long("in-place")
@@ -56,6 +56,20 @@ pub fn output_format(arg_matches: &ArgMatches) -> Result<OutputFormat, OptionsEr
     }
 }
 
+pub fn diff(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
+    if has_flag(arg_matches, "diff") {
+        if !has_flag(arg_matches, "check") {
+            Err(OptionsError::Error(
+                "You can use --diff only with --check!".to_string(),
+            ))
+        } else {
+            Ok(true)
+        }
+    } else {
+        Ok(false)
+    }
+}
+
 pub fn in_place(arg_matches: &ArgMatches) -> Result<bool, OptionsError> {
     if has_flag(arg_matches, "in_place") {
         if get_string(arg_matches, "input_format") != Some("hurl".to_string()) {
@@ -29,6 +29,7 @@ use hurl_core::input::Input;
 pub struct Options {
     pub check: bool,
     pub color: bool,
+    pub diff: bool,
     pub in_place: bool,
     pub input_files: Vec<Input>,
     pub input_format: InputFormat,
@@ -73,6 +74,7 @@ pub fn parse() -> Result<Options, OptionsError> {
         .about("Format Hurl files")
         .arg(commands::check())
         .arg(commands::color())
+        .arg(commands::diff())
         .arg(commands::in_place())
         .arg(commands::input_files())
         .arg(commands::input_format())
@@ -94,6 +96,7 @@ pub fn parse() -> Result<Options, OptionsError> {
 fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let check = matches::check(arg_matches);
     let color = matches::color(arg_matches);
+    let diff = matches::diff(arg_matches)?;
     let in_place = matches::in_place(arg_matches)?;
     let input_files = matches::input_files(arg_matches)?;
     let input_format = matches::input_format(arg_matches)?;
@@ -103,6 +106,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     Ok(Options {
         check,
         color,
+        diff,
         in_place,
         input_files,
         input_format,
@@ -0,0 +1,56 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use similar::TextDiff;
+
+/// Returns a unified diff between the `old` and `new` content of the file `filename`, with
+/// `--- a/filename` and `+++ b/filename` headers. An empty string is returned if there is no
+/// change.
+pub fn format(old: &str, new: &str, filename: &str) -> String {
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .missing_newline_hint(false)
+        .header(&format!("a/{filename}"), &format!("b/{filename}"))
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_diff() {
+        let content = "GET http://localhost:8000/hello\nHTTP 200\n";
+        assert_eq!(format(content, content, "hello.hurl"), "");
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = "# Comment\nGET   http://localhost:8000/hello\nHTTP 200\n";
+        let new = "# Comment\nGET http://localhost:8000/hello\nHTTP 200\n";
+        assert_eq!(
+            format(old, new, "hello.hurl"),
+            "--- a/hello.hurl\n\
+             +++ b/hello.hurl\n\
+             @@ -1,3 +1,3 @@\n \
+             # Comment\n\
+             -GET   http://localhost:8000/hello\n\
+             +GET http://localhost:8000/hello\n \
+             HTTP 200\n"
+        );
+    }
+}
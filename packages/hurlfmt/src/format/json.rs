@@ -23,9 +23,10 @@ use hurl_core::ast::{
     CountOption, DurationOption, Entry, EntryOption, File, FileParam, Filter, FilterValue, Header,
     Hex, HurlFile, JsonListElement, JsonValue, KeyValue, MultilineString, MultilineStringKind,
     MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate, PredicateFuncValue,
-    PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response, StatusValue,
-    VersionValue,
+    PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response, SourceInfo,
+    StatusValue, VersionValue,
 };
+use hurl_core::reader::Pos;
 use hurl_core::typing::{Count, Duration};
 
 pub fn format(hurl_file: &HurlFile) -> String {
@@ -51,6 +52,12 @@ impl ToJson for Entry {
         if let Some(response) = &self.response {
             attributes.push(("response".to_string(), response.to_json()));
         }
+        let end = match &self.response {
+            Some(response) => response.source_info.end,
+            None => self.request.source_info.end,
+        };
+        let source_info = SourceInfo::new(self.request.source_info.start, end);
+        attributes.push(("source_info".to_string(), source_info.to_json()));
         JValue::Object(attributes)
     }
 }
@@ -108,6 +115,7 @@ impl ToJson for Request {
             let comments = comments.iter().map(|c| c.to_json()).collect();
             attributes.push(("comments".to_string(), JValue::List(comments)));
         }
+        attributes.push(("source_info".to_string(), self.source_info.to_json()));
 
         JValue::Object(attributes)
     }
@@ -135,6 +143,7 @@ impl ToJson for Response {
         if let Some(body) = &self.body {
             attributes.push(("body".to_string(), body.to_json()));
         }
+        attributes.push(("source_info".to_string(), self.source_info.to_json()));
         JValue::Object(attributes)
     }
 }
@@ -146,6 +155,28 @@ fn add_headers(attributes: &mut Vec<(String, JValue)>, headers: &[Header]) {
     }
 }
 
+impl ToJson for SourceInfo {
+    /// Transforms this source info to a JSON object, with start and end positions.
+    fn to_json(&self) -> JValue {
+        JValue::Object(vec![
+            ("start".to_string(), self.start.to_json()),
+            ("end".to_string(), self.end.to_json()),
+        ])
+    }
+}
+
+impl ToJson for Pos {
+    fn to_json(&self) -> JValue {
+        JValue::Object(vec![
+            ("line".to_string(), JValue::Number(self.line.to_string())),
+            (
+                "column".to_string(),
+                JValue::Number(self.column.to_string()),
+            ),
+        ])
+    }
+}
+
 impl ToJson for Body {
     fn to_json(&self) -> JValue {
         self.value.to_json()
@@ -308,10 +339,13 @@ impl ToJson for EntryOption {
             OptionKind::CaCertificate(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientCert(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientKey(filename) => JValue::String(filename.to_string()),
+            OptionKind::CompressRequest(value) => JValue::String(value.to_string()),
             OptionKind::Compressed(value) => value.to_json(),
             OptionKind::ConnectTo(value) => JValue::String(value.to_string()),
             OptionKind::ConnectTimeout(value) => value.to_json(),
             OptionKind::Delay(value) => value.to_json(),
+            OptionKind::FollowLink(value) => JValue::String(value.to_string()),
+            OptionKind::FollowLinkMax(value) => value.to_json(),
             OptionKind::FollowLocation(value) => value.to_json(),
             OptionKind::FollowLocationTrusted(value) => value.to_json(),
             OptionKind::Header(value) => JValue::String(value.to_string()),
@@ -324,17 +358,23 @@ impl ToJson for EntryOption {
             OptionKind::IpV6(value) => value.to_json(),
             OptionKind::LimitRate(value) => value.to_json(),
             OptionKind::MaxRedirect(value) => value.to_json(),
+            OptionKind::MaxTime(value) => value.to_json(),
+            OptionKind::Name(value) => JValue::String(value.to_string()),
             OptionKind::NetRc(value) => value.to_json(),
             OptionKind::NetRcFile(filename) => JValue::String(filename.to_string()),
             OptionKind::NetRcOptional(value) => value.to_json(),
+            OptionKind::NoProxy(value) => JValue::String(value.to_string()),
             OptionKind::Output(filename) => JValue::String(filename.to_string()),
             OptionKind::PathAsIs(value) => value.to_json(),
             OptionKind::Proxy(value) => JValue::String(value.to_string()),
             OptionKind::Repeat(value) => value.to_json(),
+            OptionKind::RequestCharset(value) => JValue::String(value.to_string()),
             OptionKind::Resolve(value) => JValue::String(value.to_string()),
+            OptionKind::ResponseCharset(value) => JValue::String(value.to_string()),
             OptionKind::Retry(value) => value.to_json(),
             OptionKind::RetryInterval(value) => value.to_json(),
             OptionKind::Skip(value) => value.to_json(),
+            OptionKind::StripNewlines(value) => value.to_json(),
             OptionKind::UnixSocket(value) => JValue::String(value.to_string()),
             OptionKind::User(value) => JValue::String(value.to_string()),
             OptionKind::Variable(value) => {
@@ -342,6 +382,9 @@ impl ToJson for EntryOption {
             }
             OptionKind::Verbose(value) => value.to_json(),
             OptionKind::VeryVerbose(value) => value.to_json(),
+            OptionKind::XPathNamespace(value) => {
+                JValue::String(format!("{}={}", value.name, value.value))
+            }
         };
 
         // If the value contains the unit such as `{ "value": 10, "unit": "second" }`
@@ -460,6 +503,9 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Url => {
             attributes.push(("type".to_string(), JValue::String("url".to_string())));
         }
+        QueryValue::Redirects => {
+            attributes.push(("type".to_string(), JValue::String("redirects".to_string())));
+        }
         QueryValue::Body => {
             attributes.push(("type".to_string(), JValue::String("body".to_string())));
         }
@@ -467,10 +513,32 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
             attributes.push(("type".to_string(), JValue::String("jsonpath".to_string())));
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
         }
+        QueryValue::Jsonpointer { expr, .. } => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("jsonpointer".to_string()),
+            ));
+            attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+        }
         QueryValue::Header { name, .. } => {
             attributes.push(("type".to_string(), JValue::String("header".to_string())));
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
         }
+        QueryValue::Trailer { name, .. } => {
+            attributes.push(("type".to_string(), JValue::String("trailer".to_string())));
+            attributes.push(("name".to_string(), JValue::String(name.to_string())));
+        }
+        QueryValue::ServerTiming { name, .. } => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("server-timing".to_string()),
+            ));
+            attributes.push(("name".to_string(), JValue::String(name.to_string())));
+        }
+        QueryValue::Link { rel, .. } => {
+            attributes.push(("type".to_string(), JValue::String("link".to_string())));
+            attributes.push(("rel".to_string(), JValue::String(rel.to_string())));
+        }
         QueryValue::Cookie { expr, .. } => {
             attributes.push(("type".to_string(), JValue::String("cookie".to_string())));
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
@@ -493,6 +561,9 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Bytes => {
             attributes.push(("type".to_string(), JValue::String("bytes".to_string())));
         }
+        QueryValue::RawBytes => {
+            attributes.push(("type".to_string(), JValue::String("rawBytes".to_string())));
+        }
         QueryValue::Sha256 => {
             attributes.push(("type".to_string(), JValue::String("sha256".to_string())));
         }
@@ -509,6 +580,24 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
             ));
             attributes.push(("expr".to_string(), field.to_json()));
         }
+        QueryValue::Timings {
+            attribute_name: field,
+            ..
+        } => {
+            attributes.push(("type".to_string(), JValue::String("timings".to_string())));
+            attributes.push((
+                "expr".to_string(),
+                JValue::String(field.value().to_string()),
+            ));
+        }
+        QueryValue::Custom { name, args } => {
+            attributes.push(("type".to_string(), JValue::String(format!("@{name}"))));
+            let args = args
+                .iter()
+                .map(|(_, arg)| JValue::String(arg.to_string()))
+                .collect();
+            attributes.push(("args".to_string(), JValue::List(args)));
+        }
     };
     attributes
 }
@@ -594,6 +683,25 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("contain".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::ContainTimes { value, count, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("contain-times".to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+                attributes.push(("count".to_string(), JValue::Number(count.to_string())));
+            }
+            PredicateFuncValue::NearlyEqual { value, epsilon, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("nearly-equal".to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+                if let Some(epsilon) = epsilon {
+                    let (epsilon, _) = json_predicate_value(epsilon.value);
+                    attributes.push(("epsilon".to_string(), epsilon));
+                }
+            }
             PredicateFuncValue::Include { value, .. } => {
                 attributes.push(("type".to_string(), JValue::String("include".to_string())));
                 add_predicate_value(&mut attributes, value);
@@ -623,6 +731,10 @@ impl ToJson for Predicate {
             PredicateFuncValue::IsDate => {
                 attributes.push(("type".to_string(), JValue::String("isDate".to_string())));
             }
+            PredicateFuncValue::IsDateFormat { fmt, .. } => {
+                attributes.push(("type".to_string(), JValue::String("isDate".to_string())));
+                attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
+            }
             PredicateFuncValue::IsIsoDate => {
                 attributes.push(("type".to_string(), JValue::String("isIsoDate".to_string())));
             }
@@ -635,6 +747,21 @@ impl ToJson for Predicate {
             PredicateFuncValue::IsNumber => {
                 attributes.push(("type".to_string(), JValue::String("isNumber".to_string())));
             }
+            PredicateFuncValue::IsUuid => {
+                attributes.push(("type".to_string(), JValue::String("isUuid".to_string())));
+            }
+            PredicateFuncValue::IsEmail => {
+                attributes.push(("type".to_string(), JValue::String("isEmail".to_string())));
+            }
+            PredicateFuncValue::IsUrl => {
+                attributes.push(("type".to_string(), JValue::String("isUrl".to_string())));
+            }
+            PredicateFuncValue::IsIpv4 => {
+                attributes.push(("type".to_string(), JValue::String("isIpv4".to_string())));
+            }
+            PredicateFuncValue::IsIpv6 => {
+                attributes.push(("type".to_string(), JValue::String("isIpv6".to_string())));
+            }
         }
         JValue::Object(attributes)
     }
@@ -709,15 +836,35 @@ impl ToJson for FilterValue {
         let mut attributes = vec![];
         let att_name = "type".to_string();
         match self {
+            FilterValue::Base32Decode => {
+                attributes.push((att_name, JValue::String("base32Decode".to_string())));
+            }
+            FilterValue::Base32Encode => {
+                attributes.push((att_name, JValue::String("base32Encode".to_string())));
+            }
             FilterValue::Base64Decode => {
                 attributes.push((att_name, JValue::String("base64Decode".to_string())));
             }
             FilterValue::Base64Encode => {
                 attributes.push((att_name, JValue::String("base64Encode".to_string())));
             }
+            FilterValue::Base64UrlSafeDecode => {
+                attributes.push((att_name, JValue::String("base64UrlSafeDecode".to_string())));
+            }
+            FilterValue::Base64UrlSafeEncode => {
+                attributes.push((att_name, JValue::String("base64UrlSafeEncode".to_string())));
+            }
             FilterValue::Count => {
                 attributes.push((att_name, JValue::String("count".to_string())));
             }
+            FilterValue::Custom { name, args } => {
+                attributes.push((att_name, JValue::String(format!("@{name}"))));
+                let args = args
+                    .iter()
+                    .map(|(_, arg)| JValue::String(arg.to_string()))
+                    .collect();
+                attributes.push(("args".to_string(), JValue::List(args)));
+            }
             FilterValue::DaysAfterNow => {
                 attributes.push((att_name, JValue::String("daysAfterNow".to_string())));
             }
@@ -728,24 +875,61 @@ impl ToJson for FilterValue {
                 attributes.push((att_name, JValue::String("decode".to_string())));
                 attributes.push(("encoding".to_string(), JValue::String(encoding.to_string())));
             }
+            FilterValue::DecodeAuto => {
+                attributes.push((att_name, JValue::String("decode".to_string())));
+            }
+            FilterValue::Decompress => {
+                attributes.push((att_name, JValue::String("decompress".to_string())));
+            }
             FilterValue::Format { fmt, .. } => {
                 attributes.push((att_name, JValue::String("format".to_string())));
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
+            FilterValue::JsonEscape => {
+                attributes.push((att_name, JValue::String("jsonEscape".to_string())));
+            }
+            FilterValue::Jsonl => {
+                attributes.push((att_name, JValue::String("jsonl".to_string())));
+            }
             FilterValue::JsonPath { expr, .. } => {
                 attributes.push((att_name, JValue::String("jsonpath".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
+            FilterValue::JsonPointer { expr, .. } => {
+                attributes.push((att_name, JValue::String("jsonpointer".to_string())));
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::JsonUnescape => {
+                attributes.push((att_name, JValue::String("jsonUnescape".to_string())));
+            }
             FilterValue::Nth { n, .. } => {
                 attributes.push((att_name, JValue::String("nth".to_string())));
                 attributes.push(("n".to_string(), JValue::Number(n.to_string())));
             }
+            FilterValue::HexDecode => {
+                attributes.push((att_name, JValue::String("hexDecode".to_string())));
+            }
+            FilterValue::Md5 => {
+                attributes.push((att_name, JValue::String("md5".to_string())));
+            }
+            FilterValue::Sha1 => {
+                attributes.push((att_name, JValue::String("sha1".to_string())));
+            }
+            FilterValue::Sha256 => {
+                attributes.push((att_name, JValue::String("sha256".to_string())));
+            }
+            FilterValue::HexEncode => {
+                attributes.push((att_name, JValue::String("hexEncode".to_string())));
+            }
             FilterValue::HtmlEscape => {
                 attributes.push((att_name, JValue::String("htmlEscape".to_string())));
             }
             FilterValue::HtmlUnescape => {
                 attributes.push((att_name, JValue::String("htmlUnescape".to_string())));
             }
+            FilterValue::PathEncode => {
+                attributes.push((att_name, JValue::String("pathEncode".to_string())));
+            }
             FilterValue::Regex { value, .. } => {
                 attributes.push((att_name, JValue::String("regex".to_string())));
                 attributes.push(("expr".to_string(), value.to_json()));
@@ -765,6 +949,9 @@ impl ToJson for FilterValue {
             FilterValue::UrlEncode => {
                 attributes.push((att_name, JValue::String("urlEncode".to_string())));
             }
+            FilterValue::ToString => {
+                attributes.push((att_name, JValue::String("toString".to_string())));
+            }
             FilterValue::UrlDecode => {
                 attributes.push((att_name, JValue::String("urlDecode".to_string())));
             }
@@ -782,6 +969,9 @@ impl ToJson for FilterValue {
             FilterValue::ToInt => {
                 attributes.push((att_name, JValue::String("toInt".to_string())));
             }
+            FilterValue::XmlEscape => {
+                attributes.push((att_name, JValue::String("xmlEscape".to_string())));
+            }
             FilterValue::XPath { expr, .. } => {
                 attributes.push((att_name, JValue::String("xpath".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
@@ -878,7 +1068,7 @@ pub mod tests {
                 }],
                 sections: vec![],
                 body: None,
-                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(3, 1)),
             }
             .to_json(),
             JValue::Object(vec![
@@ -893,6 +1083,25 @@ pub mod tests {
                         ("name".to_string(), JValue::String("Foo".to_string())),
                         ("value".to_string(), JValue::String("Bar".to_string()))
                     ])])
+                ),
+                (
+                    "source_info".to_string(),
+                    JValue::Object(vec![
+                        (
+                            "start".to_string(),
+                            JValue::Object(vec![
+                                ("line".to_string(), JValue::Number("1".to_string())),
+                                ("column".to_string(), JValue::Number("1".to_string()))
+                            ])
+                        ),
+                        (
+                            "end".to_string(),
+                            JValue::Object(vec![
+                                ("line".to_string(), JValue::Number("3".to_string())),
+                                ("column".to_string(), JValue::Number("1".to_string()))
+                            ])
+                        )
+                    ])
                 )
             ])
         );
@@ -925,7 +1134,26 @@ pub mod tests {
                     "version".to_string(),
                     JValue::String("HTTP/1.1".to_string())
                 ),
-                ("status".to_string(), JValue::Number("200".to_string()))
+                ("status".to_string(), JValue::Number("200".to_string())),
+                (
+                    "source_info".to_string(),
+                    JValue::Object(vec![
+                        (
+                            "start".to_string(),
+                            JValue::Object(vec![
+                                ("line".to_string(), JValue::Number("0".to_string())),
+                                ("column".to_string(), JValue::Number("0".to_string()))
+                            ])
+                        ),
+                        (
+                            "end".to_string(),
+                            JValue::Object(vec![
+                                ("line".to_string(), JValue::Number("0".to_string())),
+                                ("column".to_string(), JValue::Number("0".to_string()))
+                            ])
+                        )
+                    ])
+                )
             ])
         );
         assert_eq!(
@@ -948,7 +1176,25 @@ pub mod tests {
                 source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
             }
             .to_json(),
-            JValue::Object(vec![])
+            JValue::Object(vec![(
+                "source_info".to_string(),
+                JValue::Object(vec![
+                    (
+                        "start".to_string(),
+                        JValue::Object(vec![
+                            ("line".to_string(), JValue::Number("0".to_string())),
+                            ("column".to_string(), JValue::Number("0".to_string()))
+                        ])
+                    ),
+                    (
+                        "end".to_string(),
+                        JValue::Object(vec![
+                            ("line".to_string(), JValue::Number("0".to_string())),
+                            ("column".to_string(), JValue::Number("0".to_string()))
+                        ])
+                    )
+                ])
+            )])
         );
     }
 
@@ -15,10 +15,12 @@
  * limitations under the License.
  *
  */
+pub use self::diff::format as format_diff;
 pub use self::json::format as format_json;
 pub use self::text::format as format_text;
 pub use self::token::{Token, Tokenizable};
 
+mod diff;
 mod json;
 mod serialize_json;
 mod text;
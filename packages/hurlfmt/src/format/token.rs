@@ -421,11 +421,27 @@ impl Tokenizable for QueryValue {
         match self.clone() {
             QueryValue::Status => tokens.push(Token::QueryType(String::from("status"))),
             QueryValue::Url => tokens.push(Token::QueryType(String::from("url"))),
+            QueryValue::Redirects => tokens.push(Token::QueryType(String::from("redirects"))),
             QueryValue::Header { space0, name } => {
                 tokens.push(Token::QueryType(String::from("header")));
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut name.tokenize());
             }
+            QueryValue::Trailer { space0, name } => {
+                tokens.push(Token::QueryType(String::from("trailer")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+            }
+            QueryValue::ServerTiming { space0, name } => {
+                tokens.push(Token::QueryType(String::from("server-timing")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+            }
+            QueryValue::Link { space0, rel } => {
+                tokens.push(Token::QueryType(String::from("link")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut rel.tokenize());
+            }
             QueryValue::Cookie { space0, expr } => {
                 tokens.push(Token::QueryType(String::from("cookie")));
                 tokens.append(&mut space0.tokenize());
@@ -444,6 +460,11 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut expr.tokenize());
             }
+            QueryValue::Jsonpointer { space0, expr } => {
+                tokens.push(Token::QueryType(String::from("jsonpointer")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+            }
             QueryValue::Regex { space0, value } => {
                 tokens.push(Token::QueryType(String::from("regex")));
                 tokens.append(&mut space0.tokenize());
@@ -456,6 +477,7 @@ impl Tokenizable for QueryValue {
             }
             QueryValue::Duration => tokens.push(Token::QueryType(String::from("duration"))),
             QueryValue::Bytes => tokens.push(Token::QueryType(String::from("bytes"))),
+            QueryValue::RawBytes => tokens.push(Token::QueryType(String::from("rawBytes"))),
             QueryValue::Sha256 => tokens.push(Token::QueryType(String::from("sha256"))),
             QueryValue::Md5 => tokens.push(Token::QueryType(String::from("md5"))),
             QueryValue::Certificate {
@@ -466,6 +488,23 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut field.tokenize());
             }
+            QueryValue::Timings {
+                space0,
+                attribute_name: field,
+            } => {
+                tokens.push(Token::QueryType(String::from("timings")));
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::StringDelimiter("\"".to_string()));
+                tokens.push(Token::String(field.value().to_string()));
+                tokens.push(Token::StringDelimiter("\"".to_string()));
+            }
+            QueryValue::Custom { name, args } => {
+                tokens.push(Token::QueryType(format!("@{name}")));
+                for (space, arg) in args {
+                    tokens.append(&mut space.tokenize());
+                    tokens.append(&mut arg.tokenize());
+                }
+            }
         }
         tokens
     }
@@ -587,6 +626,33 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::ContainTimes {
+                space0,
+                value,
+                space1,
+                count,
+            } => {
+                tokens.push(Token::PredicateType(name));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+                tokens.append(&mut space1.tokenize());
+                tokens.push(Token::Number(count.to_string()));
+            }
+            PredicateFuncValue::NearlyEqual {
+                space0,
+                value,
+                epsilon,
+            } => {
+                tokens.push(Token::PredicateType(name));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+                if let Some(epsilon) = epsilon {
+                    tokens.append(&mut epsilon.space0.tokenize());
+                    tokens.push(Token::PredicateType("epsilon".to_string()));
+                    tokens.append(&mut epsilon.space1.tokenize());
+                    tokens.append(&mut epsilon.value.tokenize());
+                }
+            }
             PredicateFuncValue::Include { space0, value } => {
                 tokens.push(Token::PredicateType(name));
                 tokens.append(&mut space0.tokenize());
@@ -615,6 +681,11 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsDate => {
                 tokens.push(Token::PredicateType(name));
             }
+            PredicateFuncValue::IsDateFormat { space0, fmt } => {
+                tokens.push(Token::PredicateType(name));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut fmt.tokenize());
+            }
             PredicateFuncValue::IsIsoDate => {
                 tokens.push(Token::PredicateType(name));
             }
@@ -627,6 +698,21 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsNumber => {
                 tokens.push(Token::PredicateType(name));
             }
+            PredicateFuncValue::IsUuid => {
+                tokens.push(Token::PredicateType(name));
+            }
+            PredicateFuncValue::IsEmail => {
+                tokens.push(Token::PredicateType(name));
+            }
+            PredicateFuncValue::IsUrl => {
+                tokens.push(Token::PredicateType(name));
+            }
+            PredicateFuncValue::IsIpv4 => {
+                tokens.push(Token::PredicateType(name));
+            }
+            PredicateFuncValue::IsIpv6 => {
+                tokens.push(Token::PredicateType(name));
+            }
         }
         tokens
     }
@@ -686,6 +772,7 @@ impl Tokenizable for MultilineStringAttribute {
     fn tokenize(&self) -> Vec<Token> {
         match self {
             MultilineStringAttribute::Escape => vec![Token::String("escape".to_string())],
+            MultilineStringAttribute::NoNewline => vec![Token::String("nonewline".to_string())],
             MultilineStringAttribute::NoVariable => vec![Token::String("novariable".to_string())],
         }
     }
@@ -951,10 +1038,13 @@ impl Tokenizable for OptionKind {
             OptionKind::CaCertificate(filename) => filename.tokenize(),
             OptionKind::ClientCert(filename) => filename.tokenize(),
             OptionKind::ClientKey(filename) => filename.tokenize(),
+            OptionKind::CompressRequest(value) => value.tokenize(),
             OptionKind::Compressed(value) => value.tokenize(),
             OptionKind::ConnectTo(value) => value.tokenize(),
             OptionKind::ConnectTimeout(value) => value.tokenize(),
             OptionKind::Delay(value) => value.tokenize(),
+            OptionKind::FollowLink(value) => value.tokenize(),
+            OptionKind::FollowLinkMax(value) => value.tokenize(),
             OptionKind::FollowLocation(value) => value.tokenize(),
             OptionKind::FollowLocationTrusted(value) => value.tokenize(),
             OptionKind::Header(value) => value.tokenize(),
@@ -967,22 +1057,29 @@ impl Tokenizable for OptionKind {
             OptionKind::IpV6(value) => value.tokenize(),
             OptionKind::LimitRate(value) => value.tokenize(),
             OptionKind::MaxRedirect(value) => value.tokenize(),
+            OptionKind::MaxTime(value) => value.tokenize(),
+            OptionKind::Name(value) => value.tokenize(),
             OptionKind::NetRc(value) => value.tokenize(),
             OptionKind::NetRcFile(filename) => filename.tokenize(),
             OptionKind::NetRcOptional(value) => value.tokenize(),
+            OptionKind::NoProxy(value) => value.tokenize(),
             OptionKind::Output(filename) => filename.tokenize(),
             OptionKind::PathAsIs(value) => value.tokenize(),
             OptionKind::Proxy(value) => value.tokenize(),
             OptionKind::Repeat(value) => value.tokenize(),
+            OptionKind::RequestCharset(value) => value.tokenize(),
             OptionKind::Resolve(value) => value.tokenize(),
+            OptionKind::ResponseCharset(value) => value.tokenize(),
             OptionKind::Retry(value) => value.tokenize(),
             OptionKind::RetryInterval(value) => value.tokenize(),
             OptionKind::Skip(value) => value.tokenize(),
+            OptionKind::StripNewlines(value) => value.tokenize(),
             OptionKind::UnixSocket(value) => value.tokenize(),
             OptionKind::User(value) => value.tokenize(),
             OptionKind::Variable(value) => value.tokenize(),
             OptionKind::Verbose(value) => value.tokenize(),
             OptionKind::VeryVerbose(value) => value.tokenize(),
+            OptionKind::XPathNamespace(value) => value.tokenize(),
         }
     }
 }
@@ -1079,9 +1176,25 @@ impl Tokenizable for VariableValue {
 impl Tokenizable for Filter {
     fn tokenize(&self) -> Vec<Token> {
         match self.value.clone() {
+            FilterValue::Base32Decode => vec![Token::FilterType(String::from("base32Decode"))],
+            FilterValue::Base32Encode => vec![Token::FilterType(String::from("base32Encode"))],
             FilterValue::Base64Decode => vec![Token::FilterType(String::from("base64Decode"))],
             FilterValue::Base64Encode => vec![Token::FilterType(String::from("base64Encode"))],
+            FilterValue::Base64UrlSafeDecode => {
+                vec![Token::FilterType(String::from("base64UrlSafeDecode"))]
+            }
+            FilterValue::Base64UrlSafeEncode => {
+                vec![Token::FilterType(String::from("base64UrlSafeEncode"))]
+            }
             FilterValue::Count => vec![Token::FilterType(String::from("count"))],
+            FilterValue::Custom { name, args } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(format!("@{name}"))];
+                for (space, arg) in args {
+                    tokens.append(&mut space.tokenize());
+                    tokens.append(&mut arg.tokenize());
+                }
+                tokens
+            }
             FilterValue::DaysAfterNow => vec![Token::FilterType(String::from("daysAfterNow"))],
             FilterValue::DaysBeforeNow => vec![Token::FilterType(String::from("daysBeforeNow"))],
             FilterValue::Decode { space0, encoding } => {
@@ -1090,28 +1203,45 @@ impl Tokenizable for Filter {
                 tokens.append(&mut encoding.tokenize());
                 tokens
             }
+            FilterValue::DecodeAuto => vec![Token::FilterType(String::from("decode"))],
+            FilterValue::Decompress => vec![Token::FilterType(String::from("decompress"))],
             FilterValue::Format { space0, fmt } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("format"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut fmt.tokenize());
                 tokens
             }
+            FilterValue::HexDecode => vec![Token::FilterType(String::from("hexDecode"))],
+            FilterValue::HexEncode => vec![Token::FilterType(String::from("hexEncode"))],
             FilterValue::HtmlEscape => vec![Token::FilterType(String::from("htmlEscape"))],
             FilterValue::HtmlUnescape => {
                 vec![Token::FilterType(String::from("htmlUnescape"))]
             }
+            FilterValue::JsonEscape => vec![Token::FilterType(String::from("jsonEscape"))],
+            FilterValue::Md5 => vec![Token::FilterType(String::from("md5"))],
+            FilterValue::Sha1 => vec![Token::FilterType(String::from("sha1"))],
+            FilterValue::Sha256 => vec![Token::FilterType(String::from("sha256"))],
+            FilterValue::Jsonl => vec![Token::FilterType(String::from("jsonl"))],
             FilterValue::JsonPath { space0, expr } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("jsonpath"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut expr.tokenize());
                 tokens
             }
+            FilterValue::JsonPointer { space0, expr } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("jsonpointer"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+                tokens
+            }
+            FilterValue::JsonUnescape => vec![Token::FilterType(String::from("jsonUnescape"))],
             FilterValue::Nth { space0, n } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("nth"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.push(Token::Number(n.to_string()));
                 tokens
             }
+            FilterValue::PathEncode => vec![Token::FilterType(String::from("pathEncode"))],
             FilterValue::Regex { space0, value } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("regex"))];
                 tokens.append(&mut space0.tokenize());
@@ -1132,6 +1262,7 @@ impl Tokenizable for Filter {
                 tokens
             }
             FilterValue::UrlEncode => vec![Token::FilterType(String::from("urlEncode"))],
+            FilterValue::ToString => vec![Token::FilterType(String::from("toString"))],
             FilterValue::UrlDecode => vec![Token::FilterType(String::from("urlDecode"))],
             FilterValue::Split { space0, sep } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("split"))];
@@ -1147,6 +1278,7 @@ impl Tokenizable for Filter {
             }
             FilterValue::ToFloat => vec![Token::FilterType(String::from("toFloat"))],
             FilterValue::ToInt => vec![Token::FilterType(String::from("toInt"))],
+            FilterValue::XmlEscape => vec![Token::FilterType(String::from("xmlEscape"))],
             FilterValue::XPath { space0, expr } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("xpath"))];
                 tokens.append(&mut space0.tokenize());
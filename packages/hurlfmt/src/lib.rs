@@ -16,6 +16,5 @@
  *
  */
 pub mod cli;
-pub mod curl;
 pub mod format;
 pub mod linter;
@@ -20,17 +20,20 @@ use hurl_core::error;
 use hurl_core::error::DisplaySourceError;
 use hurl_core::text::{Style, StyledString};
 
+use crate::linter::ValueKind;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LinterError {
     pub source_info: SourceInfo,
     pub kind: LinterErrorKind,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum LinterErrorKind {
     UnnecessarySpace,
     UnnecessaryJsonEncoding,
     OneSpace,
+    InvalidFilterInput { filter: String, actual: ValueKind },
 }
 
 ///
@@ -42,18 +45,25 @@ impl DisplaySourceError for LinterError {
     }
 
     fn description(&self) -> String {
-        match self.kind {
+        match &self.kind {
             LinterErrorKind::UnnecessarySpace => "Unnecessary space".to_string(),
             LinterErrorKind::UnnecessaryJsonEncoding => "Unnecessary json encoding".to_string(),
             LinterErrorKind::OneSpace => "One space".to_string(),
+            LinterErrorKind::InvalidFilterInput { .. } => "Filter error".to_string(),
         }
     }
 
     fn fixme(&self, content: &[&str]) -> StyledString {
-        let message = match self.kind {
+        let message = match &self.kind {
             LinterErrorKind::UnnecessarySpace => "Remove space".to_string(),
             LinterErrorKind::UnnecessaryJsonEncoding => "Use Simple String".to_string(),
             LinterErrorKind::OneSpace => "Use only one space".to_string(),
+            LinterErrorKind::InvalidFilterInput { filter, actual } => {
+                format!(
+                    "invalid filter input: {filter} can not be applied to {}",
+                    actual.name()
+                )
+            }
         };
         let mut s = StyledString::new();
         let message = error::add_carets(&message, self.source_info(), content);
@@ -0,0 +1,196 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Static checks of filters chains.
+use hurl_core::ast::{
+    CertificateAttributeName, Filter, FilterValue, Query, QueryValue, Whitespace,
+};
+
+use crate::linter::{LinterError, LinterErrorKind};
+
+/// Kind of the value returned by a query or a filter, when it can be known without running the file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValueKind {
+    Bool,
+    Bytes,
+    Date,
+    Float,
+    Integer,
+    List,
+    Nodeset,
+    String,
+}
+
+impl ValueKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ValueKind::Bool => "boolean",
+            ValueKind::Bytes => "bytes",
+            ValueKind::Date => "date",
+            ValueKind::Float => "float",
+            ValueKind::Integer => "integer",
+            ValueKind::List => "list",
+            ValueKind::Nodeset => "nodeset",
+            ValueKind::String => "string",
+        }
+    }
+}
+
+/// Checks that each filter of `filters` can be applied to the value returned by `query` and the
+/// previous filters.
+///
+/// Only the chains whose values kinds are statically known are checked: as soon as a query or a
+/// filter returns a value that depends on the HTTP response (like `jsonpath`), the rest of the
+/// chain is accepted.
+pub fn check_filters(query: &Query, filters: &[(Whitespace, Filter)]) -> Option<LinterError> {
+    let mut kind = query_kind(&query.value);
+    for (_, filter) in filters {
+        let (inputs, output) = filter_signature(&filter.value);
+        if let Some(actual) = kind {
+            if !inputs.contains(&actual) {
+                return Some(LinterError {
+                    source_info: filter.source_info,
+                    kind: LinterErrorKind::InvalidFilterInput {
+                        filter: filter.value.name().to_string(),
+                        actual,
+                    },
+                });
+            }
+        }
+        kind = output;
+    }
+    None
+}
+
+/// Returns the kind of the value returned by a query, or `None` if it can't be known statically.
+fn query_kind(query: &QueryValue) -> Option<ValueKind> {
+    match query {
+        QueryValue::Status | QueryValue::Duration => Some(ValueKind::Integer),
+        QueryValue::Url | QueryValue::Body => Some(ValueKind::String),
+        QueryValue::Redirects => Some(ValueKind::List),
+        QueryValue::Bytes | QueryValue::RawBytes | QueryValue::Sha256 | QueryValue::Md5 => {
+            Some(ValueKind::Bytes)
+        }
+        QueryValue::Timings { .. } => Some(ValueKind::Float),
+        QueryValue::Certificate {
+            attribute_name: field,
+            ..
+        } => match field {
+            CertificateAttributeName::Subject
+            | CertificateAttributeName::Issuer
+            | CertificateAttributeName::SerialNumber => Some(ValueKind::String),
+            CertificateAttributeName::StartDate | CertificateAttributeName::ExpireDate => {
+                Some(ValueKind::Date)
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns the kinds of value accepted by a filter, and the kind of its returned value (or `None`
+/// if it can't be known statically).
+fn filter_signature(filter: &FilterValue) -> (&'static [ValueKind], Option<ValueKind>) {
+    use ValueKind::*;
+    match filter {
+        FilterValue::Base32Decode => (&[Bytes, String], Some(Bytes)),
+        FilterValue::Base64Decode | FilterValue::Base64UrlSafeDecode | FilterValue::HexDecode => {
+            (&[String], Some(Bytes))
+        }
+        FilterValue::Base32Encode => (&[Bytes, String], Some(String)),
+        FilterValue::Base64Encode
+        | FilterValue::Base64UrlSafeEncode
+        | FilterValue::HexEncode
+        | FilterValue::Md5
+        | FilterValue::Sha1
+        | FilterValue::Sha256 => (&[Bytes], Some(String)),
+        FilterValue::Count => (&[Bytes, List, Nodeset], Some(Integer)),
+        FilterValue::Custom { .. } => (
+            &[Bool, Bytes, Date, Float, Integer, List, Nodeset, String],
+            None,
+        ),
+        FilterValue::DaysAfterNow | FilterValue::DaysBeforeNow => (&[Date], Some(Integer)),
+        FilterValue::Decode { .. } | FilterValue::DecodeAuto => (&[Bytes], Some(String)),
+        FilterValue::Decompress => (&[Bytes], Some(Bytes)),
+        FilterValue::Format { .. } => (&[Date], Some(String)),
+        FilterValue::HtmlEscape
+        | FilterValue::HtmlUnescape
+        | FilterValue::JsonEscape
+        | FilterValue::JsonUnescape
+        | FilterValue::PathEncode
+        | FilterValue::Regex { .. }
+        | FilterValue::Replace { .. }
+        | FilterValue::UrlDecode
+        | FilterValue::UrlEncode
+        | FilterValue::XmlEscape => (&[String], Some(String)),
+        FilterValue::Jsonl => (&[String], Some(List)),
+        FilterValue::JsonPath { .. } | FilterValue::JsonPointer { .. } => (&[List, String], None),
+        FilterValue::XPath { .. } => (&[String], None),
+        FilterValue::Nth { .. } => (&[List], None),
+        FilterValue::Split { .. } => (&[String], Some(List)),
+        FilterValue::ToDate { .. } => (&[String], Some(Date)),
+        FilterValue::ToFloat => (&[Float, Integer, String], Some(Float)),
+        FilterValue::ToInt => (&[Float, Integer, String], Some(Integer)),
+        FilterValue::ToString => (&[Bool, Date, Float, Integer, String], Some(String)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::{SectionValue, SourceInfo};
+    use hurl_core::parser::parse_hurl_file;
+    use hurl_core::reader::Pos;
+
+    use super::*;
+
+    fn check_asserts(content: &str) -> Vec<LinterError> {
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let mut errors = vec![];
+        for section in &hurl_file.entries[0].response.as_ref().unwrap().sections {
+            if let SectionValue::Asserts(asserts) = &section.value {
+                for assert in asserts {
+                    errors.extend(check_filters(&assert.query, &assert.filters));
+                }
+            }
+        }
+        errors
+    }
+
+    #[test]
+    fn test_check_filters_invalid_input() {
+        let content = "GET http://localhost\nHTTP 200\n[Asserts]\nstatus count == 1\n";
+        assert_eq!(
+            check_asserts(content),
+            vec![LinterError {
+                source_info: SourceInfo::new(Pos::new(4, 8), Pos::new(4, 13)),
+                kind: LinterErrorKind::InvalidFilterInput {
+                    filter: "count".to_string(),
+                    actual: ValueKind::Integer,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_filters_valid_chains() {
+        let content = "GET http://localhost\nHTTP 200\n[Asserts]\n\
+                       body toInt == 1\n\
+                       jsonpath \"$.items\" count == 2\n\
+                       bytes hexEncode == \"abc\"\n\
+                       header \"Id\" split \",\" count == 3\n";
+        assert_eq!(check_asserts(content), vec![]);
+    }
+}
@@ -18,5 +18,7 @@
 pub use rules::{check_hurl_file, lint_hurl_file};
 
 pub use self::error::{LinterError, LinterErrorKind};
+pub use self::filters::ValueKind;
 mod error;
+mod filters;
 mod rules;
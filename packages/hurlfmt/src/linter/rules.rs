@@ -15,11 +15,12 @@
  * limitations under the License.
  *
  */
+use crate::linter::filters::check_filters;
 use crate::linter::{LinterError, LinterErrorKind};
 use hurl_core::ast::{
     Assert, Base64, Body, Bytes, Capture, Comment, Cookie, CookieAttribute, CookieAttributeName,
-    CookiePath, DurationOption, Entry, EntryOption, File, FileParam, Filter, FilterValue, GraphQl,
-    Hex, HurlFile, KeyValue, LineTerminator, MultilineString, MultilineStringAttribute,
+    CookiePath, DurationOption, Entry, EntryOption, Epsilon, File, FileParam, Filter, FilterValue,
+    GraphQl, Hex, HurlFile, KeyValue, LineTerminator, MultilineString, MultilineStringAttribute,
     MultilineStringKind, MultipartParam, OptionKind, Predicate, PredicateFunc, PredicateFuncValue,
     PredicateValue, Query, QueryValue, RegexValue, Request, Response, Section, SectionValue,
     SourceInfo, Template, Text, VariableDefinition, Whitespace,
@@ -153,6 +154,23 @@ fn check_section(section: &Section) -> Vec<LinterError> {
     for error in check_line_terminator(&section.line_terminator0) {
         errors.push(error);
     }
+    match &section.value {
+        SectionValue::Captures(captures) => {
+            errors.extend(
+                captures
+                    .iter()
+                    .filter_map(|c| check_filters(&c.query, &c.filters)),
+            );
+        }
+        SectionValue::Asserts(asserts) => {
+            errors.extend(
+                asserts
+                    .iter()
+                    .filter_map(|a| check_filters(&a.query, &a.filters)),
+            );
+        }
+        _ => {}
+    }
     errors
 }
 
@@ -267,10 +285,23 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
     match query_value {
         QueryValue::Status => QueryValue::Status,
         QueryValue::Url => QueryValue::Url,
+        QueryValue::Redirects => QueryValue::Redirects,
         QueryValue::Header { name, .. } => QueryValue::Header {
             name: name.clone(),
             space0: one_whitespace(),
         },
+        QueryValue::Trailer { name, .. } => QueryValue::Trailer {
+            name: name.clone(),
+            space0: one_whitespace(),
+        },
+        QueryValue::ServerTiming { name, .. } => QueryValue::ServerTiming {
+            name: name.clone(),
+            space0: one_whitespace(),
+        },
+        QueryValue::Link { rel, .. } => QueryValue::Link {
+            rel: rel.clone(),
+            space0: one_whitespace(),
+        },
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
             ..
@@ -293,6 +324,10 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             expr: expr.clone(),
             space0: one_whitespace(),
         },
+        QueryValue::Jsonpointer { expr, .. } => QueryValue::Jsonpointer {
+            expr: expr.clone(),
+            space0: one_whitespace(),
+        },
         QueryValue::Regex { value, .. } => QueryValue::Regex {
             value: lint_regex_value(value),
             space0: one_whitespace(),
@@ -303,6 +338,7 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
         },
         QueryValue::Duration => QueryValue::Duration,
         QueryValue::Bytes => QueryValue::Bytes,
+        QueryValue::RawBytes => QueryValue::RawBytes,
         QueryValue::Sha256 => QueryValue::Sha256,
         QueryValue::Md5 => QueryValue::Md5,
         QueryValue::Certificate {
@@ -312,6 +348,20 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             attribute_name: *field,
             space0: one_whitespace(),
         },
+        QueryValue::Timings {
+            attribute_name: field,
+            ..
+        } => QueryValue::Timings {
+            attribute_name: *field,
+            space0: one_whitespace(),
+        },
+        QueryValue::Custom { name, args } => QueryValue::Custom {
+            name: name.clone(),
+            args: args
+                .iter()
+                .map(|(_, arg)| (one_whitespace(), arg.clone()))
+                .collect(),
+        },
     }
 }
 
@@ -397,6 +447,21 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::ContainTimes { value, count, .. } => PredicateFuncValue::ContainTimes {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+            space1: one_whitespace(),
+            count: count.clone(),
+        },
+        PredicateFuncValue::NearlyEqual { value, epsilon, .. } => PredicateFuncValue::NearlyEqual {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+            epsilon: epsilon.as_ref().map(|epsilon| Epsilon {
+                space0: one_whitespace(),
+                space1: one_whitespace(),
+                value: lint_predicate_value(&epsilon.value),
+            }),
+        },
 
         PredicateFuncValue::Include { value, .. } => PredicateFuncValue::Include {
             space0: one_whitespace(),
@@ -421,10 +486,19 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
         PredicateFuncValue::IsString => PredicateFuncValue::IsString,
         PredicateFuncValue::IsCollection => PredicateFuncValue::IsCollection,
         PredicateFuncValue::IsDate => PredicateFuncValue::IsDate,
+        PredicateFuncValue::IsDateFormat { fmt, .. } => PredicateFuncValue::IsDateFormat {
+            space0: one_whitespace(),
+            fmt: fmt.clone(),
+        },
         PredicateFuncValue::IsIsoDate => PredicateFuncValue::IsIsoDate,
         PredicateFuncValue::Exist => PredicateFuncValue::Exist,
         PredicateFuncValue::IsEmpty => PredicateFuncValue::IsEmpty,
         PredicateFuncValue::IsNumber => PredicateFuncValue::IsNumber,
+        PredicateFuncValue::IsUuid => PredicateFuncValue::IsUuid,
+        PredicateFuncValue::IsEmail => PredicateFuncValue::IsEmail,
+        PredicateFuncValue::IsUrl => PredicateFuncValue::IsUrl,
+        PredicateFuncValue::IsIpv4 => PredicateFuncValue::IsIpv4,
+        PredicateFuncValue::IsIpv6 => PredicateFuncValue::IsIpv6,
     }
 }
 
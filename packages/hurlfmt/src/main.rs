@@ -23,7 +23,7 @@ use hurl_core::input::InputKind;
 use hurl_core::{parser, text};
 use hurlfmt::cli::options::{InputFormat, OptionsError, OutputFormat};
 use hurlfmt::cli::Logger;
-use hurlfmt::{cli, curl, format, linter};
+use hurlfmt::{cli, format, linter};
 
 const EXIT_OK: i32 = 0;
 const EXIT_ERROR: i32 = 1;
@@ -50,6 +50,7 @@ fn main() {
 
     let logger = Logger::new(opts.color);
     let mut output_all = String::new();
+    let mut has_lint_issue = false;
 
     for input_file in &opts.input_files {
         // Get content of the input
@@ -67,7 +68,7 @@ fn main() {
         // Parse input curl or Hurl file
         let input = match opts.input_format {
             InputFormat::Hurl => content.to_string(),
-            InputFormat::Curl => match curl::parse(&content) {
+            InputFormat::Curl => match hurl_curl::parse(&content) {
                 Ok(s) => s,
                 Err(e) => {
                     logger.error(&e.to_string());
@@ -91,11 +92,19 @@ fn main() {
             for e in lints.iter() {
                 logger.warn_lint(&content, input_file, e);
             }
-            if lints.is_empty() {
-                process::exit(EXIT_OK);
-            } else {
-                process::exit(EXIT_LINT_ISSUE);
+            has_lint_issue |= !lints.is_empty();
+            if opts.diff {
+                let formatted = format::format_text(&linter::lint_hurl_file(&hurl_file), false);
+                let formatted = if formatted.ends_with('\n') {
+                    formatted
+                } else {
+                    format!("{formatted}\n")
+                };
+                let diff = format::format_diff(&content, &formatted, &input_file.to_string());
+                has_lint_issue |= !diff.is_empty();
+                print!("{diff}");
             }
+            continue;
         }
 
         // Output files
@@ -116,6 +125,13 @@ fn main() {
             output_all.push_str(&output);
         }
     }
+    if opts.check {
+        if has_lint_issue {
+            process::exit(EXIT_LINT_ISSUE);
+        } else {
+            process::exit(EXIT_OK);
+        }
+    }
     if !opts.in_place {
         write_output(&output_all, opts.output_file);
     }